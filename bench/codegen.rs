@@ -1,4 +1,4 @@
-use cornucopia::{conn::cornucopia_conn, CodegenSettings};
+use cornucopia::{conn::cornucopia_conn, CodegenSettings, TimeBackend};
 use criterion::Criterion;
 
 fn bench(c: &mut Criterion) {
@@ -17,6 +17,28 @@ fn bench(c: &mut Criterion) {
                     gen_sync: true,
                     gen_async: false,
                     derive_ser: true,
+                    derive_sqlx: false,
+                    derive_hash: false,
+                    gen_cow: false,
+                    gen_foreign_keys: false,
+                    gen_prelude: false,
+                    gen_query_errors: false,
+                    numeric_as_f64: false,
+                    ordered_floats: false,
+                    enum_ord: false,
+                    column_enums: false,
+                    yesql: false,
+                    dyn_params: false,
+                    qualified_paths: false,
+                    statements_struct: false,
+                    named_streams: false,
+                    columns_by_name: false,
+                    text_as_bytes: false,
+                    infinite_timestamps: false,
+                    time_backend: TimeBackend::Time,
+                    bigint_as_string: false,
+                    serde_feature: None,
+                    statement_timeout: None,
                 },
             )
             .unwrap()
@@ -32,6 +54,28 @@ fn bench(c: &mut Criterion) {
                     gen_sync: true,
                     gen_async: false,
                     derive_ser: true,
+                    derive_sqlx: false,
+                    derive_hash: false,
+                    gen_cow: false,
+                    gen_foreign_keys: false,
+                    gen_prelude: false,
+                    gen_query_errors: false,
+                    numeric_as_f64: false,
+                    ordered_floats: false,
+                    enum_ord: false,
+                    column_enums: false,
+                    yesql: false,
+                    dyn_params: false,
+                    qualified_paths: false,
+                    statements_struct: false,
+                    named_streams: false,
+                    columns_by_name: false,
+                    text_as_bytes: false,
+                    infinite_timestamps: false,
+                    time_backend: TimeBackend::Time,
+                    bigint_as_string: false,
+                    serde_feature: None,
+                    statement_timeout: None,
                 },
             )
             .unwrap()
@@ -16,21 +16,30 @@ pub mod queries {
             T1: cornucopia_async::StringSql,
             T2: cornucopia_async::StringSql,
         > {
+            /// `varchar`
             pub name: T1,
+            /// `varchar`, nullable
             pub hair_color: Option<T2>,
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct User {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: String,
+            /// `varchar`, nullable
             pub hair_color: Option<String>,
         }
         pub struct UserBorrowed<'a> {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `varchar`, nullable
             pub hair_color: Option<&'a str>,
         }
         impl<'a> From<UserBorrowed<'a>> for User {
+            #[inline]
             fn from(
                 UserBorrowed {
                     id,
@@ -47,18 +56,27 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct Post {
+            /// `int4`
             pub id: i32,
+            /// `int4`
             pub user_id: i32,
+            /// `varchar`
             pub title: String,
+            /// `text`, nullable
             pub body: Option<String>,
         }
         pub struct PostBorrowed<'a> {
+            /// `int4`
             pub id: i32,
+            /// `int4`
             pub user_id: i32,
+            /// `varchar`
             pub title: &'a str,
+            /// `text`, nullable
             pub body: Option<&'a str>,
         }
         impl<'a> From<PostBorrowed<'a>> for Post {
+            #[inline]
             fn from(
                 PostBorrowed {
                     id,
@@ -77,16 +95,23 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct Comment {
+            /// `int4`
             pub id: i32,
+            /// `int4`
             pub post_id: i32,
+            /// `text`
             pub text: String,
         }
         pub struct CommentBorrowed<'a> {
+            /// `int4`
             pub id: i32,
+            /// `int4`
             pub post_id: i32,
+            /// `text`
             pub text: &'a str,
         }
         impl<'a> From<CommentBorrowed<'a>> for Comment {
+            #[inline]
             fn from(CommentBorrowed { id, post_id, text }: CommentBorrowed<'a>) -> Self {
                 Self {
                     id,
@@ -97,24 +122,39 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct SelectComplex {
+            /// `int4`
             pub myuser_id: i32,
+            /// `varchar`
             pub name: String,
+            /// `varchar`, nullable
             pub hair_color: Option<String>,
+            /// `int4`, nullable
             pub post_id: Option<i32>,
+            /// `int4`, nullable
             pub user_id: Option<i32>,
+            /// `varchar`, nullable
             pub title: Option<String>,
+            /// `text`, nullable
             pub body: Option<String>,
         }
         pub struct SelectComplexBorrowed<'a> {
+            /// `int4`
             pub myuser_id: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `varchar`, nullable
             pub hair_color: Option<&'a str>,
+            /// `int4`, nullable
             pub post_id: Option<i32>,
+            /// `int4`, nullable
             pub user_id: Option<i32>,
+            /// `varchar`, nullable
             pub title: Option<&'a str>,
+            /// `text`, nullable
             pub body: Option<&'a str>,
         }
         impl<'a> From<SelectComplexBorrowed<'a>> for SelectComplex {
+            #[inline]
             fn from(
                 SelectComplexBorrowed {
                     myuser_id,
@@ -145,6 +185,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_sync::private::Stmt,
                 extractor: fn(&postgres::Row) -> super::UserBorrowed,
                 mapper: fn(super::UserBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> UserQuery<'a, C, T, N>
             where
@@ -160,6 +201,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub fn one(self) -> Result<T, postgres::Error> {
@@ -168,7 +210,15 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                    self.iter()?.collect()
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
                 }
                 pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                     let stmt = self.stmt.prepare(self.client)?;
@@ -177,6 +227,10 @@ pub mod queries {
                         .query_opt(stmt, &self.params)?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub fn execute(self) -> Result<u64, postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client)?;
+                    self.client.execute(stmt, &self.params)
+                }
                 pub fn iter(
                     self,
                 ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -196,6 +250,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_sync::private::Stmt,
                 extractor: fn(&postgres::Row) -> super::PostBorrowed,
                 mapper: fn(super::PostBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> PostQuery<'a, C, T, N>
             where
@@ -211,6 +266,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub fn one(self) -> Result<T, postgres::Error> {
@@ -219,7 +275,15 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                    self.iter()?.collect()
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
                 }
                 pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                     let stmt = self.stmt.prepare(self.client)?;
@@ -228,6 +292,10 @@ pub mod queries {
                         .query_opt(stmt, &self.params)?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub fn execute(self) -> Result<u64, postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client)?;
+                    self.client.execute(stmt, &self.params)
+                }
                 pub fn iter(
                     self,
                 ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -247,6 +315,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_sync::private::Stmt,
                 extractor: fn(&postgres::Row) -> super::CommentBorrowed,
                 mapper: fn(super::CommentBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> CommentQuery<'a, C, T, N>
             where
@@ -262,6 +331,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub fn one(self) -> Result<T, postgres::Error> {
@@ -270,7 +340,15 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                    self.iter()?.collect()
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
                 }
                 pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                     let stmt = self.stmt.prepare(self.client)?;
@@ -279,6 +357,10 @@ pub mod queries {
                         .query_opt(stmt, &self.params)?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub fn execute(self) -> Result<u64, postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client)?;
+                    self.client.execute(stmt, &self.params)
+                }
                 pub fn iter(
                     self,
                 ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -298,6 +380,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_sync::private::Stmt,
                 extractor: fn(&postgres::Row) -> super::SelectComplexBorrowed,
                 mapper: fn(super::SelectComplexBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> SelectComplexQuery<'a, C, T, N>
             where
@@ -313,6 +396,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub fn one(self) -> Result<T, postgres::Error> {
@@ -321,7 +405,15 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                    self.iter()?.collect()
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
                 }
                 pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                     let stmt = self.stmt.prepare(self.client)?;
@@ -330,6 +422,10 @@ pub mod queries {
                         .query_opt(stmt, &self.params)?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub fn execute(self) -> Result<u64, postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client)?;
+                    self.client.execute(stmt, &self.params)
+                }
                 pub fn iter(
                     self,
                 ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -348,6 +444,9 @@ pub mod queries {
             }
             pub struct UsersStmt(cornucopia_sync::private::Stmt);
             impl UsersStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a mut C,
@@ -362,6 +461,7 @@ pub mod queries {
                             hair_color: row.get(2),
                         },
                         mapper: |it| <super::User>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -372,6 +472,9 @@ pub mod queries {
             }
             pub struct InsertUserStmt(cornucopia_sync::private::Stmt);
             impl InsertUserStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<
                     'a,
                     C: GenericClient,
@@ -413,6 +516,9 @@ pub mod queries {
             }
             pub struct PostsStmt(cornucopia_sync::private::Stmt);
             impl PostsStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a mut C,
@@ -428,6 +534,7 @@ pub mod queries {
                             body: row.get(3),
                         },
                         mapper: |it| <super::Post>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -438,6 +545,9 @@ pub mod queries {
             }
             pub struct PostByUserIdsStmt(cornucopia_sync::private::Stmt);
             impl PostByUserIdsStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::ArraySql<Item = i32>>(
                     &'a mut self,
                     client: &'a mut C,
@@ -454,6 +564,7 @@ pub mod queries {
                             body: row.get(3),
                         },
                         mapper: |it| <super::Post>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -464,6 +575,9 @@ pub mod queries {
             }
             pub struct CommentsStmt(cornucopia_sync::private::Stmt);
             impl CommentsStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a mut C,
@@ -478,6 +592,7 @@ pub mod queries {
                             text: row.get(2),
                         },
                         mapper: |it| <super::Comment>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -488,6 +603,9 @@ pub mod queries {
             }
             pub struct CommentsByPostIdStmt(cornucopia_sync::private::Stmt);
             impl CommentsByPostIdStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::ArraySql<Item = i32>>(
                     &'a mut self,
                     client: &'a mut C,
@@ -503,6 +621,7 @@ pub mod queries {
                             text: row.get(2),
                         },
                         mapper: |it| <super::Comment>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -511,6 +630,9 @@ pub mod queries {
             }
             pub struct SelectComplexStmt(cornucopia_sync::private::Stmt);
             impl SelectComplexStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a mut C,
@@ -529,13 +651,13 @@ pub mod queries {
                             body: row.get(6),
                         },
                         mapper: |it| <super::SelectComplex>::from(it),
+                        capacity: 0,
                     }
                 }
             }
         }
         pub mod async_ {
             use cornucopia_async::GenericClient;
-            use futures;
             use futures::{StreamExt, TryStreamExt};
             pub struct UserQuery<'a, C: GenericClient, T, const N: usize> {
                 client: &'a C,
@@ -543,6 +665,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_async::private::Stmt,
                 extractor: fn(&tokio_postgres::Row) -> super::UserBorrowed,
                 mapper: fn(super::UserBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> UserQuery<'a, C, T, N>
             where
@@ -558,6 +681,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -566,7 +690,20 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                    self.iter().await?.try_collect().await
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(
+                    self,
+                    capacity: usize,
+                ) -> Result<Vec<T>, tokio_postgres::Error> {
+                    self.iter()
+                        .await?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        })
+                        .await
                 }
                 pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -576,10 +713,14 @@ pub mod queries {
                         .await?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client).await?;
+                    self.client.execute(stmt, &self.params).await
+                }
                 pub async fn iter(
                     self,
                 ) -> Result<
-                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                     tokio_postgres::Error,
                 > {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -598,6 +739,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_async::private::Stmt,
                 extractor: fn(&tokio_postgres::Row) -> super::PostBorrowed,
                 mapper: fn(super::PostBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> PostQuery<'a, C, T, N>
             where
@@ -613,6 +755,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -621,7 +764,20 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                    self.iter().await?.try_collect().await
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(
+                    self,
+                    capacity: usize,
+                ) -> Result<Vec<T>, tokio_postgres::Error> {
+                    self.iter()
+                        .await?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        })
+                        .await
                 }
                 pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -631,10 +787,14 @@ pub mod queries {
                         .await?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client).await?;
+                    self.client.execute(stmt, &self.params).await
+                }
                 pub async fn iter(
                     self,
                 ) -> Result<
-                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                     tokio_postgres::Error,
                 > {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -653,6 +813,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_async::private::Stmt,
                 extractor: fn(&tokio_postgres::Row) -> super::CommentBorrowed,
                 mapper: fn(super::CommentBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> CommentQuery<'a, C, T, N>
             where
@@ -668,6 +829,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -676,7 +838,20 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                    self.iter().await?.try_collect().await
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(
+                    self,
+                    capacity: usize,
+                ) -> Result<Vec<T>, tokio_postgres::Error> {
+                    self.iter()
+                        .await?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        })
+                        .await
                 }
                 pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -686,10 +861,14 @@ pub mod queries {
                         .await?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client).await?;
+                    self.client.execute(stmt, &self.params).await
+                }
                 pub async fn iter(
                     self,
                 ) -> Result<
-                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                     tokio_postgres::Error,
                 > {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -708,6 +887,7 @@ pub mod queries {
                 stmt: &'a mut cornucopia_async::private::Stmt,
                 extractor: fn(&tokio_postgres::Row) -> super::SelectComplexBorrowed,
                 mapper: fn(super::SelectComplexBorrowed) -> T,
+                capacity: usize,
             }
             impl<'a, C, T: 'a, const N: usize> SelectComplexQuery<'a, C, T, N>
             where
@@ -723,6 +903,7 @@ pub mod queries {
                         stmt: self.stmt,
                         extractor: self.extractor,
                         mapper,
+                        capacity: self.capacity,
                     }
                 }
                 pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -731,7 +912,20 @@ pub mod queries {
                     Ok((self.mapper)((self.extractor)(&row)))
                 }
                 pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                    self.iter().await?.try_collect().await
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(
+                    self,
+                    capacity: usize,
+                ) -> Result<Vec<T>, tokio_postgres::Error> {
+                    self.iter()
+                        .await?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        })
+                        .await
                 }
                 pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -741,10 +935,14 @@ pub mod queries {
                         .await?
                         .map(|row| (self.mapper)((self.extractor)(&row))))
                 }
+                pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                    let stmt = self.stmt.prepare(self.client).await?;
+                    self.client.execute(stmt, &self.params).await
+                }
                 pub async fn iter(
                     self,
                 ) -> Result<
-                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                    impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                     tokio_postgres::Error,
                 > {
                     let stmt = self.stmt.prepare(self.client).await?;
@@ -762,6 +960,9 @@ pub mod queries {
             }
             pub struct UsersStmt(cornucopia_async::private::Stmt);
             impl UsersStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a C,
@@ -776,6 +977,7 @@ pub mod queries {
                             hair_color: row.get(2),
                         },
                         mapper: |it| <super::User>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -786,6 +988,9 @@ pub mod queries {
             }
             pub struct InsertUserStmt(cornucopia_async::private::Stmt);
             impl InsertUserStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub async fn bind<
                     'a,
                     C: GenericClient,
@@ -839,6 +1044,9 @@ pub mod queries {
             }
             pub struct PostsStmt(cornucopia_async::private::Stmt);
             impl PostsStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a C,
@@ -854,6 +1062,7 @@ pub mod queries {
                             body: row.get(3),
                         },
                         mapper: |it| <super::Post>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -864,6 +1073,9 @@ pub mod queries {
             }
             pub struct PostByUserIdsStmt(cornucopia_async::private::Stmt);
             impl PostByUserIdsStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient, T1: cornucopia_async::ArraySql<Item = i32>>(
                     &'a mut self,
                     client: &'a C,
@@ -880,6 +1092,7 @@ pub mod queries {
                             body: row.get(3),
                         },
                         mapper: |it| <super::Post>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -890,6 +1103,9 @@ pub mod queries {
             }
             pub struct CommentsStmt(cornucopia_async::private::Stmt);
             impl CommentsStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a C,
@@ -904,6 +1120,7 @@ pub mod queries {
                             text: row.get(2),
                         },
                         mapper: |it| <super::Comment>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -914,6 +1131,9 @@ pub mod queries {
             }
             pub struct CommentsByPostIdStmt(cornucopia_async::private::Stmt);
             impl CommentsByPostIdStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient, T1: cornucopia_async::ArraySql<Item = i32>>(
                     &'a mut self,
                     client: &'a C,
@@ -929,6 +1149,7 @@ pub mod queries {
                             text: row.get(2),
                         },
                         mapper: |it| <super::Comment>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -937,6 +1158,9 @@ pub mod queries {
             }
             pub struct SelectComplexStmt(cornucopia_async::private::Stmt);
             impl SelectComplexStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
                 pub fn bind<'a, C: GenericClient>(
                     &'a mut self,
                     client: &'a C,
@@ -955,6 +1179,7 @@ pub mod queries {
                             body: row.get(6),
                         },
                         mapper: |it| <super::SelectComplex>::from(it),
+                        capacity: 0,
                     }
                 }
             }
@@ -2,7 +2,11 @@
 pub mod private;
 
 pub use crate::generic_client::GenericClient;
-pub use cornucopia_client_core::{ArrayIterator, ArraySql, BytesSql, IterSql, StringSql};
+pub use cornucopia_client_core::{
+    ArityError, ArrayIterator, ArraySql, BytesSql, HstoreIterator, HstoreSql, IterSql, JsonPath,
+    Multirange, MultirangeIterator, NumericF64, NumericSql, OrderedF32, OrderedF64, Range, RegOid,
+    StringSql, TextBytes, TextParam, Timestamp,
+};
 
 #[cfg(feature = "with-serde_json-1")]
 pub use cornucopia_client_core::JsonSql;
@@ -13,6 +17,22 @@ mod generic_client;
 
 /// This trait allows you to bind parameters to a query using a single
 /// struct, rather than passing each bind parameter as a function parameter.
+///
+/// Params are only borrowed, so the same struct can be reused to run a
+/// statement several times, without being copied or cloned:
+///
+/// ```ignore
+/// let params = InsertBookParams { author: None, name: "Necronomicon" };
+/// let mut stmt = insert_book();
+/// for _ in 0..3 {
+///     stmt.params(&client, &params).await?;
+/// }
+/// ```
 pub trait Params<'a, P, O, C> {
     fn params(&'a mut self, client: &'a C, params: &'a P) -> O;
 }
+
+/// Checks that the connection is usable by running `SELECT 1`, e.g. for readiness probes.
+pub async fn ping<C: GenericClient>(client: &C) -> Result<(), tokio_postgres::Error> {
+    client.execute("SELECT 1", &[]).await.map(|_| ())
+}
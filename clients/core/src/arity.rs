@@ -0,0 +1,22 @@
+use std::{error::Error, fmt};
+
+/// Error returned when binding a number of parameters different from the one a query expects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArityError {
+    /// Number of parameters of the query
+    pub expected: usize,
+    /// Number of parameters given
+    pub got: usize,
+}
+
+impl fmt::Display for ArityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} parameters but got {}",
+            self.expected, self.got
+        )
+    }
+}
+
+impl Error for ArityError {}
@@ -9,6 +9,7 @@ use crate::utils::escape_domain;
 /// Iterator over the items in a PostgreSQL array. You only need this if you are
 /// working with custom zero-cost type mapping of rows containing PostgreSQL arrays.
 pub struct ArrayIterator<'a, T: FromSql<'a>> {
+    raw: &'a [u8],
     values: ArrayValues<'a>,
     ty: Type,
     _type: PhantomData<T>,
@@ -51,6 +52,7 @@ impl<'a, T: FromSql<'a>> FromSql<'a> for ArrayIterator<'a, T> {
         }
 
         Ok(ArrayIterator {
+            raw,
             ty: member_type.clone(),
             values: array.values(),
             _type: PhantomData::default(),
@@ -64,3 +66,21 @@ impl<'a, T: FromSql<'a>> FromSql<'a> for ArrayIterator<'a, T> {
         }
     }
 }
+
+/// Serializes all the items of the array, including those already iterated
+#[cfg(feature = "with-serde_json-1")]
+impl<'a, T: FromSql<'a> + serde_1::ser::Serialize> serde_1::ser::Serialize
+    for ArrayIterator<'a, T>
+{
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde_1::ser::{Error as _, SerializeSeq};
+        let array = array_from_sql(self.raw).map_err(S::Error::custom)?;
+        let mut values = array.values();
+        let mut seq = serializer.serialize_seq(None)?;
+        while let Some(raw) = values.next().map_err(S::Error::custom)? {
+            let value = T::from_sql_nullable(&self.ty, raw).map_err(S::Error::custom)?;
+            seq.serialize_element(&value)?;
+        }
+        seq.end()
+    }
+}
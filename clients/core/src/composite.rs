@@ -0,0 +1,74 @@
+use postgres_types::{
+    private::{read_be_i32, BytesMut},
+    Field, IsNull, Kind, Type,
+};
+use std::error::Error;
+
+fn composite_fields(ty: &Type) -> &[Field] {
+    match ty.kind() {
+        Kind::Composite(fields) => fields,
+        _ => unreachable!(),
+    }
+}
+
+/// Encodes a composite value, `write_field` encoding the value of each field
+pub fn write_composite(
+    ty: &Type,
+    out: &mut BytesMut,
+    mut write_field: impl FnMut(&Field, &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>,
+) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    let fields = composite_fields(ty);
+    out.extend_from_slice(&(fields.len() as i32).to_be_bytes());
+    for field in fields {
+        out.extend_from_slice(&field.type_().oid().to_be_bytes());
+        let base = out.len();
+        out.extend_from_slice(&[0; 4]);
+        let count = match write_field(field, out)? {
+            IsNull::Yes => -1,
+            IsNull::No => {
+                let len = out.len() - base - 4;
+                if len > i32::MAX as usize {
+                    return Err("value too large to transmit".into());
+                }
+                len as i32
+            }
+        };
+        out[base..base + 4].copy_from_slice(&count.to_be_bytes());
+    }
+    Ok(IsNull::No)
+}
+
+/// Decodes a composite value, `read_field` decoding the value of each field in the order
+/// they are sent by the server
+pub fn read_composite<'a>(
+    ty: &Type,
+    mut raw: &'a [u8],
+    mut read_field: impl FnMut(&Field, &mut &'a [u8]) -> Result<(), Box<dyn Error + Sync + Send>>,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let fields = composite_fields(ty);
+    let num_fields = read_be_i32(&mut raw)?;
+    if num_fields as usize != fields.len() {
+        return Err(format!("invalid field count: {} vs {}", num_fields, fields.len()).into());
+    }
+    for field in fields {
+        let _oid = read_be_i32(&mut raw)?;
+        read_field(field, &mut raw)?;
+    }
+    Ok(())
+}
+
+/// Whether `ty` is the composite `name` with `nb_fields` fields, all accepted by `accepts_field`
+pub fn composite_accepts(
+    ty: &Type,
+    name: &str,
+    nb_fields: usize,
+    accepts_field: impl Fn(&Field) -> bool,
+) -> bool {
+    if ty.name() != name {
+        return false;
+    }
+    match ty.kind() {
+        Kind::Composite(fields) => fields.len() == nb_fields && fields.iter().all(accepts_field),
+        _ => false,
+    }
+}
@@ -1,5 +1,5 @@
 use postgres_protocol::types::{array_to_sql, ArrayDimension};
-use postgres_types::{private::BytesMut, IsNull, Kind, ToSql, Type};
+use postgres_types::{private::BytesMut, Format, IsNull, Kind, ToSql, Type};
 use std::{
     error::Error,
     fmt::{Debug, Formatter},
@@ -30,6 +30,11 @@ impl<T: ToSql> ToSql for Domain<T> {
         return T::accepts(escape_domain(ty));
     }
 
+    // Values bound from their text representation stay in text format
+    fn encode_format(&self, ty: &Type) -> Format {
+        self.0.encode_format(escape_domain(ty))
+    }
+
     fn to_sql_checked(
         &self,
         ty: &Type,
@@ -0,0 +1,57 @@
+use fallible_iterator::FallibleIterator;
+use postgres_protocol::types::{hstore_from_sql, HstoreEntries};
+use postgres_types::{FromSql, Type};
+use std::fmt::Debug;
+
+/// Iterator over the entries of a PostgreSQL `hstore`. You only need this if you are
+/// working with custom zero-cost type mapping of rows containing `hstore` values.
+pub struct HstoreIterator<'a> {
+    raw: &'a [u8],
+    entries: HstoreEntries<'a>,
+}
+
+impl<'a> Debug for HstoreIterator<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HstoreIterator")
+            .field("entries", &"[(&str, Option<&str>)]")
+            .finish()
+    }
+}
+
+impl<'a> Iterator for HstoreIterator<'a> {
+    type Item = (&'a str, Option<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().unwrap()
+    }
+}
+
+impl<'a> FromSql<'a> for HstoreIterator<'a> {
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> Result<HstoreIterator<'a>, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(HstoreIterator {
+            raw,
+            entries: hstore_from_sql(raw)?,
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "hstore"
+    }
+}
+
+/// Serializes all the entries of the `hstore`, including those already iterated
+#[cfg(feature = "with-serde_json-1")]
+impl<'a> serde_1::ser::Serialize for HstoreIterator<'a> {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde_1::ser::{Error as _, SerializeMap};
+        let mut entries = hstore_from_sql(self.raw).map_err(S::Error::custom)?;
+        let mut map = serializer.serialize_map(None)?;
+        while let Some((key, value)) = entries.next().map_err(S::Error::custom)? {
+            map.serialize_entry(key, &value)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,75 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::{error::Error, fmt};
+
+/// Only version of the `jsonpath` binary format
+const JSONPATH_VERSION: u8 = 1;
+
+/// A PostgreSQL `jsonpath` value, kept in its text form
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonPath(pub String);
+
+impl JsonPath {
+    /// Text form of this path
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for JsonPath {
+    fn from(path: String) -> Self {
+        JsonPath(path)
+    }
+}
+
+impl From<&str> for JsonPath {
+    fn from(path: &str) -> Self {
+        JsonPath(path.to_string())
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> FromSql<'a> for JsonPath {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match raw.split_first() {
+            Some((&JSONPATH_VERSION, text)) => Ok(JsonPath(std::str::from_utf8(text)?.into())),
+            Some((version, _)) => Err(format!("unsupported jsonpath version {version}").into()),
+            None => Err("empty jsonpath value".into()),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::JSONPATH
+    }
+}
+
+impl ToSql for JsonPath {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        out.extend_from_slice(&[JSONPATH_VERSION]);
+        out.extend_from_slice(self.0.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        *ty == Type::JSONPATH
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl serde_1::ser::Serialize for JsonPath {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
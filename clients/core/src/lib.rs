@@ -1,12 +1,40 @@
+mod arity;
 mod array_iterator;
+mod composite;
 mod domain;
+mod hstore_iterator;
+mod json_path;
+mod multirange;
+mod numeric;
+mod ordered_float;
+mod range;
+mod reg_oid;
+#[cfg(feature = "with-serde_json-1")]
+mod serde_string;
+mod text_bytes;
+mod text_param;
+mod timestamp;
 mod type_traits;
 mod utils;
 
+pub use arity::ArityError;
 pub use array_iterator::ArrayIterator;
+pub use composite::{composite_accepts, read_composite, write_composite};
 pub use domain::{Domain, DomainArray};
-pub use type_traits::{ArraySql, BytesSql, IterSql, StringSql};
+pub use hstore_iterator::HstoreIterator;
+pub use json_path::JsonPath;
+pub use multirange::{Multirange, MultirangeIterator};
+pub use numeric::NumericF64;
+pub use ordered_float::{OrderedF32, OrderedF64};
+pub use range::Range;
+pub use reg_oid::RegOid;
+pub use text_bytes::TextBytes;
+pub use text_param::TextParam;
+pub use timestamp::Timestamp;
+pub use type_traits::{ArraySql, BytesSql, HstoreSql, IterSql, NumericSql, StringSql};
 
+#[cfg(feature = "with-serde_json-1")]
+pub use serde_string::{serialize_option_string, serialize_string};
 #[cfg(feature = "with-serde_json-1")]
 pub use type_traits::JsonSql;
 
@@ -0,0 +1,162 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, Kind, ToSql, Type};
+use std::{error::Error, fmt::Debug, marker::PhantomData};
+
+use crate::Range;
+
+/// A PostgreSQL multirange value, an ordered list of non-overlapping ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Multirange<T>(pub Vec<Range<T>>);
+
+impl<T> From<Vec<Range<T>>> for Multirange<T> {
+    fn from(ranges: Vec<Range<T>>) -> Self {
+        Multirange(ranges)
+    }
+}
+
+fn read_i32(raw: &mut &[u8]) -> Result<i32, Box<dyn Error + Sync + Send>> {
+    match raw {
+        [a, b, c, d, rest @ ..] => {
+            let value = i32::from_be_bytes([*a, *b, *c, *d]);
+            *raw = rest;
+            Ok(value)
+        }
+        _ => Err("invalid multirange value".into()),
+    }
+}
+
+/// Iterator over the ranges of a PostgreSQL multirange. You only need this if you are
+/// working with custom zero-cost type mapping of rows containing multiranges.
+pub struct MultirangeIterator<'a, T: FromSql<'a>> {
+    raw: &'a [u8],
+    remaining: i32,
+    ty: Type,
+    _type: PhantomData<T>,
+}
+
+impl<'a, T: FromSql<'a>> Debug for MultirangeIterator<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultirangeIterator")
+            .field("ranges", &"[Range<T>]")
+            .field("ty", &self.ty)
+            .finish()
+    }
+}
+
+impl<'a, T: FromSql<'a>> MultirangeIterator<'a, T> {
+    fn next_range(&mut self) -> Result<Range<T>, Box<dyn Error + Sync + Send>> {
+        let len = read_i32(&mut self.raw)? as usize;
+        if self.raw.len() < len {
+            return Err("invalid multirange value".into());
+        }
+        let (range, rest) = self.raw.split_at(len);
+        self.raw = rest;
+        Range::from_sql_member(&self.ty, range)
+    }
+}
+
+impl<'a, T: FromSql<'a>> Iterator for MultirangeIterator<'a, T> {
+    type Item = Range<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.next_range().unwrap())
+    }
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for MultirangeIterator<'a, T> {
+    fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let member = match ty.kind() {
+            Kind::Multirange(member) => member,
+            _ => return Err(format!("unexpected type {ty}").into()),
+        };
+        let remaining = read_i32(&mut raw)?.max(0);
+        Ok(MultirangeIterator {
+            raw,
+            remaining,
+            ty: member.clone(),
+            _type: PhantomData,
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match ty.kind() {
+            Kind::Multirange(member) => T::accepts(member),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Multirange<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let mut it = MultirangeIterator::<T>::from_sql(ty, raw)?;
+        let mut ranges = Vec::with_capacity(it.remaining as usize);
+        while it.remaining > 0 {
+            it.remaining -= 1;
+            ranges.push(it.next_range()?);
+        }
+        Ok(Multirange(ranges))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        MultirangeIterator::<T>::accepts(ty)
+    }
+}
+
+impl<T: ToSql> ToSql for Multirange<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        let member = match ty.kind() {
+            Kind::Multirange(member) => member,
+            _ => return Err(format!("unexpected type {ty}").into()),
+        };
+        out.extend_from_slice(&i32::try_from(self.0.len())?.to_be_bytes());
+        for range in &self.0 {
+            // Reserve the length prefix and fill it once the range is written
+            let base = out.len();
+            out.extend_from_slice(&[0; 4]);
+            range.to_sql_member(member, out)?;
+            let len = i32::try_from(out.len() - base - 4)?;
+            out[base..base + 4].copy_from_slice(&len.to_be_bytes());
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        match ty.kind() {
+            Kind::Multirange(member) => T::accepts(member),
+            _ => false,
+        }
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+/// Serializes the ranges not yet iterated
+#[cfg(feature = "with-serde_json-1")]
+impl<'a, T: FromSql<'a> + serde_1::ser::Serialize> serde_1::ser::Serialize
+    for MultirangeIterator<'a, T>
+{
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde_1::ser::{Error as _, SerializeSeq};
+        let mut it = MultirangeIterator::<T> {
+            raw: self.raw,
+            remaining: self.remaining,
+            ty: self.ty.clone(),
+            _type: PhantomData,
+        };
+        let mut seq = serializer.serialize_seq(Some(it.remaining as usize))?;
+        while it.remaining > 0 {
+            it.remaining -= 1;
+            seq.serialize_element(&it.next_range().map_err(S::Error::custom)?)?;
+        }
+        seq.end()
+    }
+}
@@ -0,0 +1,140 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+const NUMERIC_PINF: u16 = 0xD000;
+const NUMERIC_NINF: u16 = 0xF000;
+
+/// A PostgreSQL `numeric` value approximated by a `f64`
+///
+/// Values that do not fit in a `f64` lose precision, this is only suited
+/// for computations where exactness does not matter.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct NumericF64(pub f64);
+
+impl From<f64> for NumericF64 {
+    fn from(value: f64) -> Self {
+        NumericF64(value)
+    }
+}
+
+impl From<NumericF64> for f64 {
+    fn from(value: NumericF64) -> Self {
+        value.0
+    }
+}
+
+fn read_u16(raw: &mut &[u8]) -> Result<u16, Box<dyn Error + Sync + Send>> {
+    match raw {
+        [a, b, rest @ ..] => {
+            let value = u16::from_be_bytes([*a, *b]);
+            *raw = rest;
+            Ok(value)
+        }
+        _ => Err("invalid numeric value".into()),
+    }
+}
+
+impl<'a> FromSql<'a> for NumericF64 {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let ndigits = read_u16(&mut raw)?;
+        let weight = read_u16(&mut raw)? as i16;
+        let sign = read_u16(&mut raw)?;
+        let _dscale = read_u16(&mut raw)?;
+        let sign = match sign {
+            NUMERIC_POS => "",
+            NUMERIC_NEG => "-",
+            NUMERIC_NAN => return Ok(NumericF64(f64::NAN)),
+            NUMERIC_PINF => return Ok(NumericF64(f64::INFINITY)),
+            NUMERIC_NINF => return Ok(NumericF64(f64::NEG_INFINITY)),
+            _ => return Err(format!("invalid numeric sign {sign:#x}").into()),
+        };
+        // Base 10000 digits are written as `0.digits` scaled by the weight,
+        // letting the float parser do the correct rounding
+        let mut text = format!("{sign}0.");
+        for _ in 0..ndigits {
+            let digit = read_u16(&mut raw)?;
+            if digit >= 10000 {
+                return Err(format!("invalid numeric digit {digit}").into());
+            }
+            text.push_str(&format!("{digit:04}"));
+        }
+        if ndigits == 0 {
+            text.push('0');
+        }
+        let exponent = (i32::from(weight) + 1) * 4;
+        Ok(NumericF64(format!("{text}e{exponent}").parse()?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::NUMERIC
+    }
+}
+
+impl ToSql for NumericF64 {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        let mut write = |ndigits: u16, weight: i16, sign: u16, dscale: u16, digits: &[u16]| {
+            for value in [ndigits, weight as u16, sign, dscale].iter().chain(digits) {
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        };
+        let value = self.0;
+        if value.is_nan() {
+            write(0, 0, NUMERIC_NAN, 0, &[]);
+        } else if value.is_infinite() {
+            let sign = if value > 0. {
+                NUMERIC_PINF
+            } else {
+                NUMERIC_NINF
+            };
+            write(0, 0, sign, 0, &[]);
+        } else {
+            let sign = if value < 0. { NUMERIC_NEG } else { NUMERIC_POS };
+            // The shortest decimal representation that round-trips
+            let text = format!("{}", value.abs());
+            let (int, frac) = text.split_once('.').unwrap_or((&text, ""));
+            let dscale = frac.len() as u16;
+            // Align both parts on base 10000 digits
+            let int = format!("{}{int}", "0".repeat((4 - int.len() % 4) % 4));
+            let frac = format!("{frac}{}", "0".repeat((4 - frac.len() % 4) % 4));
+            let mut weight = (int.len() / 4) as i16 - 1;
+            let mut digits: Vec<u16> = format!("{int}{frac}")
+                .as_bytes()
+                .chunks(4)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap().parse().unwrap())
+                .collect();
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+            let leading = digits.iter().take_while(|d| **d == 0).count();
+            digits.drain(..leading);
+            weight -= leading as i16;
+            if digits.is_empty() {
+                weight = 0;
+            }
+            write(digits.len() as u16, weight, sign, dscale, &digits);
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        *ty == Type::NUMERIC
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl serde_1::ser::Serialize for NumericF64 {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
@@ -0,0 +1,102 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    hash::{Hash, Hasher},
+};
+
+macro_rules! ordered_float {
+    ($(#[$doc:meta])* $name:ident, $float:ty) => {
+        $(#[$doc])*
+        ///
+        /// Values are compared using their total order, so `NaN` is equal to itself
+        /// and `-0.0` is less than `0.0`. This makes them usable as map keys.
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name(pub $float);
+
+        impl From<$float> for $name {
+            fn from(value: $float) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $float {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                // Bit-wise equality matches the total order
+                self.0.to_bits().hash(state)
+            }
+        }
+
+        impl<'a> FromSql<'a> for $name {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                <$float>::from_sql(ty, raw).map($name)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                <$float as FromSql>::accepts(ty)
+            }
+        }
+
+        impl ToSql for $name {
+            fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+            where
+                Self: Sized,
+            {
+                self.0.to_sql(ty, out)
+            }
+
+            fn accepts(ty: &Type) -> bool
+            where
+                Self: Sized,
+            {
+                <$float as ToSql>::accepts(ty)
+            }
+
+            postgres_types::to_sql_checked!();
+        }
+
+        #[cfg(feature = "with-serde_json-1")]
+        impl serde_1::ser::Serialize for $name {
+            fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+    };
+}
+
+ordered_float!(
+    /// A PostgreSQL `real` value with a total order
+    OrderedF32,
+    f32
+);
+ordered_float!(
+    /// A PostgreSQL `double precision` value with a total order
+    OrderedF64,
+    f64
+);
@@ -0,0 +1,175 @@
+use postgres_protocol::types::{
+    empty_range_to_sql, range_from_sql, range_to_sql, Range as RawRange, RangeBound,
+};
+use postgres_types::{private::BytesMut, FromSql, IsNull, Kind, ToSql, Type};
+use std::{error::Error, ops::Bound};
+
+/// A PostgreSQL range value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Range<T> {
+    /// The empty range
+    Empty,
+    /// A range with a lower and an upper bound
+    Nonempty(Bound<T>, Bound<T>),
+}
+
+impl<T> Range<T> {
+    /// Maps the bounds of this range using `f`
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Range<U> {
+        let mut map = |bound| match bound {
+            Bound::Included(v) => Bound::Included(f(v)),
+            Bound::Excluded(v) => Bound::Excluded(f(v)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        match self {
+            Range::Empty => Range::Empty,
+            Range::Nonempty(lower, upper) => Range::Nonempty(map(lower), map(upper)),
+        }
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for Range<T> {
+    fn from((lower, upper): (Bound<T>, Bound<T>)) -> Self {
+        Range::Nonempty(lower, upper)
+    }
+}
+
+impl<T> From<std::ops::Range<T>> for Range<T> {
+    fn from(range: std::ops::Range<T>) -> Self {
+        Range::Nonempty(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<std::ops::RangeInclusive<T>> for Range<T> {
+    fn from(range: std::ops::RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Range::Nonempty(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+fn bound_from_sql<'a, T: FromSql<'a>>(
+    ty: &Type,
+    bound: RangeBound<Option<&'a [u8]>>,
+) -> Result<Bound<T>, Box<dyn Error + Sync + Send>> {
+    let value = |raw: Option<&'a [u8]>| match raw {
+        Some(raw) => T::from_sql(ty, raw),
+        None => Err("unexpected null range bound".into()),
+    };
+    Ok(match bound {
+        RangeBound::Inclusive(raw) => Bound::Included(value(raw)?),
+        RangeBound::Exclusive(raw) => Bound::Excluded(value(raw)?),
+        RangeBound::Unbounded => Bound::Unbounded,
+    })
+}
+
+fn bound_to_sql<T: ToSql>(
+    ty: &Type,
+    bound: &Bound<T>,
+    out: &mut BytesMut,
+) -> Result<RangeBound<postgres_protocol::IsNull>, Box<dyn Error + Sync + Send>> {
+    let mut value = |v: &T| -> Result<_, Box<dyn Error + Sync + Send>> {
+        Ok(match v.to_sql(ty, out)? {
+            IsNull::Yes => postgres_protocol::IsNull::Yes,
+            IsNull::No => postgres_protocol::IsNull::No,
+        })
+    };
+    Ok(match bound {
+        Bound::Included(v) => RangeBound::Inclusive(value(v)?),
+        Bound::Excluded(v) => RangeBound::Exclusive(value(v)?),
+        Bound::Unbounded => RangeBound::Unbounded,
+    })
+}
+
+impl<'a, T: FromSql<'a>> Range<T> {
+    /// Decodes a range whose bounds are of type `member`
+    pub(crate) fn from_sql_member(
+        member: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(match range_from_sql(raw)? {
+            RawRange::Empty => Range::Empty,
+            RawRange::Nonempty(lower, upper) => Range::Nonempty(
+                bound_from_sql(member, lower)?,
+                bound_from_sql(member, upper)?,
+            ),
+        })
+    }
+}
+
+impl<T: ToSql> Range<T> {
+    /// Encodes a range whose bounds are of type `member`
+    pub(crate) fn to_sql_member(
+        &self,
+        member: &Type,
+        out: &mut BytesMut,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        match self {
+            Range::Empty => empty_range_to_sql(out),
+            Range::Nonempty(lower, upper) => range_to_sql(
+                |out| bound_to_sql(member, lower, out),
+                |out| bound_to_sql(member, upper, out),
+                out,
+            )?,
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Range<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let member = match ty.kind() {
+            Kind::Range(member) => member,
+            _ => return Err(format!("unexpected type {ty}").into()),
+        };
+        Self::from_sql_member(member, raw)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match ty.kind() {
+            Kind::Range(member) => T::accepts(member),
+            _ => false,
+        }
+    }
+}
+
+impl<T: ToSql> ToSql for Range<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        let member = match ty.kind() {
+            Kind::Range(member) => member,
+            _ => return Err(format!("unexpected type {ty}").into()),
+        };
+        self.to_sql_member(member, out)?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        match ty.kind() {
+            Kind::Range(member) => T::accepts(member),
+            _ => false,
+        }
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl<T: serde_1::ser::Serialize> serde_1::ser::Serialize for Range<T> {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde_1::ser::SerializeTupleVariant;
+        match self {
+            Range::Empty => serializer.serialize_unit_variant("Range", 0, "Empty"),
+            Range::Nonempty(lower, upper) => {
+                let mut state = serializer.serialize_tuple_variant("Range", 1, "Nonempty", 2)?;
+                state.serialize_field(lower)?;
+                state.serialize_field(upper)?;
+                state.end()
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+/// The OID of a PostgreSQL object, read from an OID alias type like `regclass` or `regtype`
+///
+/// Those values are transferred as OIDs by the binary protocol, their textual name is only
+/// available by casting them to `text` in the query (`'author'::regclass::text`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RegOid(pub u32);
+
+impl From<u32> for RegOid {
+    fn from(value: u32) -> Self {
+        RegOid(value)
+    }
+}
+
+impl From<RegOid> for u32 {
+    fn from(value: RegOid) -> Self {
+        value.0
+    }
+}
+
+fn is_reg(ty: &Type) -> bool {
+    matches!(
+        *ty,
+        Type::REGCLASS
+            | Type::REGCOLLATION
+            | Type::REGCONFIG
+            | Type::REGDICTIONARY
+            | Type::REGNAMESPACE
+            | Type::REGOPER
+            | Type::REGOPERATOR
+            | Type::REGPROC
+            | Type::REGPROCEDURE
+            | Type::REGROLE
+            | Type::REGTYPE
+    )
+}
+
+impl<'a> FromSql<'a> for RegOid {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        u32::from_sql(&Type::OID, raw).map(RegOid)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        is_reg(ty)
+    }
+}
+
+impl ToSql for RegOid {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        self.0.to_sql(&Type::OID, out)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        is_reg(ty)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl serde_1::ser::Serialize for RegOid {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
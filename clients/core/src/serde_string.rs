@@ -0,0 +1,21 @@
+use serde_1::ser::Serializer;
+use std::fmt::Display;
+
+/// Serializes `value` as its string representation, for numbers too large for JavaScript
+pub fn serialize_string<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Same as [`serialize_string`], for nullable values
+pub fn serialize_option_string<T: Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.collect_str(value),
+        None => serializer.serialize_none(),
+    }
+}
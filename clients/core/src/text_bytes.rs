@@ -0,0 +1,70 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::{error::Error, ops::Deref};
+
+/// A PostgreSQL `text` or `varchar` value read as raw bytes
+///
+/// Databases using an encoding like `SQL_ASCII` can store text that is not
+/// valid UTF-8, which `&str` refuses to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextBytes<'a>(pub &'a [u8]);
+
+impl<'a> Deref for TextBytes<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> From<&'a [u8]> for TextBytes<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        TextBytes(value)
+    }
+}
+
+impl<'a> From<TextBytes<'a>> for Vec<u8> {
+    fn from(value: TextBytes<'a>) -> Self {
+        value.0.to_vec()
+    }
+}
+
+fn is_text(ty: &Type) -> bool {
+    matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME) || ty.name() == "citext"
+}
+
+impl<'a> FromSql<'a> for TextBytes<'a> {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(TextBytes(raw))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        is_text(ty)
+    }
+}
+
+impl<'a> ToSql for TextBytes<'a> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        out.extend_from_slice(self.0);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        is_text(ty)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl<'a> serde_1::ser::Serialize for TextBytes<'a> {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Like the owned `Vec<u8>`
+        serializer.collect_seq(self.0)
+    }
+}
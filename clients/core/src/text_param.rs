@@ -0,0 +1,34 @@
+use postgres_types::{private::BytesMut, Format, IsNull, ToSql, Type};
+use std::error::Error;
+
+use crate::StringSql;
+
+/// A parameter sent in its text representation, parsed by the database with the input function
+/// of the parameter's type, e.g. `TextParam("3 days")` for an `interval`.
+///
+/// Malformed values are rejected by the database when the query is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextParam<T>(pub T);
+
+impl<T: StringSql> ToSql for TextParam<T> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        // The binary form of a `text` value is its UTF-8 encoding
+        self.0.to_sql(&Type::TEXT, out)
+    }
+
+    fn accepts(_: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    fn encode_format(&self, _: &Type) -> Format {
+        Format::Text
+    }
+
+    postgres_types::to_sql_checked!();
+}
@@ -0,0 +1,89 @@
+use postgres_types::{private::BytesMut, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+/// Raw value of `infinity` in the binary format of `timestamp` and `timestamptz`
+const POS_INFINITY: i64 = i64::MAX;
+/// Raw value of `-infinity` in the binary format of `timestamp` and `timestamptz`
+const NEG_INFINITY: i64 = i64::MIN;
+
+/// A PostgreSQL `timestamp` or `timestamptz` value that can be infinite
+///
+/// Variants are declared in chronological order, so that comparisons
+/// place `-infinity` before and `infinity` after every finite value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Timestamp<T> {
+    /// `-infinity`, earlier than every other timestamp
+    NegInfinity,
+    /// A finite timestamp
+    Finite(T),
+    /// `infinity`, later than every other timestamp
+    PosInfinity,
+}
+
+impl<T> Timestamp<T> {
+    /// The finite value of this timestamp, if any
+    pub fn finite(self) -> Option<T> {
+        match self {
+            Timestamp::Finite(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Timestamp<T> {
+    fn from(value: T) -> Self {
+        Timestamp::Finite(value)
+    }
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Timestamp<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let micros = match <[u8; 8]>::try_from(raw) {
+            Ok(bytes) => i64::from_be_bytes(bytes),
+            Err(_) => return Err("invalid timestamp value".into()),
+        };
+        Ok(match micros {
+            POS_INFINITY => Timestamp::PosInfinity,
+            NEG_INFINITY => Timestamp::NegInfinity,
+            _ => Timestamp::Finite(T::from_sql(ty, raw)?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ) && T::accepts(ty)
+    }
+}
+
+impl<T: ToSql> ToSql for Timestamp<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        match self {
+            Timestamp::NegInfinity => out.extend_from_slice(&NEG_INFINITY.to_be_bytes()),
+            Timestamp::Finite(value) => return value.to_sql(ty, out),
+            Timestamp::PosInfinity => out.extend_from_slice(&POS_INFINITY.to_be_bytes()),
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ) && T::accepts(ty)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "with-serde_json-1")]
+impl<T: serde_1::ser::Serialize> serde_1::ser::Serialize for Timestamp<T> {
+    fn serialize<S: serde_1::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Timestamp::NegInfinity => serializer.serialize_str("-infinity"),
+            Timestamp::Finite(value) => value.serialize(serializer),
+            Timestamp::PosInfinity => serializer.serialize_str("infinity"),
+        }
+    }
+}
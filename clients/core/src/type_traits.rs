@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
-use crate::domain::escape_domain_to_sql;
+use crate::{domain::escape_domain_to_sql, NumericF64, TextParam};
 use postgres_protocol::types::{self, ArrayDimension};
 use postgres_types::{private::BytesMut, to_sql_checked, IsNull, Kind, ToSql, Type};
 
@@ -11,11 +11,21 @@ impl StringSql for &str {}
 impl StringSql for Cow<'_, str> {}
 impl StringSql for Box<str> {}
 
+/// `numeric` mapped to `f64`, or bound exactly from its text representation
+pub trait NumericSql: std::fmt::Debug + ToSql + Sync {}
+impl<T: NumericSql> NumericSql for &T {}
+impl NumericSql for NumericF64 {}
+impl<T: StringSql> NumericSql for TextParam<T> {}
+
 pub trait BytesSql: std::fmt::Debug + ToSql + Send + Sync {}
 impl<T: BytesSql> BytesSql for &T {}
 impl BytesSql for Vec<u8> {}
 impl BytesSql for &[u8] {}
 
+pub trait HstoreSql: std::fmt::Debug + ToSql + Send + Sync {}
+impl<T: HstoreSql> HstoreSql for &T {}
+impl HstoreSql for HashMap<String, Option<String>> {}
+
 #[cfg(feature = "with-serde_json-1")]
 pub trait JsonSql: std::fmt::Debug + ToSql + Sync + Send {}
 #[cfg(feature = "with-serde_json-1")]
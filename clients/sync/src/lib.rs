@@ -1,13 +1,33 @@
 #[doc(hidden)]
 pub mod private;
 
-pub use cornucopia_client_core::{ArrayIterator, ArraySql, BytesSql, IterSql, StringSql};
+pub use cornucopia_client_core::{
+    ArityError, ArrayIterator, ArraySql, BytesSql, HstoreIterator, HstoreSql, IterSql, JsonPath,
+    Multirange, MultirangeIterator, NumericF64, NumericSql, OrderedF32, OrderedF64, Range, RegOid,
+    StringSql, TextBytes, TextParam, Timestamp,
+};
 
 #[cfg(feature = "with-serde_json-1")]
 pub use cornucopia_client_core::JsonSql;
 
 /// This trait allows you to bind parameters to a query using a single
 /// struct, rather than passing each bind parameter as a function parameter.
+///
+/// Params are only borrowed, so the same struct can be reused to run a
+/// statement several times, without being copied or cloned:
+///
+/// ```ignore
+/// let params = InsertBookParams { author: None, name: "Necronomicon" };
+/// let mut stmt = insert_book();
+/// for _ in 0..3 {
+///     stmt.params(&mut client, &params)?;
+/// }
+/// ```
 pub trait Params<'a, P, O, C> {
     fn params(&'a mut self, client: &'a mut C, params: &'a P) -> O;
 }
+
+/// Checks that the connection is usable by running `SELECT 1`, e.g. for readiness probes.
+pub fn ping<C: postgres::GenericClient>(client: &mut C) -> Result<(), postgres::Error> {
+    client.execute("SELECT 1", &[]).map(|_| ())
+}
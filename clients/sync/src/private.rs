@@ -1,4 +1,9 @@
-pub use cornucopia_client_core::{slice_iter, Domain, DomainArray};
+pub use cornucopia_client_core::{
+    composite_accepts, read_composite, slice_iter, write_composite, Domain, DomainArray,
+};
+
+#[cfg(feature = "with-serde_json-1")]
+pub use cornucopia_client_core::{serialize_option_string, serialize_string};
 
 use postgres::Statement;
 
@@ -17,6 +22,18 @@ impl Stmt {
         }
     }
 
+    /// SQL of this statement
+    #[must_use]
+    pub fn query(&self) -> &'static str {
+        self.query
+    }
+
+    /// Prepared statement, once this statement was prepared
+    #[must_use]
+    pub fn statement(&self) -> Option<&Statement> {
+        self.cached.as_ref()
+    }
+
     pub fn prepare<'a, C: postgres::GenericClient>(
         &'a mut self,
         client: &mut C,
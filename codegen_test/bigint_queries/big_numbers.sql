@@ -0,0 +1,2 @@
+--! big_numbers : (maybe?)
+SELECT 9007199254740993::int8 AS id, 1.50::numeric AS amount, NULL::int8 AS maybe, 7 AS small;
@@ -0,0 +1,5 @@
+--! select_reordered
+SELECT * FROM reordered;
+
+--! reordered_names
+SELECT name FROM reordered;
@@ -0,0 +1,15 @@
+--! select_cow_book: (author?)
+SELECT * FROM book;
+
+--! select_cow_nightmare_domain: (txt?, json?, nb?, arr?[?], composite?)
+SELECT * FROM nightmare_domain;
+
+--! select_cow_copy
+SELECT * FROM copy;
+
+--! select_cow_everything_array
+SELECT text_, bytea_, int_ FROM EverythingArray;
+
+--! select_cow_grouped_book: (info_author?)
+--# group info
+SELECT name AS info_name, author AS info_author FROM book;
@@ -0,0 +1,41 @@
+--: view purchase_summary
+
+--! insert_customer
+INSERT INTO customer (email, name, vip) VALUES (:email, :name, :vip) RETURNING id;
+
+--! insert_product
+INSERT INTO product (label, price, weight, tags) VALUES (:label, :price, :weight, :tags) RETURNING *;
+
+--! insert_purchase
+INSERT INTO purchase (customer_id, product_id, quantity, status, reference, placed_on)
+  VALUES (:customer_id, :product_id, :quantity, :status, :reference, :placed_on);
+
+--! customer_by_email :one
+SELECT * FROM customer WHERE email = :email;
+
+--! purchases_of_customer : (note?)
+SELECT p.id, p.quantity, p.status, p.note, c.name AS customer, pr.label product, pr.price
+FROM purchase p
+JOIN customer c ON c.id = p.customer_id
+JOIN product pr ON pr.id = p.product_id
+WHERE c.id = :customer_id AND p.status IN (:first_status, :second_status)
+ORDER BY p.id
+LIMIT :limit;
+
+--! purchases_by_status
+SELECT id, reference, placed_on FROM purchase WHERE status = ANY(:statuses) ORDER BY id;
+
+--! products_in_price_range : (weight?)
+SELECT id, label, weight, tags FROM product WHERE price BETWEEN :min AND :max AND label ILIKE :pattern;
+
+--! count_vip_customers
+SELECT count(*) AS vip_count FROM customer WHERE vip AND created_at < :before;
+
+--! summary_labels
+SELECT label, quantity::bigint AS quantity, :suffix::text AS suffix FROM purchase_summary WHERE status <> :status;
+
+--! ship_purchase
+UPDATE purchase SET status = 'shipped', note = :note WHERE id = :id RETURNING status;
+
+--! delete_purchases
+DELETE FROM purchase p USING customer c WHERE c.id = p.customer_id AND c.email = :email;
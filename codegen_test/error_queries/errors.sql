@@ -0,0 +1,8 @@
+--! author_by_id : AuthorRow()
+SELECT id FROM author WHERE id = :id;
+
+--! author_ids
+SELECT id FROM author;
+
+--! insert_author
+INSERT INTO author (id) VALUES (:id);
@@ -0,0 +1,5 @@
+--! float_echo
+SELECT :value::float8 AS value;
+
+--! float_values
+SELECT 'NaN'::float4 AS nan, '-0'::float8 AS zero, ARRAY[1.5, 'NaN']::float8[] AS list;
@@ -0,0 +1,8 @@
+--! insert_task
+INSERT INTO task (name, priority) VALUES (:name, :priority);
+
+--! tasks
+SELECT name, priority, ARRAY[name] AS tags FROM task;
+
+--! scored_tasks
+SELECT name, 1.5::float8 AS score FROM task;
@@ -0,0 +1,6 @@
+--! insert_subscription
+INSERT INTO subscription (id, since, until) VALUES (:id, :since, :until);
+--! subscriptions
+SELECT * FROM subscription ORDER BY id;
+--! open_ended_ids
+SELECT id FROM subscription WHERE until = 'infinity' ORDER BY id;
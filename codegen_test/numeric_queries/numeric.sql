@@ -0,0 +1,11 @@
+--! numeric_echo
+SELECT :value::numeric AS value;
+
+--! numeric_values
+SELECT '123456789.000123'::numeric AS big, '-0.00042'::numeric AS small, 'NaN'::numeric AS nan, ARRAY[1.5, 0, -20000]::numeric[] AS list;
+
+--! float_echo
+SELECT :value::float8 AS value;
+
+--! numeric_doubled
+SELECT (:amount::numeric * 2)::text AS doubled;
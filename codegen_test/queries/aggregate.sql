@@ -0,0 +1,5 @@
+--! count_authors
+SELECT COUNT(*) FROM author;
+
+--! named_price_stats: (total?)
+SELECT SUM(price)::numeric AS total, COUNT(*) AS count FROM named;
@@ -0,0 +1,2 @@
+--! echo_altered
+SELECT :altered::altered_composite AS altered;
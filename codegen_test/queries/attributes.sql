@@ -0,0 +1,13 @@
+--! books_by_author AuthorFilter(): AttributeBook(author?)
+--# params derive(Clone)
+--# row derive(Eq, Hash)
+SELECT name, author FROM Book WHERE author = :author;
+
+--! all_attribute_books: AttributeBook(author?)
+--# row derive(Eq, Hash)
+SELECT name, author FROM Book;
+
+--! mapped_books: (author?)
+--# column name: crate::Title = crate::Title::from
+--# column author: crate::Title = crate::Title::from
+SELECT name, author FROM Book WHERE author = :author;
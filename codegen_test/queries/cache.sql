@@ -0,0 +1,6 @@
+--! insert_country
+INSERT INTO country (code, name) VALUES (:code, :name);
+
+--! countries
+--# cache code
+SELECT code, name FROM country;
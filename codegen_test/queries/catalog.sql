@@ -0,0 +1,5 @@
+--! author_table
+SELECT 'author'::regclass AS table_oid, 'author'::regclass::text AS table_name, 'int4'::regtype AS type_oid;
+
+--! table_name
+SELECT :table_oid::regclass::text AS name;
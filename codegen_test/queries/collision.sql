@@ -0,0 +1,2 @@
+--! select_book
+SELECT name FROM book;
@@ -8,4 +8,25 @@ SELECT * FROM clone;
 INSERT INTO copy (composite) VALUES (:composite);
 
 --! select_copy
-SELECT * FROM copy;
\ No newline at end of file
+SELECT * FROM copy;
+
+--! select_copy_enum
+SELECT 42 AS id, 'Squidward'::spongebob_character AS character;
+
+--! select_copy_domain
+SELECT 42::copy_domain AS nb, 'Squidward'::spongebob_character AS character;
+
+--! select_copy_nested
+SELECT (42, 4.2)::copy_composite AS composite, 42::copy_domain AS nb;
+
+--! select_clone_enum_array
+SELECT 42 AS id, ARRAY['Squidward'::spongebob_character] AS characters;
+
+--! insert_clones
+INSERT INTO clone (composite) SELECT c FROM UNNEST(:composites::clone_composite[]) AS c;
+
+--! insert_copies
+INSERT INTO copy (composite) SELECT c FROM UNNEST(:composites::copy_composite[]) AS c;
+
+--! select_characters_in
+SELECT c AS character FROM UNNEST(ENUM_RANGE(NULL::spongebob_character)) AS c WHERE c = ANY(:characters::spongebob_character[]);
@@ -0,0 +1,9 @@
+--! insert_post (subtitle?)
+INSERT INTO post (author_id, title, subtitle, created_at)
+    VALUES (:author_id, :title, :subtitle, :created_at);
+
+--! latest_posts : (subtitle?)
+SELECT DISTINCT ON (author_id) author_id, title, subtitle, created_at
+FROM post
+WHERE created_at <= :before
+ORDER BY author_id, created_at DESC;
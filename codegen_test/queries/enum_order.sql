@@ -0,0 +1,5 @@
+--! insert_task
+INSERT INTO task (name, priority) VALUES (:name, :priority);
+
+--! tasks_by_priority
+SELECT priority FROM task ORDER BY priority, name;
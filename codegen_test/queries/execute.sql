@@ -0,0 +1,10 @@
+--! set_marker
+SELECT set_config('cornucopia.marker', :value, false);
+--! marker
+SELECT current_setting('cornucopia.marker');
+--! lock_advisory
+--# execute
+SELECT pg_advisory_lock(:key);
+--! unlock_advisory
+--# execute
+SELECT pg_advisory_unlock(:key);
@@ -0,0 +1,7 @@
+--! author_by_id
+--# explain
+SELECT id FROM author WHERE id = :id;
+
+--! insert_author_id
+--# explain
+INSERT INTO author (id) VALUES (:id);
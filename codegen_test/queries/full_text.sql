@@ -0,0 +1,13 @@
+--! insert_article
+INSERT INTO article (title, body) VALUES (:title, :body);
+
+--! search_articles
+SELECT title, ts_rank(to_tsvector('english', body), query) AS rank
+FROM article, plainto_tsquery('english', :terms) query
+WHERE to_tsvector('english', body) @@ query
+ORDER BY rank DESC, title;
+
+--! search_articles_cd
+SELECT title, ts_rank_cd(to_tsvector('english', body), plainto_tsquery('english', :terms)) AS rank
+FROM article
+ORDER BY rank DESC, title;
@@ -0,0 +1,4 @@
+--# module cfg(feature = "gated")
+
+--! gated_author_ids
+SELECT id FROM author ORDER BY id;
@@ -0,0 +1,12 @@
+--! insert_group_named (price?)
+INSERT INTO named (name, price, show) VALUES (:name, :price, :show);
+--! named_books: NamedBook(named_price?, book_author?)
+--# group named
+--# group book
+SELECT n.id AS named_id, n.name AS named_name, n.price AS named_price, n.show,
+    b.name AS book_name, b.author AS book_author
+FROM named n JOIN Book b ON b.author = n.name
+ORDER BY b.name;
+--! named_flags: NamedFlags(flag_price?)
+--# group flag
+SELECT id, show AS flag_show, price AS flag_price FROM named ORDER BY id;
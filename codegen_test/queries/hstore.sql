@@ -0,0 +1,5 @@
+--! insert_setting
+INSERT INTO setting (name, opts) VALUES (:name, :opts);
+
+--! select_settings
+SELECT * FROM setting;
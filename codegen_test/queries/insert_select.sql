@@ -0,0 +1,8 @@
+--! copy_books_of
+INSERT INTO book (name, author) SELECT name, author FROM book WHERE author = :author;
+
+--! copy_books_of_returning
+INSERT INTO book (name, author) SELECT name, author FROM book WHERE author = :author RETURNING name;
+
+--! snapshot_books
+SELECT name, author INTO TEMPORARY book_snapshot FROM book;
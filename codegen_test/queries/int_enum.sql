@@ -0,0 +1,8 @@
+--: enum BookStatus(Draft = 1, Published = 2, Archived = -1)
+--: enum Importance(Low = 0, Urgent = 100000)
+
+--! insert_draft (status: BookStatus, previous_status?: BookStatus, priority: Importance)
+INSERT INTO draft (title, status, previous_status, priority) VALUES (:title, :status, :previous_status, :priority);
+
+--! select_draft: (status: BookStatus, previous_status?: BookStatus, priority: Importance)
+SELECT * FROM draft;
@@ -0,0 +1,19 @@
+--! insert_document
+INSERT INTO document (data) VALUES (:data);
+
+--! select_documents_containing
+SELECT data FROM document WHERE data @> :filter;
+
+--! select_document_fields: (author?)
+SELECT data ->> 'title' AS title, data -> 'tags' AS tags, data #>> '{meta,author}' AS author
+FROM document
+WHERE data ? :key;
+
+--! insert_document_path
+INSERT INTO document_path (name, path) VALUES (:name, :path);
+
+--! select_document_paths
+SELECT name, path FROM document_path;
+
+--! query_documents
+SELECT jsonb_path_query(data, :path) AS value FROM document;
@@ -0,0 +1,7 @@
+--# default nullable
+
+--! nullable_books
+SELECT name, author FROM book;
+
+--! nullable_books_override: (name!)
+SELECT name, author FROM book;
@@ -1,8 +1,17 @@
 --: Nullity(texts[?], composite?)
 --: NullityParams(texts[?], composite?)
 --: nullity_composite(jsons?[?])
+--: tagged(tags[?])
 
 --! new_nullity NullityParams
 INSERT INTO nullity(texts, name, composite) VALUES (:texts, :name, :composite); 
 --! nullity: Nullity
 SELECT * FROM nullity;
+--! new_nullable_texts (texts?)
+INSERT INTO nullity(texts, name) VALUES (:texts, :name);
+--! nullable_texts: (texts?)
+SELECT name, texts FROM nullity WHERE name LIKE 'texts %' ORDER BY name;
+--! new_tagged
+INSERT INTO tagged_item(tagged) VALUES (:tagged);
+--! select_tagged
+SELECT tagged FROM tagged_item;
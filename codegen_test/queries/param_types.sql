@@ -0,0 +1,5 @@
+--! case_value (value::int4) : (value?)
+SELECT CASE WHEN :value IS NOT NULL THEN :value END AS value;
+
+--! echo_ids (ids::int8[])
+SELECT :ids AS ids;
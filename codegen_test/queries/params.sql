@@ -11,4 +11,30 @@ SELECT * FROM book WHERE name = ANY (:title);
 UPDATE book SET name = :name WHERE length(name) > 42 AND length(:name) < 42;
 
 --! params_order
-UPDATE imaginary SET c=:c, a=:a, z=:a, r=:c;
\ No newline at end of file
+UPDATE imaginary SET c=:c, a=:a, z=:a, r=:c;
+
+--: RowOrder()
+
+--! row_order: RowOrder
+SELECT 'z' AS z, 'r' AS r, 'c' AS c, 'a' AS a;
+
+--! row_order_shuffled: RowOrder
+SELECT 'a' AS a, 'c' AS c, 'r' AS r, 'z' AS z;
+
+--! select_book_by_name: :one
+SELECT * FROM book WHERE name = :name LIMIT 1;
+
+--! select_author_by_id :one
+SELECT * FROM author AS a WHERE a.id = :id;
+
+--! insert_session
+INSERT INTO session (name) VALUES (:name) RETURNING id;
+
+--! session_name_by_id
+SELECT name FROM session WHERE id = :id;
+
+--! book_names_limited
+SELECT name FROM book ORDER BY name LIMIT 10;
+
+--! book_names_nested_limit
+SELECT name FROM book WHERE name IN (SELECT name FROM book LIMIT 10);
@@ -0,0 +1,5 @@
+--! insert_attempt
+INSERT INTO attempt (outcome) VALUES (:outcome);
+
+--! select_attempts
+SELECT * FROM attempt;
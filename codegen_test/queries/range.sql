@@ -0,0 +1,14 @@
+--! insert_booking
+INSERT INTO booking (room, during) VALUES (:room, :during);
+
+--! select_overlapping_bookings
+SELECT * FROM booking WHERE during && :during;
+
+--! select_ranges
+SELECT int4range(1, 10) AS int4, int8range(NULL, 10, '(]') AS int8, 'empty'::daterange AS date;
+
+--! select_multiranges
+SELECT int4multirange(int4range(1, 5), int4range(10, 20)) AS int4, '{}'::tstzmultirange AS tstz;
+
+--! echo_multirange
+SELECT :ranges::int4multirange AS ranges;
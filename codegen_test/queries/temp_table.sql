@@ -0,0 +1,4 @@
+--! book_counts
+--# setup CREATE TEMP TABLE book_count AS
+--# setup SELECT author, count(*) AS books FROM book GROUP BY author;
+SELECT author, books FROM book_count ORDER BY author;
@@ -0,0 +1,5 @@
+--! interval_from_text
+SELECT :delay::interval::text AS delay;
+
+--! nullable_interval_from_text (delay?)
+SELECT (now() + :delay::interval) IS NULL AS is_null;
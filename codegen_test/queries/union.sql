@@ -0,0 +1,7 @@
+--! insert_union_book (author?)
+INSERT INTO Book (name, author) VALUES (:name, :author);
+--! union_books: UnionBook(name!, author?)
+SELECT name, author FROM Book WHERE name LIKE 'Union%' AND author IS NOT NULL
+UNION ALL
+SELECT name, NULL FROM Book WHERE name LIKE 'Union%' AND author IS NULL
+ORDER BY name;
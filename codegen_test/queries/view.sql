@@ -0,0 +1,5 @@
+--: view book_summary(author?, name_len?)
+--: view book_count
+
+--! refresh_book_count
+REFRESH MATERIALIZED VIEW book_count;
@@ -79,6 +79,14 @@ CREATE TABLE nullity (
     composite nullity_composite
 );
 
+CREATE TYPE tagged AS (
+    tags TEXT[]
+);
+
+CREATE TABLE tagged_item (
+    tagged tagged NOT NULL
+);
+
 -- Params
 
 CREATE TABLE Book (
@@ -200,3 +208,164 @@ CREATE TABLE Syntax (
     async syntax_composite,
     enum syntax_enum
 );
+
+-- Int enum
+
+CREATE TABLE draft (
+    title TEXT NOT NULL,
+    status SMALLINT NOT NULL,
+    previous_status SMALLINT,
+    priority INTEGER NOT NULL
+);
+
+-- Foreign keys
+
+CREATE TABLE author (
+    id INT PRIMARY KEY
+);
+CREATE TABLE edition (
+    isbn TEXT,
+    number INT,
+    PRIMARY KEY (isbn, number)
+);
+CREATE TABLE book_author (
+    author_id INT NOT NULL REFERENCES author (id),
+    isbn TEXT NOT NULL,
+    edition INT NOT NULL,
+    FOREIGN KEY (isbn, edition) REFERENCES edition (isbn, number)
+);
+
+-- View
+
+CREATE VIEW book_summary AS SELECT name, author, length(name) AS name_len FROM Book;
+CREATE MATERIALIZED VIEW book_count AS SELECT count(*) AS count FROM Book;
+
+-- Range
+
+CREATE TABLE booking (
+    room TEXT NOT NULL,
+    during TSTZRANGE NOT NULL
+);
+
+-- Hstore
+
+CREATE EXTENSION IF NOT EXISTS hstore;
+
+CREATE TABLE setting (
+    name TEXT NOT NULL,
+    opts HSTORE NOT NULL
+);
+
+-- Prelude collision
+
+CREATE TYPE result AS ENUM ('Success', 'Failure');
+CREATE TYPE outcome AS (
+    result result,
+    message TEXT
+);
+
+CREATE TABLE attempt (
+    outcome outcome NOT NULL
+);
+
+-- Jsonb operators
+
+CREATE TABLE document (
+    data JSONB NOT NULL
+);
+
+CREATE TABLE document_path (
+    name TEXT NOT NULL,
+    path JSONPATH NOT NULL
+);
+
+-- Enum ordering
+
+CREATE TYPE priority AS ENUM ('low', 'medium', 'high');
+-- Values added later, as a migration would, are generated in their sort order
+ALTER TYPE priority ADD VALUE 'urgent';
+ALTER TYPE priority ADD VALUE 'trivial' BEFORE 'low';
+
+CREATE TABLE task (
+    name TEXT NOT NULL,
+    priority priority NOT NULL
+);
+
+-- Uuid primary key
+
+CREATE TABLE session (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL
+);
+
+-- Distinct on
+
+CREATE TABLE post (
+    author_id INT NOT NULL,
+    title TEXT NOT NULL,
+    subtitle TEXT,
+    created_at TIMESTAMP NOT NULL
+);
+
+-- Cache
+
+CREATE TABLE country (
+    code TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+-- Search path
+
+DROP SCHEMA IF EXISTS tenant CASCADE;
+CREATE SCHEMA tenant;
+
+CREATE TYPE tenant.entry_kind AS ENUM ('debit', 'credit');
+
+CREATE TABLE tenant.ledger (
+    id SERIAL PRIMARY KEY,
+    kind tenant.entry_kind NOT NULL,
+    amount INT NOT NULL
+);
+
+-- Altered composite
+
+CREATE TYPE altered_composite AS (
+    name TEXT,
+    id INT
+);
+-- Re-adding an attribute moves it to the end, as a migration would
+ALTER TYPE altered_composite DROP ATTRIBUTE name;
+ALTER TYPE altered_composite ADD ATTRIBUTE name TEXT;
+ALTER TYPE altered_composite ADD ATTRIBUTE tags TEXT[];
+
+-- Columns by name
+
+CREATE TABLE reordered (
+    id INT NOT NULL,
+    name TEXT NOT NULL
+);
+
+-- Text read as raw bytes
+
+CREATE TABLE legacy_text (
+    id INT NOT NULL,
+    name TEXT NOT NULL,
+    note VARCHAR,
+    tags TEXT[] NOT NULL
+);
+
+-- Infinite timestamps
+
+CREATE TABLE subscription (
+    id INT NOT NULL,
+    since TIMESTAMPTZ NOT NULL,
+    until TIMESTAMP NOT NULL
+);
+
+-- Full-text search
+
+CREATE TABLE article (
+    id SERIAL PRIMARY KEY,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL
+);
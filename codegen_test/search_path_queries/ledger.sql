@@ -0,0 +1,5 @@
+--! insert_entry
+INSERT INTO ledger (kind, amount) VALUES (:kind, :amount);
+
+--! entries
+SELECT kind, amount FROM ledger ORDER BY id;
@@ -0,0 +1,2 @@
+--! serde_row
+SELECT 'Necronomicon' AS "book.name", 'Bob'::spongebob_character AS character;
@@ -0,0 +1,4 @@
+--! insert_split_book (author?)
+INSERT INTO Book (name, author) VALUES (:name, :author);
+--! split_books: SplitBook(author?)
+SELECT name, author FROM Book WHERE name LIKE 'Split%' ORDER BY name;
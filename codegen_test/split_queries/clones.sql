@@ -0,0 +1,2 @@
+--! split_clones
+SELECT composite FROM clone;
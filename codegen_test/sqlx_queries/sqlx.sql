@@ -0,0 +1,2 @@
+--! posts : (subtitle?)
+SELECT author_id, title AS "post:title", subtitle, created_at FROM post;
@@ -0,0 +1,156 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod sqlx {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Posts {
+            /// `int4`
+            pub author_id: i32,
+            /// `text`
+            pub post_title: String,
+            /// `text`, nullable
+            pub subtitle: Option<String>,
+            /// `timestamp`
+            pub created_at: time::PrimitiveDateTime,
+        }
+        pub struct PostsBorrowed<'a> {
+            /// `int4`
+            pub author_id: i32,
+            /// `text`
+            pub post_title: &'a str,
+            /// `text`, nullable
+            pub subtitle: Option<&'a str>,
+            /// `timestamp`
+            pub created_at: time::PrimitiveDateTime,
+        }
+        impl<'a> From<PostsBorrowed<'a>> for Posts {
+            #[inline]
+            fn from(
+                PostsBorrowed {
+                    author_id,
+                    post_title,
+                    subtitle,
+                    created_at,
+                }: PostsBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    author_id,
+                    post_title: post_title.into(),
+                    subtitle: subtitle.map(|v| v.into()),
+                    created_at,
+                }
+            }
+        }
+        use cornucopia_async::GenericClient;
+        use futures::{StreamExt, TryStreamExt};
+        pub struct PostsQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_async::private::Stmt,
+            extractor: fn(&tokio_postgres::Row) -> PostsBorrowed,
+            mapper: fn(PostsBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PostsQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(PostsBorrowed) -> R) -> PostsQuery<'a, C, R, N> {
+                PostsQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                let row = self.client.query_one(stmt, &self.params).await?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
+            }
+            pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)
+                    .await?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
+            pub async fn iter(
+                self,
+            ) -> Result<
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
+                tokio_postgres::Error,
+            > {
+                let stmt = self.stmt.prepare(self.client).await?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_async::private::slice_iter(&self.params))
+                    .await?
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))))
+                    .into_stream();
+                Ok(it)
+            }
+        }
+        pub fn posts() -> PostsStmt {
+            PostsStmt(cornucopia_async::private::Stmt::new(
+                "SELECT author_id, title AS \"post:title\", subtitle, created_at FROM post",
+            ))
+        }
+        pub struct PostsStmt(cornucopia_async::private::Stmt);
+        impl PostsStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a C,
+            ) -> PostsQuery<'a, C, Posts, 0> {
+                PostsQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| PostsBorrowed {
+                        author_id: row.get(0),
+                        post_title: row.get(1),
+                        subtitle: row.get(2),
+                        created_at: row.get(3),
+                    },
+                    mapper: |it| <Posts>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod big_numbers {
+        #[derive(serde::Serialize, Debug, Clone, PartialEq, Copy)]
+        pub struct BigNumbers {
+            /// `int8`
+            #[serde(serialize_with = "cornucopia_sync::private::serialize_string")]
+            pub id: i64,
+            /// `numeric`
+            #[serde(serialize_with = "cornucopia_sync::private::serialize_string")]
+            pub amount: rust_decimal::Decimal,
+            /// `int8`, nullable
+            #[serde(serialize_with = "cornucopia_sync::private::serialize_option_string")]
+            pub maybe: Option<i64>,
+            /// `int4`
+            pub small: i32,
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct BigNumbersQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> BigNumbers,
+            mapper: fn(BigNumbers) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> BigNumbersQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(BigNumbers) -> R) -> BigNumbersQuery<'a, C, R, N> {
+                BigNumbersQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn big_numbers() -> BigNumbersStmt {
+            BigNumbersStmt(cornucopia_sync::private::Stmt::new("SELECT 9007199254740993::int8 AS id, 1.50::numeric AS amount, NULL::int8 AS maybe, 7 AS small"))
+        }
+        pub struct BigNumbersStmt(cornucopia_sync::private::Stmt);
+        impl BigNumbersStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> BigNumbersQuery<'a, C, BigNumbers, 0> {
+                BigNumbersQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| BigNumbers {
+                        id: row.get(0),
+                        amount: row.get(1),
+                        maybe: row.get(2),
+                        small: row.get(3),
+                    },
+                    mapper: |it| <BigNumbers>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
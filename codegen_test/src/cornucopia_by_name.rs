@@ -0,0 +1,216 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod by_name {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectReordered {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub name: String,
+        }
+        pub struct SelectReorderedBorrowed<'a> {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub name: &'a str,
+        }
+        impl<'a> From<SelectReorderedBorrowed<'a>> for SelectReordered {
+            #[inline]
+            fn from(SelectReorderedBorrowed { id, name }: SelectReorderedBorrowed<'a>) -> Self {
+                Self {
+                    id,
+                    name: name.into(),
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct SelectReorderedQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SelectReorderedBorrowed,
+            mapper: fn(SelectReorderedBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SelectReorderedQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(SelectReorderedBorrowed) -> R,
+            ) -> SelectReorderedQuery<'a, C, R, N> {
+                SelectReorderedQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct StringQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> &str,
+            mapper: fn(&str) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> StringQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(&str) -> R) -> StringQuery<'a, C, R, N> {
+                StringQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn select_reordered() -> SelectReorderedStmt {
+            SelectReorderedStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT * FROM reordered",
+            ))
+        }
+        pub struct SelectReorderedStmt(cornucopia_sync::private::Stmt);
+        impl SelectReorderedStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SelectReorderedQuery<'a, C, SelectReordered, 0> {
+                SelectReorderedQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| SelectReorderedBorrowed {
+                        id: row.get("id"),
+                        name: row.get("name"),
+                    },
+                    mapper: |it| <SelectReordered>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn reordered_names() -> ReorderedNamesStmt {
+            ReorderedNamesStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT name FROM reordered",
+            ))
+        }
+        pub struct ReorderedNamesStmt(cornucopia_sync::private::Stmt);
+        impl ReorderedNamesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> StringQuery<'a, C, String, 0> {
+                StringQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get("name"),
+                    mapper: |it| it.into(),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,787 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {
+    pub mod public {
+        #[derive(Debug, postgres_types::FromSql, Copy, Clone, PartialEq)]
+        #[postgres(name = "copy_composite")]
+        pub struct CopyComposite {
+            #[postgres(name = "first")]
+            pub first: i32,
+            #[postgres(name = "second")]
+            pub second: f64,
+        }
+        impl<'a> postgres_types::ToSql for CopyComposite {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let CopyComposite { first, second } = self;
+                cornucopia_sync::private::write_composite(ty, out, |field, out| {
+                    match field.name() {
+                        "first" => postgres_types::ToSql::to_sql(first, field.type_(), out),
+                        "second" => postgres_types::ToSql::to_sql(second, field.type_(), out),
+                        _ => unreachable!(),
+                    }
+                })
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                cornucopia_sync::private::composite_accepts(ty, "copy_composite", 2, |f| {
+                    match f.name() {
+                        "first" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
+                        "second" => <f64 as postgres_types::ToSql>::accepts(f.type_()),
+                        _ => false,
+                    }
+                })
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+        #[derive(Debug, postgres_types :: FromSql, Clone, PartialEq)]
+        #[postgres(name = "domain_composite")]
+        pub struct DomainComposite {
+            #[postgres(name = "txt")]
+            pub txt: std::string::String,
+            #[postgres(name = "json")]
+            pub json: serde_json::Value,
+            #[postgres(name = "nb")]
+            pub nb: i32,
+            #[postgres(name = "arr")]
+            pub arr: std::vec::Vec<serde_json::Value>,
+        }
+        #[derive(Debug)]
+        pub struct DomainCompositeBorrowed<'a> {
+            pub txt: &'a str,
+            pub json: postgres_types::Json<&'a serde_json::value::RawValue>,
+            pub nb: i32,
+            pub arr: cornucopia_sync::ArrayIterator<
+                'a,
+                postgres_types::Json<&'a serde_json::value::RawValue>,
+            >,
+        }
+        impl<'a> std::convert::From<DomainCompositeBorrowed<'a>> for DomainComposite {
+            #[inline]
+            fn from(
+                DomainCompositeBorrowed { txt, json, nb, arr }: DomainCompositeBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    txt: txt.into(),
+                    json: serde_json::from_str(json.0.get()).unwrap(),
+                    nb,
+                    arr: arr
+                        .map(|v| serde_json::from_str(v.0.get()).unwrap())
+                        .collect(),
+                }
+            }
+        }
+        impl<'a> postgres_types::FromSql<'a> for DomainCompositeBorrowed<'a> {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                out: &'a [u8],
+            ) -> std::result::Result<
+                DomainCompositeBorrowed<'a>,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let mut txt = None;
+                let mut json = None;
+                let mut nb = None;
+                let mut arr = None;
+                cornucopia_sync::private::read_composite(ty, out, |field, out| {
+                    match field.name() {
+                        "txt" => {
+                            txt = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        "json" => {
+                            json = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        "nb" => nb = Some(postgres_types::private::read_value(field.type_(), out)?),
+                        "arr" => {
+                            arr = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        _ => {
+                            return std::result::Result::Err(std::convert::Into::into(format!(
+                                "unexpected field: {}",
+                                field.name()
+                            )))
+                        }
+                    }
+                    std::result::Result::Ok(())
+                })?;
+                std::result::Result::Ok(DomainCompositeBorrowed {
+                    txt: txt.ok_or("missing field: txt")?,
+                    json: json.ok_or("missing field: json")?,
+                    nb: nb.ok_or("missing field: nb")?,
+                    arr: arr.ok_or("missing field: arr")?,
+                })
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                ty.name() == "domain_composite" && ty.schema() == "public"
+            }
+        }
+        #[derive(Debug)]
+        pub struct DomainCompositeParams<'a> {
+            pub txt: &'a str,
+            pub json: &'a serde_json::value::Value,
+            pub nb: i32,
+            pub arr: &'a [&'a serde_json::value::Value],
+        }
+        impl<'a> postgres_types::ToSql for DomainCompositeParams<'a> {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let DomainCompositeParams { txt, json, nb, arr } = self;
+                cornucopia_sync::private::write_composite(ty, out, |field, out| {
+                    match field.name() {
+                        "txt" => postgres_types::ToSql::to_sql(
+                            &cornucopia_sync::private::Domain(txt),
+                            field.type_(),
+                            out,
+                        ),
+                        "json" => postgres_types::ToSql::to_sql(
+                            &cornucopia_sync::private::Domain(json),
+                            field.type_(),
+                            out,
+                        ),
+                        "nb" => postgres_types::ToSql::to_sql(
+                            &cornucopia_sync::private::Domain(nb),
+                            field.type_(),
+                            out,
+                        ),
+                        "arr" => postgres_types::ToSql::to_sql(
+                            &cornucopia_sync::private::Domain(
+                                &cornucopia_sync::private::DomainArray(arr),
+                            ),
+                            field.type_(),
+                            out,
+                        ),
+                        _ => unreachable!(),
+                    }
+                })
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                cornucopia_sync::private::composite_accepts(ty, "domain_composite", 4, |f| {
+                    match
+        f.name()
+        {
+            "txt" => <cornucopia_sync::private::Domain::<&'a str> as
+            postgres_types::ToSql>::accepts(f.type_()),"json" => <cornucopia_sync::private::Domain::<&'a serde_json::value::Value> as
+            postgres_types::ToSql>::accepts(f.type_()),"nb" => <cornucopia_sync::private::Domain::<i32> as
+            postgres_types::ToSql>::accepts(f.type_()),"arr" => <cornucopia_sync::private::Domain::<cornucopia_sync::private::DomainArray::<&'a serde_json::value::Value, &[&'a serde_json::value::Value]>> as
+            postgres_types::ToSql>::accepts(f.type_()),_ => false,
+        }
+                })
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+    }
+}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod cow {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectCowBook<'a> {
+            /// `text`
+            pub name: std::borrow::Cow<'a, str>,
+            /// `text`, nullable
+            pub author: Option<std::borrow::Cow<'a, str>>,
+        }
+        impl<'a> SelectCowBook<'a> {
+            #[inline]
+            pub fn into_owned(self) -> SelectCowBook<'static> {
+                let SelectCowBook { name, author } = self;
+                SelectCowBook {
+                    name: std::borrow::Cow::Owned(name.into_owned()),
+                    author: author.map(|v| std::borrow::Cow::Owned(v.into_owned())),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectCowNightmareDomain<'a> {
+            /// `text`, nullable
+            pub txt: Option<std::borrow::Cow<'a, str>>,
+            /// `json`, nullable
+            pub json: Option<serde_json::Value>,
+            /// `int4`, nullable
+            pub nb: Option<i32>,
+            /// `domain_json[]`, nullable, with nullable elements
+            pub arr: Option<Vec<Option<serde_json::Value>>>,
+            /// `domain_composite`, nullable
+            pub composite: Option<super::super::types::public::DomainComposite>,
+        }
+        impl<'a> SelectCowNightmareDomain<'a> {
+            #[inline]
+            pub fn into_owned(self) -> SelectCowNightmareDomain<'static> {
+                let SelectCowNightmareDomain {
+                    txt,
+                    json,
+                    nb,
+                    arr,
+                    composite,
+                } = self;
+                SelectCowNightmareDomain {
+                    txt: txt.map(|v| std::borrow::Cow::Owned(v.into_owned())),
+                    json,
+                    nb,
+                    arr,
+                    composite,
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectCowEverythingArray {
+            /// `text[]`
+            pub text_: Vec<String>,
+            /// `bytea[]`
+            pub bytea_: Vec<Vec<u8>>,
+            /// `int4[]`
+            pub int_: Vec<i32>,
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectCowGroupedBookInfo<'a> {
+            /// `text`
+            pub name: std::borrow::Cow<'a, str>,
+            /// `text`, nullable
+            pub author: Option<std::borrow::Cow<'a, str>>,
+        }
+        impl<'a> SelectCowGroupedBookInfo<'a> {
+            #[inline]
+            pub fn into_owned(self) -> SelectCowGroupedBookInfo<'static> {
+                let SelectCowGroupedBookInfo { name, author } = self;
+                SelectCowGroupedBookInfo {
+                    name: std::borrow::Cow::Owned(name.into_owned()),
+                    author: author.map(|v| std::borrow::Cow::Owned(v.into_owned())),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SelectCowGroupedBook<'a> {
+            /// Columns prefixed by `info_`
+            pub info: SelectCowGroupedBookInfo<'a>,
+        }
+        impl<'a> SelectCowGroupedBook<'a> {
+            #[inline]
+            pub fn into_owned(self) -> SelectCowGroupedBook<'static> {
+                let SelectCowGroupedBook { info } = self;
+                SelectCowGroupedBook {
+                    info: info.into_owned(),
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct SelectCowBookQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SelectCowBook,
+            mapper: fn(SelectCowBook) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SelectCowBookQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(SelectCowBook) -> R) -> SelectCowBookQuery<'a, C, R, N> {
+                SelectCowBookQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct SelectCowNightmareDomainQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SelectCowNightmareDomain,
+            mapper: fn(SelectCowNightmareDomain) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SelectCowNightmareDomainQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(SelectCowNightmareDomain) -> R,
+            ) -> SelectCowNightmareDomainQuery<'a, C, R, N> {
+                SelectCowNightmareDomainQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct PublicCopyCompositeQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> super::super::types::public::CopyComposite,
+            mapper: fn(super::super::types::public::CopyComposite) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PublicCopyCompositeQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(super::super::types::public::CopyComposite) -> R,
+            ) -> PublicCopyCompositeQuery<'a, C, R, N> {
+                PublicCopyCompositeQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct SelectCowEverythingArrayQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SelectCowEverythingArray,
+            mapper: fn(SelectCowEverythingArray) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SelectCowEverythingArrayQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(SelectCowEverythingArray) -> R,
+            ) -> SelectCowEverythingArrayQuery<'a, C, R, N> {
+                SelectCowEverythingArrayQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct SelectCowGroupedBookQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SelectCowGroupedBook,
+            mapper: fn(SelectCowGroupedBook) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SelectCowGroupedBookQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(SelectCowGroupedBook) -> R,
+            ) -> SelectCowGroupedBookQuery<'a, C, R, N> {
+                SelectCowGroupedBookQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn select_cow_book() -> SelectCowBookStmt {
+            SelectCowBookStmt(cornucopia_sync::private::Stmt::new("SELECT * FROM book"))
+        }
+        pub struct SelectCowBookStmt(cornucopia_sync::private::Stmt);
+        impl SelectCowBookStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SelectCowBookQuery<'a, C, SelectCowBook<'static>, 0> {
+                SelectCowBookQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| SelectCowBook {
+                        name: std::borrow::Cow::Borrowed(row.get(0)),
+                        author: row.get::<_, Option<_>>(1).map(std::borrow::Cow::Borrowed),
+                    },
+                    mapper: |it| it.into_owned(),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn select_cow_nightmare_domain() -> SelectCowNightmareDomainStmt {
+            SelectCowNightmareDomainStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT * FROM nightmare_domain",
+            ))
+        }
+        pub struct SelectCowNightmareDomainStmt(cornucopia_sync::private::Stmt);
+        impl SelectCowNightmareDomainStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SelectCowNightmareDomainQuery<'a, C, SelectCowNightmareDomain<'static>, 0>
+            {
+                SelectCowNightmareDomainQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| SelectCowNightmareDomain {
+                        txt: row.get::<_, Option<_>>(0).map(std::borrow::Cow::Borrowed),
+                        json: row
+                            .get::<_, Option<postgres_types::Json<&serde_json::value::RawValue>>>(1)
+                            .map(|v| serde_json::from_str(v.0.get()).unwrap()),
+                        nb: row.get::<_, Option<i32>>(2),
+                        arr: row
+                            .get::<_, Option<
+                                cornucopia_sync::ArrayIterator<
+                                    '_,
+                                    Option<postgres_types::Json<&serde_json::value::RawValue>>,
+                                >,
+                            >>(3)
+                            .map(|v| {
+                                v.map(|v| v.map(|v| serde_json::from_str(v.0.get()).unwrap()))
+                                    .collect()
+                            }),
+                        composite: row
+                            .get::<_, Option<super::super::types::public::DomainCompositeBorrowed>>(
+                                4,
+                            )
+                            .map(|v| v.into()),
+                    },
+                    mapper: |it| it.into_owned(),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn select_cow_copy() -> SelectCowCopyStmt {
+            SelectCowCopyStmt(cornucopia_sync::private::Stmt::new("SELECT * FROM copy"))
+        }
+        pub struct SelectCowCopyStmt(cornucopia_sync::private::Stmt);
+        impl SelectCowCopyStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> PublicCopyCompositeQuery<'a, C, super::super::types::public::CopyComposite, 0>
+            {
+                PublicCopyCompositeQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn select_cow_everything_array() -> SelectCowEverythingArrayStmt {
+            SelectCowEverythingArrayStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT text_, bytea_, int_ FROM EverythingArray",
+            ))
+        }
+        pub struct SelectCowEverythingArrayStmt(cornucopia_sync::private::Stmt);
+        impl SelectCowEverythingArrayStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SelectCowEverythingArrayQuery<'a, C, SelectCowEverythingArray, 0> {
+                SelectCowEverythingArrayQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| SelectCowEverythingArray {
+                        text_: row
+                            .get::<_, cornucopia_sync::ArrayIterator<'_, &str>>(0)
+                            .map(|v| v.into())
+                            .collect(),
+                        bytea_: row
+                            .get::<_, cornucopia_sync::ArrayIterator<'_, &[u8]>>(1)
+                            .map(|v| v.into())
+                            .collect(),
+                        int_: row
+                            .get::<_, cornucopia_sync::ArrayIterator<'_, i32>>(2)
+                            .map(|v| v)
+                            .collect(),
+                    },
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn select_cow_grouped_book() -> SelectCowGroupedBookStmt {
+            SelectCowGroupedBookStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT name AS info_name, author AS info_author FROM book",
+            ))
+        }
+        pub struct SelectCowGroupedBookStmt(cornucopia_sync::private::Stmt);
+        impl SelectCowGroupedBookStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SelectCowGroupedBookQuery<'a, C, SelectCowGroupedBook<'static>, 0> {
+                SelectCowGroupedBookQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| SelectCowGroupedBook {
+                        info: SelectCowGroupedBookInfo {
+                            name: std::borrow::Cow::Borrowed(row.get(0)),
+                            author: row.get::<_, Option<_>>(1).map(std::borrow::Cow::Borrowed),
+                        },
+                    },
+                    mapper: |it| it.into_owned(),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1642 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {
+    pub mod public {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(non_camel_case_types)]
+        pub enum PurchaseStatus {
+            pending,
+            paid,
+            cancelled,
+            shipped,
+        }
+        impl<'a> postgres_types::ToSql for PurchaseStatus {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                buf: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let s = match *self {
+                    PurchaseStatus::pending => "pending",
+                    PurchaseStatus::paid => "paid",
+                    PurchaseStatus::cancelled => "cancelled",
+                    PurchaseStatus::shipped => "shipped",
+                };
+                buf.extend_from_slice(s.as_bytes());
+                std::result::Result::Ok(postgres_types::IsNull::No)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "purchase_status" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 4 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "pending" => true,
+                            "paid" => true,
+                            "cancelled" => true,
+                            "shipped" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+        impl<'a> postgres_types::FromSql<'a> for PurchaseStatus {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                buf: &'a [u8],
+            ) -> std::result::Result<
+                PurchaseStatus,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                match std::str::from_utf8(buf)? {
+                    "pending" => std::result::Result::Ok(PurchaseStatus::pending),
+                    "paid" => std::result::Result::Ok(PurchaseStatus::paid),
+                    "cancelled" => std::result::Result::Ok(PurchaseStatus::cancelled),
+                    "shipped" => std::result::Result::Ok(PurchaseStatus::shipped),
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
+                        "invalid variant `{}`",
+                        s
+                    ))),
+                }
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "purchase_status" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 4 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "pending" => true,
+                            "paid" => true,
+                            "cancelled" => true,
+                            "shipped" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod dump {
+        #[derive(Debug)]
+        pub struct InsertCustomerParams<
+            T1: cornucopia_sync::StringSql,
+            T2: cornucopia_sync::StringSql,
+        > {
+            /// `text`
+            pub email: T1,
+            /// `varchar`
+            pub name: T2,
+            /// `bool`
+            pub vip: bool,
+        }
+        #[derive(Debug)]
+        pub struct InsertProductParams<
+            T1: cornucopia_sync::StringSql,
+            T2: cornucopia_sync::StringSql,
+            T3: cornucopia_sync::ArraySql<Item = T2>,
+        > {
+            /// `text`
+            pub label: T1,
+            /// `numeric`
+            pub price: rust_decimal::Decimal,
+            /// `float8`
+            pub weight: f64,
+            /// `text[]`
+            pub tags: T3,
+        }
+        #[derive(Clone, Copy, Debug)]
+        pub struct InsertPurchaseParams {
+            /// `int4`
+            pub customer_id: i32,
+            /// `int8`
+            pub product_id: i64,
+            /// `int2`
+            pub quantity: i16,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+            /// `uuid`
+            pub reference: uuid::Uuid,
+            /// `date`
+            pub placed_on: time::Date,
+        }
+        #[derive(Clone, Copy, Debug)]
+        pub struct PurchasesOfCustomerParams {
+            /// `int4`
+            pub customer_id: i32,
+            /// `purchase_status`
+            pub first_status: super::super::types::public::PurchaseStatus,
+            /// `purchase_status`
+            pub second_status: super::super::types::public::PurchaseStatus,
+            /// `int8`
+            pub limit: i64,
+        }
+        #[derive(Debug)]
+        pub struct ProductsInPriceRangeParams<T1: cornucopia_sync::StringSql> {
+            /// `numeric`
+            pub min: rust_decimal::Decimal,
+            /// `numeric`
+            pub max: rust_decimal::Decimal,
+            /// `text`
+            pub pattern: T1,
+        }
+        #[derive(Debug)]
+        pub struct SummaryLabelsParams<T1: cornucopia_sync::StringSql> {
+            /// `text`
+            pub suffix: T1,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+        }
+        #[derive(Debug)]
+        pub struct ShipPurchaseParams<T1: cornucopia_sync::StringSql> {
+            /// `text`
+            pub note: T1,
+            /// `int4`
+            pub id: i32,
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct PurchaseSummary {
+            /// `int4`
+            pub id: i32,
+            /// `varchar`
+            pub name: String,
+            /// `text`
+            pub label: String,
+            /// `int2`
+            pub quantity: i16,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+        }
+        pub struct PurchaseSummaryBorrowed<'a> {
+            /// `int4`
+            pub id: i32,
+            /// `varchar`
+            pub name: &'a str,
+            /// `text`
+            pub label: &'a str,
+            /// `int2`
+            pub quantity: i16,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+        }
+        impl<'a> From<PurchaseSummaryBorrowed<'a>> for PurchaseSummary {
+            #[inline]
+            fn from(
+                PurchaseSummaryBorrowed {
+                    id,
+                    name,
+                    label,
+                    quantity,
+                    status,
+                }: PurchaseSummaryBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    name: name.into(),
+                    label: label.into(),
+                    quantity,
+                    status,
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct InsertProduct {
+            /// `int8`
+            pub id: i64,
+            /// `text`
+            pub label: String,
+            /// `numeric`
+            pub price: rust_decimal::Decimal,
+            /// `float8`
+            pub weight: f64,
+            /// `text[]`
+            pub tags: Vec<String>,
+        }
+        pub struct InsertProductBorrowed<'a> {
+            /// `int8`
+            pub id: i64,
+            /// `text`
+            pub label: &'a str,
+            /// `numeric`
+            pub price: rust_decimal::Decimal,
+            /// `float8`
+            pub weight: f64,
+            /// `text[]`
+            pub tags: cornucopia_sync::ArrayIterator<'a, &'a str>,
+        }
+        impl<'a> From<InsertProductBorrowed<'a>> for InsertProduct {
+            #[inline]
+            fn from(
+                InsertProductBorrowed {
+                    id,
+                    label,
+                    price,
+                    weight,
+                    tags,
+                }: InsertProductBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    label: label.into(),
+                    price,
+                    weight,
+                    tags: tags.map(|v| v.into()).collect(),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct CustomerByEmail {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub email: String,
+            /// `varchar`
+            pub name: String,
+            /// `bool`
+            pub vip: bool,
+            /// `timestamptz`
+            pub created_at: time::OffsetDateTime,
+        }
+        pub struct CustomerByEmailBorrowed<'a> {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub email: &'a str,
+            /// `varchar`
+            pub name: &'a str,
+            /// `bool`
+            pub vip: bool,
+            /// `timestamptz`
+            pub created_at: time::OffsetDateTime,
+        }
+        impl<'a> From<CustomerByEmailBorrowed<'a>> for CustomerByEmail {
+            #[inline]
+            fn from(
+                CustomerByEmailBorrowed {
+                    id,
+                    email,
+                    name,
+                    vip,
+                    created_at,
+                }: CustomerByEmailBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    email: email.into(),
+                    name: name.into(),
+                    vip,
+                    created_at,
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct PurchasesOfCustomer {
+            /// `int4`
+            pub id: i32,
+            /// `int2`
+            pub quantity: i16,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+            /// `text`, nullable
+            pub note: Option<String>,
+            /// `varchar`
+            pub customer: String,
+            /// `text`
+            pub product: String,
+            /// `numeric`
+            pub price: rust_decimal::Decimal,
+        }
+        pub struct PurchasesOfCustomerBorrowed<'a> {
+            /// `int4`
+            pub id: i32,
+            /// `int2`
+            pub quantity: i16,
+            /// `purchase_status`
+            pub status: super::super::types::public::PurchaseStatus,
+            /// `text`, nullable
+            pub note: Option<&'a str>,
+            /// `varchar`
+            pub customer: &'a str,
+            /// `text`
+            pub product: &'a str,
+            /// `numeric`
+            pub price: rust_decimal::Decimal,
+        }
+        impl<'a> From<PurchasesOfCustomerBorrowed<'a>> for PurchasesOfCustomer {
+            #[inline]
+            fn from(
+                PurchasesOfCustomerBorrowed {
+                    id,
+                    quantity,
+                    status,
+                    note,
+                    customer,
+                    product,
+                    price,
+                }: PurchasesOfCustomerBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    quantity,
+                    status,
+                    note: note.map(|v| v.into()),
+                    customer: customer.into(),
+                    product: product.into(),
+                    price,
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, Copy)]
+        pub struct PurchasesByStatus {
+            /// `int4`
+            pub id: i32,
+            /// `uuid`
+            pub reference: uuid::Uuid,
+            /// `date`
+            pub placed_on: time::Date,
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ProductsInPriceRange {
+            /// `int8`
+            pub id: i64,
+            /// `text`
+            pub label: String,
+            /// `float8`, nullable
+            pub weight: Option<f64>,
+            /// `text[]`
+            pub tags: Vec<String>,
+        }
+        pub struct ProductsInPriceRangeBorrowed<'a> {
+            /// `int8`
+            pub id: i64,
+            /// `text`
+            pub label: &'a str,
+            /// `float8`, nullable
+            pub weight: Option<f64>,
+            /// `text[]`
+            pub tags: cornucopia_sync::ArrayIterator<'a, &'a str>,
+        }
+        impl<'a> From<ProductsInPriceRangeBorrowed<'a>> for ProductsInPriceRange {
+            #[inline]
+            fn from(
+                ProductsInPriceRangeBorrowed {
+                    id,
+                    label,
+                    weight,
+                    tags,
+                }: ProductsInPriceRangeBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    label: label.into(),
+                    weight,
+                    tags: tags.map(|v| v.into()).collect(),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SummaryLabels {
+            /// `text`
+            pub label: String,
+            /// `int8`
+            pub quantity: i64,
+            /// `text`
+            pub suffix: String,
+        }
+        pub struct SummaryLabelsBorrowed<'a> {
+            /// `text`
+            pub label: &'a str,
+            /// `int8`
+            pub quantity: i64,
+            /// `text`
+            pub suffix: &'a str,
+        }
+        impl<'a> From<SummaryLabelsBorrowed<'a>> for SummaryLabels {
+            #[inline]
+            fn from(
+                SummaryLabelsBorrowed {
+                    label,
+                    quantity,
+                    suffix,
+                }: SummaryLabelsBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    label: label.into(),
+                    quantity,
+                    suffix: suffix.into(),
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct PurchaseSummaryQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> PurchaseSummaryBorrowed,
+            mapper: fn(PurchaseSummaryBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PurchaseSummaryQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(PurchaseSummaryBorrowed) -> R,
+            ) -> PurchaseSummaryQuery<'a, C, R, N> {
+                PurchaseSummaryQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct I32Query<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> i32,
+            mapper: fn(i32) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> I32Query<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(i32) -> R) -> I32Query<'a, C, R, N> {
+                I32Query {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct InsertProductQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> InsertProductBorrowed,
+            mapper: fn(InsertProductBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> InsertProductQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(InsertProductBorrowed) -> R,
+            ) -> InsertProductQuery<'a, C, R, N> {
+                InsertProductQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct CustomerByEmailQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> CustomerByEmailBorrowed,
+            mapper: fn(CustomerByEmailBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> CustomerByEmailQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(CustomerByEmailBorrowed) -> R,
+            ) -> CustomerByEmailQuery<'a, C, R, N> {
+                CustomerByEmailQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct PurchasesOfCustomerQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> PurchasesOfCustomerBorrowed,
+            mapper: fn(PurchasesOfCustomerBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PurchasesOfCustomerQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(PurchasesOfCustomerBorrowed) -> R,
+            ) -> PurchasesOfCustomerQuery<'a, C, R, N> {
+                PurchasesOfCustomerQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct PurchasesByStatusQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> PurchasesByStatus,
+            mapper: fn(PurchasesByStatus) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PurchasesByStatusQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(PurchasesByStatus) -> R,
+            ) -> PurchasesByStatusQuery<'a, C, R, N> {
+                PurchasesByStatusQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct ProductsInPriceRangeQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> ProductsInPriceRangeBorrowed,
+            mapper: fn(ProductsInPriceRangeBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> ProductsInPriceRangeQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(ProductsInPriceRangeBorrowed) -> R,
+            ) -> ProductsInPriceRangeQuery<'a, C, R, N> {
+                ProductsInPriceRangeQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct I64Query<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> i64,
+            mapper: fn(i64) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> I64Query<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(i64) -> R) -> I64Query<'a, C, R, N> {
+                I64Query {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct SummaryLabelsQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> SummaryLabelsBorrowed,
+            mapper: fn(SummaryLabelsBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SummaryLabelsQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(SummaryLabelsBorrowed) -> R,
+            ) -> SummaryLabelsQuery<'a, C, R, N> {
+                SummaryLabelsQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct PublicPurchaseStatusQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> super::super::types::public::PurchaseStatus,
+            mapper: fn(super::super::types::public::PurchaseStatus) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> PublicPurchaseStatusQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(super::super::types::public::PurchaseStatus) -> R,
+            ) -> PublicPurchaseStatusQuery<'a, C, R, N> {
+                PublicPurchaseStatusQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn purchase_summary() -> PurchaseSummaryStmt {
+            PurchaseSummaryStmt(cornucopia_sync::private::Stmt::new("SELECT \"id\", \"name\", \"label\", \"quantity\", \"status\" FROM \"purchase_summary\""))
+        }
+        pub struct PurchaseSummaryStmt(cornucopia_sync::private::Stmt);
+        impl PurchaseSummaryStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> PurchaseSummaryQuery<'a, C, PurchaseSummary, 0> {
+                PurchaseSummaryQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| PurchaseSummaryBorrowed {
+                        id: row.get(0),
+                        name: row.get(1),
+                        label: row.get(2),
+                        quantity: row.get(3),
+                        status: row.get(4),
+                    },
+                    mapper: |it| <PurchaseSummary>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn insert_customer() -> InsertCustomerStmt {
+            InsertCustomerStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO customer (email, name, vip) VALUES ($1, $2, $3) RETURNING id",
+            ))
+        }
+        pub struct InsertCustomerStmt(cornucopia_sync::private::Stmt);
+        impl InsertCustomerStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+            >(
+                &'a mut self,
+                client: &'a mut C,
+                email: &'a T1,
+                name: &'a T2,
+                vip: &'a bool,
+            ) -> I32Query<'a, C, i32, 3> {
+                I32Query {
+                    client,
+                    params: [email, name, vip],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        impl<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+            >
+            cornucopia_sync::Params<'a, InsertCustomerParams<T1, T2>, I32Query<'a, C, i32, 3>, C>
+            for InsertCustomerStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertCustomerParams<T1, T2>,
+            ) -> I32Query<'a, C, i32, 3> {
+                self.bind(client, &params.email, &params.name, &params.vip)
+            }
+        }
+        pub fn insert_product() -> InsertProductStmt {
+            InsertProductStmt(cornucopia_sync::private::Stmt::new("INSERT INTO product (label, price, weight, tags) VALUES ($1, $2, $3, $4) RETURNING *"))
+        }
+        pub struct InsertProductStmt(cornucopia_sync::private::Stmt);
+        impl InsertProductStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+                T3: cornucopia_sync::ArraySql<Item = T2>,
+            >(
+                &'a mut self,
+                client: &'a mut C,
+                label: &'a T1,
+                price: &'a rust_decimal::Decimal,
+                weight: &'a f64,
+                tags: &'a T3,
+            ) -> InsertProductQuery<'a, C, InsertProduct, 4> {
+                InsertProductQuery {
+                    client,
+                    params: [label, price, weight, tags],
+                    stmt: &mut self.0,
+                    extractor: |row| InsertProductBorrowed {
+                        id: row.get(0),
+                        label: row.get(1),
+                        price: row.get(2),
+                        weight: row.get(3),
+                        tags: row.get(4),
+                    },
+                    mapper: |it| <InsertProduct>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        impl<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+                T3: cornucopia_sync::ArraySql<Item = T2>,
+            >
+            cornucopia_sync::Params<
+                'a,
+                InsertProductParams<T1, T2, T3>,
+                InsertProductQuery<'a, C, InsertProduct, 4>,
+                C,
+            > for InsertProductStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertProductParams<T1, T2, T3>,
+            ) -> InsertProductQuery<'a, C, InsertProduct, 4> {
+                self.bind(
+                    client,
+                    &params.label,
+                    &params.price,
+                    &params.weight,
+                    &params.tags,
+                )
+            }
+        }
+        pub fn insert_purchase() -> InsertPurchaseStmt {
+            InsertPurchaseStmt(cornucopia_sync::private::Stmt::new("INSERT INTO purchase (customer_id, product_id, quantity, status, reference, placed_on)
+  VALUES ($1, $2, $3, $4, $5, $6)"))
+        }
+        pub struct InsertPurchaseStmt(cornucopia_sync::private::Stmt);
+        impl InsertPurchaseStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                customer_id: &'a i32,
+                product_id: &'a i64,
+                quantity: &'a i16,
+                status: &'a super::super::types::public::PurchaseStatus,
+                reference: &'a uuid::Uuid,
+                placed_on: &'a time::Date,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(
+                    stmt,
+                    &[
+                        customer_id,
+                        product_id,
+                        quantity,
+                        status,
+                        reference,
+                        placed_on,
+                    ],
+                )
+            }
+        }
+        impl<'a, C: GenericClient>
+            cornucopia_sync::Params<'a, InsertPurchaseParams, Result<u64, postgres::Error>, C>
+            for InsertPurchaseStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertPurchaseParams,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(
+                    client,
+                    &params.customer_id,
+                    &params.product_id,
+                    &params.quantity,
+                    &params.status,
+                    &params.reference,
+                    &params.placed_on,
+                )
+            }
+        }
+        pub fn customer_by_email() -> CustomerByEmailStmt {
+            CustomerByEmailStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT * FROM customer WHERE email = $1",
+            ))
+        }
+        pub struct CustomerByEmailStmt(cornucopia_sync::private::Stmt);
+        impl CustomerByEmailStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                email: &'a T1,
+            ) -> CustomerByEmailQuery<'a, C, CustomerByEmail, 1> {
+                CustomerByEmailQuery {
+                    client,
+                    params: [email],
+                    stmt: &mut self.0,
+                    extractor: |row| CustomerByEmailBorrowed {
+                        id: row.get(0),
+                        email: row.get(1),
+                        name: row.get(2),
+                        vip: row.get(3),
+                        created_at: row.get(4),
+                    },
+                    mapper: |it| <CustomerByEmail>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn purchases_of_customer() -> PurchasesOfCustomerStmt {
+            PurchasesOfCustomerStmt(cornucopia_sync::private::Stmt::new("SELECT p.id, p.quantity, p.status, p.note, c.name AS customer, pr.label product, pr.price
+FROM purchase p
+JOIN customer c ON c.id = p.customer_id
+JOIN product pr ON pr.id = p.product_id
+WHERE c.id = $1 AND p.status IN ($2, $3)
+ORDER BY p.id
+LIMIT $4"))
+        }
+        pub struct PurchasesOfCustomerStmt(cornucopia_sync::private::Stmt);
+        impl PurchasesOfCustomerStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                customer_id: &'a i32,
+                first_status: &'a super::super::types::public::PurchaseStatus,
+                second_status: &'a super::super::types::public::PurchaseStatus,
+                limit: &'a i64,
+            ) -> PurchasesOfCustomerQuery<'a, C, PurchasesOfCustomer, 4> {
+                PurchasesOfCustomerQuery {
+                    client,
+                    params: [customer_id, first_status, second_status, limit],
+                    stmt: &mut self.0,
+                    extractor: |row| PurchasesOfCustomerBorrowed {
+                        id: row.get(0),
+                        quantity: row.get(1),
+                        status: row.get(2),
+                        note: row.get(3),
+                        customer: row.get(4),
+                        product: row.get(5),
+                        price: row.get(6),
+                    },
+                    mapper: |it| <PurchasesOfCustomer>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        impl<'a, C: GenericClient>
+            cornucopia_sync::Params<
+                'a,
+                PurchasesOfCustomerParams,
+                PurchasesOfCustomerQuery<'a, C, PurchasesOfCustomer, 4>,
+                C,
+            > for PurchasesOfCustomerStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a PurchasesOfCustomerParams,
+            ) -> PurchasesOfCustomerQuery<'a, C, PurchasesOfCustomer, 4> {
+                self.bind(
+                    client,
+                    &params.customer_id,
+                    &params.first_status,
+                    &params.second_status,
+                    &params.limit,
+                )
+            }
+        }
+        pub fn purchases_by_status() -> PurchasesByStatusStmt {
+            PurchasesByStatusStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT id, reference, placed_on FROM purchase WHERE status = ANY($1) ORDER BY id",
+            ))
+        }
+        pub struct PurchasesByStatusStmt(cornucopia_sync::private::Stmt);
+        impl PurchasesByStatusStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::ArraySql<Item = super::super::types::public::PurchaseStatus>,
+            >(
+                &'a mut self,
+                client: &'a mut C,
+                statuses: &'a T1,
+            ) -> PurchasesByStatusQuery<'a, C, PurchasesByStatus, 1> {
+                PurchasesByStatusQuery {
+                    client,
+                    params: [statuses],
+                    stmt: &mut self.0,
+                    extractor: |row| PurchasesByStatus {
+                        id: row.get(0),
+                        reference: row.get(1),
+                        placed_on: row.get(2),
+                    },
+                    mapper: |it| <PurchasesByStatus>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn products_in_price_range() -> ProductsInPriceRangeStmt {
+            ProductsInPriceRangeStmt(cornucopia_sync::private::Stmt::new("SELECT id, label, weight, tags FROM product WHERE price BETWEEN $1 AND $2 AND label ILIKE $3"))
+        }
+        pub struct ProductsInPriceRangeStmt(cornucopia_sync::private::Stmt);
+        impl ProductsInPriceRangeStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                min: &'a rust_decimal::Decimal,
+                max: &'a rust_decimal::Decimal,
+                pattern: &'a T1,
+            ) -> ProductsInPriceRangeQuery<'a, C, ProductsInPriceRange, 3> {
+                ProductsInPriceRangeQuery {
+                    client,
+                    params: [min, max, pattern],
+                    stmt: &mut self.0,
+                    extractor: |row| ProductsInPriceRangeBorrowed {
+                        id: row.get(0),
+                        label: row.get(1),
+                        weight: row.get(2),
+                        tags: row.get(3),
+                    },
+                    mapper: |it| <ProductsInPriceRange>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        impl<'a, C: GenericClient, T1: cornucopia_sync::StringSql>
+            cornucopia_sync::Params<
+                'a,
+                ProductsInPriceRangeParams<T1>,
+                ProductsInPriceRangeQuery<'a, C, ProductsInPriceRange, 3>,
+                C,
+            > for ProductsInPriceRangeStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a ProductsInPriceRangeParams<T1>,
+            ) -> ProductsInPriceRangeQuery<'a, C, ProductsInPriceRange, 3> {
+                self.bind(client, &params.min, &params.max, &params.pattern)
+            }
+        }
+        pub fn count_vip_customers() -> CountVipCustomersStmt {
+            CountVipCustomersStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT count(*) AS vip_count FROM customer WHERE vip AND created_at < $1",
+            ))
+        }
+        pub struct CountVipCustomersStmt(cornucopia_sync::private::Stmt);
+        impl CountVipCustomersStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                before: &'a time::OffsetDateTime,
+            ) -> I64Query<'a, C, i64, 1> {
+                I64Query {
+                    client,
+                    params: [before],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn summary_labels() -> SummaryLabelsStmt {
+            SummaryLabelsStmt(cornucopia_sync::private::Stmt::new("SELECT label, quantity::bigint AS quantity, $1::text AS suffix FROM purchase_summary WHERE status <> $2"))
+        }
+        pub struct SummaryLabelsStmt(cornucopia_sync::private::Stmt);
+        impl SummaryLabelsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                suffix: &'a T1,
+                status: &'a super::super::types::public::PurchaseStatus,
+            ) -> SummaryLabelsQuery<'a, C, SummaryLabels, 2> {
+                SummaryLabelsQuery {
+                    client,
+                    params: [suffix, status],
+                    stmt: &mut self.0,
+                    extractor: |row| SummaryLabelsBorrowed {
+                        label: row.get(0),
+                        quantity: row.get(1),
+                        suffix: row.get(2),
+                    },
+                    mapper: |it| <SummaryLabels>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        impl<'a, C: GenericClient, T1: cornucopia_sync::StringSql>
+            cornucopia_sync::Params<
+                'a,
+                SummaryLabelsParams<T1>,
+                SummaryLabelsQuery<'a, C, SummaryLabels, 2>,
+                C,
+            > for SummaryLabelsStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a SummaryLabelsParams<T1>,
+            ) -> SummaryLabelsQuery<'a, C, SummaryLabels, 2> {
+                self.bind(client, &params.suffix, &params.status)
+            }
+        }
+        pub fn ship_purchase() -> ShipPurchaseStmt {
+            ShipPurchaseStmt(cornucopia_sync::private::Stmt::new(
+                "UPDATE purchase SET status = 'shipped', note = $1 WHERE id = $2 RETURNING status",
+            ))
+        }
+        pub struct ShipPurchaseStmt(cornucopia_sync::private::Stmt);
+        impl ShipPurchaseStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                note: &'a T1,
+                id: &'a i32,
+            ) -> PublicPurchaseStatusQuery<'a, C, super::super::types::public::PurchaseStatus, 2>
+            {
+                PublicPurchaseStatusQuery {
+                    client,
+                    params: [note, id],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        impl<'a, C: GenericClient, T1: cornucopia_sync::StringSql>
+            cornucopia_sync::Params<
+                'a,
+                ShipPurchaseParams<T1>,
+                PublicPurchaseStatusQuery<'a, C, super::super::types::public::PurchaseStatus, 2>,
+                C,
+            > for ShipPurchaseStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a ShipPurchaseParams<T1>,
+            ) -> PublicPurchaseStatusQuery<'a, C, super::super::types::public::PurchaseStatus, 2>
+            {
+                self.bind(client, &params.note, &params.id)
+            }
+        }
+        pub fn delete_purchases() -> DeletePurchasesStmt {
+            DeletePurchasesStmt(cornucopia_sync::private::Stmt::new("DELETE FROM purchase p USING customer c WHERE c.id = p.customer_id AND c.email = $1"))
+        }
+        pub struct DeletePurchasesStmt(cornucopia_sync::private::Stmt);
+        impl DeletePurchasesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                email: &'a T1,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[email])
+            }
+        }
+    }
+}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(dead_code)]
+pub mod foreign_keys {
+    /// A foreign key constraint of the database schema
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ForeignKey {
+        pub schema: &'static str,
+        pub name: &'static str,
+        pub table: &'static str,
+        pub columns: &'static [&'static str],
+        pub foreign_schema: &'static str,
+        pub foreign_table: &'static str,
+        pub foreign_columns: &'static [&'static str],
+    }
+    pub mod public {
+        pub const PURCHASE_CUSTOMER_ID_FKEY: super::ForeignKey = super::ForeignKey {
+            schema: "public",
+            name: "purchase_customer_id_fkey",
+            table: "purchase",
+            columns: &["customer_id"],
+            foreign_schema: "public",
+            foreign_table: "customer",
+            foreign_columns: &["id"],
+        };
+        pub const PURCHASE_PRODUCT_ID_FKEY: super::ForeignKey = super::ForeignKey {
+            schema: "public",
+            name: "purchase_product_id_fkey",
+            table: "purchase",
+            columns: &["product_id"],
+            foreign_schema: "public",
+            foreign_table: "product",
+            foreign_columns: &["id"],
+        };
+    }
+}
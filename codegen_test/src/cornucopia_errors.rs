@@ -0,0 +1,687 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod errors {
+        #[derive(Debug, Clone, PartialEq, Copy)]
+        pub struct AuthorRow {
+            /// `int4`
+            pub id: i32,
+        }
+        pub mod sync {
+            #[derive(Debug)]
+            pub enum Error {
+                Query {
+                    query: &'static str,
+                    source: postgres::Error,
+                },
+            }
+            impl std::fmt::Display for Error {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        Error::Query { query, .. } => write!(f, "query `{query}` failed"),
+                    }
+                }
+            }
+            impl std::error::Error for Error {
+                fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                    match self {
+                        Error::Query { source, .. } => Some(source),
+                    }
+                }
+            }
+            fn query_err(query: &'static str) -> impl Fn(postgres::Error) -> Error {
+                move |source| Error::Query { query, source }
+            }
+            pub struct AuthorRowQuery<'a, C: ::postgres::GenericClient, T, const N: usize> {
+                client: &'a mut C,
+                params: [&'a (dyn postgres_types::ToSql + Sync); N],
+                stmt: &'a mut cornucopia_sync::private::Stmt,
+                extractor: fn(&postgres::Row) -> super::AuthorRow,
+                mapper: fn(super::AuthorRow) -> T,
+                capacity: usize,
+                query: &'static str,
+            }
+            impl<'a, C, T: 'a, const N: usize> AuthorRowQuery<'a, C, T, N>
+            where
+                C: ::postgres::GenericClient,
+            {
+                pub fn map<R>(
+                    self,
+                    mapper: fn(super::AuthorRow) -> R,
+                ) -> AuthorRowQuery<'a, C, R, N> {
+                    AuthorRowQuery {
+                        client: self.client,
+                        params: self.params,
+                        stmt: self.stmt,
+                        extractor: self.extractor,
+                        mapper,
+                        capacity: self.capacity,
+                        query: self.query,
+                    }
+                }
+                pub fn one(self) -> Result<T, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .map_err(query_err(self.query))?;
+                    Ok((self.mapper)((self.extractor)(&row)))
+                }
+                pub fn all(self) -> Result<Vec<T>, Error> {
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
+                }
+                pub fn opt(self) -> Result<Option<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .map_err(query_err(self.query))?
+                        .map(|row| (self.mapper)((self.extractor)(&row))))
+                }
+                pub fn execute(self) -> Result<u64, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    self.client
+                        .execute(stmt, &self.params)
+                        .map_err(query_err(self.query))
+                }
+                pub fn iter(self) -> Result<impl Iterator<Item = Result<T, Error>> + 'a, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    let it = ::postgres::fallible_iterator::FallibleIterator::iterator(
+                        self.client
+                            .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))
+                            .map_err(query_err(self.query))?,
+                    )
+                    .map(move |res| {
+                        res.map(|row| (self.mapper)((self.extractor)(&row)))
+                            .map_err(query_err(self.query))
+                    });
+                    Ok(it)
+                }
+                pub fn one_scalar(self) -> Result<i32, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .map_err(query_err(self.query))?;
+                    let scalar = row.get::<_, i32>(0);
+                    Ok(scalar)
+                }
+                pub fn opt_scalar(self) -> Result<Option<i32>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .map_err(query_err(self.query))?
+                        .map(|row| {
+                            let scalar = row.get::<_, i32>(0);
+                            scalar
+                        }))
+                }
+            }
+            pub struct I32Query<'a, C: ::postgres::GenericClient, T, const N: usize> {
+                client: &'a mut C,
+                params: [&'a (dyn postgres_types::ToSql + Sync); N],
+                stmt: &'a mut cornucopia_sync::private::Stmt,
+                extractor: fn(&postgres::Row) -> i32,
+                mapper: fn(i32) -> T,
+                capacity: usize,
+                query: &'static str,
+            }
+            impl<'a, C, T: 'a, const N: usize> I32Query<'a, C, T, N>
+            where
+                C: ::postgres::GenericClient,
+            {
+                pub fn map<R>(self, mapper: fn(i32) -> R) -> I32Query<'a, C, R, N> {
+                    I32Query {
+                        client: self.client,
+                        params: self.params,
+                        stmt: self.stmt,
+                        extractor: self.extractor,
+                        mapper,
+                        capacity: self.capacity,
+                        query: self.query,
+                    }
+                }
+                pub fn one(self) -> Result<T, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .map_err(query_err(self.query))?;
+                    Ok((self.mapper)((self.extractor)(&row)))
+                }
+                pub fn all(self) -> Result<Vec<T>, Error> {
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity)
+                }
+                pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, Error> {
+                    self.iter()?
+                        .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                            vec.push(it?);
+                            Ok(vec)
+                        })
+                }
+                pub fn opt(self) -> Result<Option<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .map_err(query_err(self.query))?
+                        .map(|row| (self.mapper)((self.extractor)(&row))))
+                }
+                pub fn execute(self) -> Result<u64, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    self.client
+                        .execute(stmt, &self.params)
+                        .map_err(query_err(self.query))
+                }
+                pub fn iter(self) -> Result<impl Iterator<Item = Result<T, Error>> + 'a, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .map_err(query_err(self.query))?;
+                    let it = ::postgres::fallible_iterator::FallibleIterator::iterator(
+                        self.client
+                            .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))
+                            .map_err(query_err(self.query))?,
+                    )
+                    .map(move |res| {
+                        res.map(|row| (self.mapper)((self.extractor)(&row)))
+                            .map_err(query_err(self.query))
+                    });
+                    Ok(it)
+                }
+            }
+            pub fn author_by_id() -> AuthorByIdStmt {
+                AuthorByIdStmt(cornucopia_sync::private::Stmt::new(
+                    "SELECT id FROM author WHERE id = $1",
+                ))
+            }
+            pub struct AuthorByIdStmt(cornucopia_sync::private::Stmt);
+            impl AuthorByIdStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
+                pub fn bind<'a, C: ::postgres::GenericClient>(
+                    &'a mut self,
+                    client: &'a mut C,
+                    id: &'a i32,
+                ) -> AuthorRowQuery<'a, C, super::AuthorRow, 1> {
+                    AuthorRowQuery {
+                        client,
+                        params: [id],
+                        stmt: &mut self.0,
+                        extractor: |row| super::AuthorRow { id: row.get(0) },
+                        mapper: |it| <super::AuthorRow>::from(it),
+                        capacity: 0,
+                        query: "author_by_id",
+                    }
+                }
+            }
+            pub fn author_ids() -> AuthorIdsStmt {
+                AuthorIdsStmt(cornucopia_sync::private::Stmt::new("SELECT id FROM author"))
+            }
+            pub struct AuthorIdsStmt(cornucopia_sync::private::Stmt);
+            impl AuthorIdsStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
+                pub fn bind<'a, C: ::postgres::GenericClient>(
+                    &'a mut self,
+                    client: &'a mut C,
+                ) -> I32Query<'a, C, i32, 0> {
+                    I32Query {
+                        client,
+                        params: [],
+                        stmt: &mut self.0,
+                        extractor: |row| row.get(0),
+                        mapper: |it| it,
+                        capacity: 0,
+                        query: "author_ids",
+                    }
+                }
+            }
+            pub fn insert_author() -> InsertAuthorStmt {
+                InsertAuthorStmt(cornucopia_sync::private::Stmt::new(
+                    "INSERT INTO author (id) VALUES ($1)",
+                ))
+            }
+            pub struct InsertAuthorStmt(cornucopia_sync::private::Stmt);
+            impl InsertAuthorStmt {
+                pub fn statement(&self) -> Option<&postgres::Statement> {
+                    self.0.statement()
+                }
+                pub fn bind<'a, C: ::postgres::GenericClient>(
+                    &'a mut self,
+                    client: &'a mut C,
+                    id: &'a i32,
+                ) -> Result<u64, Error> {
+                    let stmt = self.0.prepare(client).map_err(query_err("insert_author"))?;
+                    client
+                        .execute(stmt, &[id])
+                        .map_err(query_err("insert_author"))
+                }
+            }
+        }
+        pub mod async_ {
+            #[derive(Debug)]
+            pub enum Error {
+                Query {
+                    query: &'static str,
+                    source: tokio_postgres::Error,
+                },
+            }
+            impl std::fmt::Display for Error {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        Error::Query { query, .. } => write!(f, "query `{query}` failed"),
+                    }
+                }
+            }
+            impl std::error::Error for Error {
+                fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                    match self {
+                        Error::Query { source, .. } => Some(source),
+                    }
+                }
+            }
+            fn query_err(query: &'static str) -> impl Fn(tokio_postgres::Error) -> Error {
+                move |source| Error::Query { query, source }
+            }
+            pub struct AuthorRowQuery<'a, C: ::cornucopia_async::GenericClient, T, const N: usize> {
+                client: &'a C,
+                params: [&'a (dyn postgres_types::ToSql + Sync); N],
+                stmt: &'a mut cornucopia_async::private::Stmt,
+                extractor: fn(&tokio_postgres::Row) -> super::AuthorRow,
+                mapper: fn(super::AuthorRow) -> T,
+                capacity: usize,
+                query: &'static str,
+            }
+            impl<'a, C, T: 'a, const N: usize> AuthorRowQuery<'a, C, T, N>
+            where
+                C: ::cornucopia_async::GenericClient,
+            {
+                pub fn map<R>(
+                    self,
+                    mapper: fn(super::AuthorRow) -> R,
+                ) -> AuthorRowQuery<'a, C, R, N> {
+                    AuthorRowQuery {
+                        client: self.client,
+                        params: self.params,
+                        stmt: self.stmt,
+                        extractor: self.extractor,
+                        mapper,
+                        capacity: self.capacity,
+                        query: self.query,
+                    }
+                }
+                pub async fn one(self) -> Result<T, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    Ok((self.mapper)((self.extractor)(&row)))
+                }
+                pub async fn all(self) -> Result<Vec<T>, Error> {
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, Error> {
+                    ::futures::TryStreamExt::try_fold(
+                        self.iter().await?,
+                        Vec::with_capacity(capacity),
+                        |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        },
+                    )
+                    .await
+                }
+                pub async fn opt(self) -> Result<Option<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?
+                        .map(|row| (self.mapper)((self.extractor)(&row))))
+                }
+                pub async fn execute(self) -> Result<u64, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    self.client
+                        .execute(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))
+                }
+                pub async fn iter(self) -> Result<AuthorRowStream<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let it = AuthorRowStream {
+                        inner: Box::pin(
+                            self.client
+                                .query_raw(
+                                    stmt,
+                                    cornucopia_async::private::slice_iter(&self.params),
+                                )
+                                .await
+                                .map_err(query_err(self.query))?,
+                        ),
+                        extractor: self.extractor,
+                        mapper: self.mapper,
+                        query: self.query,
+                    };
+                    Ok(it)
+                }
+                pub async fn one_scalar(self) -> Result<i32, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let scalar = row.get::<_, i32>(0);
+                    Ok(scalar)
+                }
+                pub async fn opt_scalar(self) -> Result<Option<i32>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?
+                        .map(|row| {
+                            let scalar = row.get::<_, i32>(0);
+                            scalar
+                        }))
+                }
+            }
+            pub struct AuthorRowStream<T> {
+                inner: std::pin::Pin<Box<tokio_postgres::RowStream>>,
+                extractor: fn(&tokio_postgres::Row) -> super::AuthorRow,
+                mapper: fn(super::AuthorRow) -> T,
+                query: &'static str,
+            }
+            impl<T> futures::Stream for AuthorRowStream<T> {
+                type Item = Result<T, Error>;
+                fn poll_next(
+                    self: std::pin::Pin<&mut Self>,
+                    cx: &mut std::task::Context<'_>,
+                ) -> std::task::Poll<Option<Self::Item>> {
+                    let this = self.get_mut();
+                    futures::Stream::poll_next(this.inner.as_mut(), cx).map(|it| {
+                        it.map(|res| {
+                            res.map(|row| (this.mapper)((this.extractor)(&row)))
+                                .map_err(query_err(this.query))
+                        })
+                    })
+                }
+            }
+            pub struct I32Query<'a, C: ::cornucopia_async::GenericClient, T, const N: usize> {
+                client: &'a C,
+                params: [&'a (dyn postgres_types::ToSql + Sync); N],
+                stmt: &'a mut cornucopia_async::private::Stmt,
+                extractor: fn(&tokio_postgres::Row) -> i32,
+                mapper: fn(i32) -> T,
+                capacity: usize,
+                query: &'static str,
+            }
+            impl<'a, C, T: 'a, const N: usize> I32Query<'a, C, T, N>
+            where
+                C: ::cornucopia_async::GenericClient,
+            {
+                pub fn map<R>(self, mapper: fn(i32) -> R) -> I32Query<'a, C, R, N> {
+                    I32Query {
+                        client: self.client,
+                        params: self.params,
+                        stmt: self.stmt,
+                        extractor: self.extractor,
+                        mapper,
+                        capacity: self.capacity,
+                        query: self.query,
+                    }
+                }
+                pub async fn one(self) -> Result<T, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let row = self
+                        .client
+                        .query_one(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    Ok((self.mapper)((self.extractor)(&row)))
+                }
+                pub async fn all(self) -> Result<Vec<T>, Error> {
+                    let capacity = self.capacity;
+                    self.vec_with_capacity(capacity).await
+                }
+                pub async fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, Error> {
+                    ::futures::TryStreamExt::try_fold(
+                        self.iter().await?,
+                        Vec::with_capacity(capacity),
+                        |mut vec, it| {
+                            vec.push(it);
+                            futures::future::ready(Ok(vec))
+                        },
+                    )
+                    .await
+                }
+                pub async fn opt(self) -> Result<Option<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))?
+                        .map(|row| (self.mapper)((self.extractor)(&row))))
+                }
+                pub async fn execute(self) -> Result<u64, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    self.client
+                        .execute(stmt, &self.params)
+                        .await
+                        .map_err(query_err(self.query))
+                }
+                pub async fn iter(self) -> Result<I32Stream<T>, Error> {
+                    let stmt = self
+                        .stmt
+                        .prepare(self.client)
+                        .await
+                        .map_err(query_err(self.query))?;
+                    let it = I32Stream {
+                        inner: Box::pin(
+                            self.client
+                                .query_raw(
+                                    stmt,
+                                    cornucopia_async::private::slice_iter(&self.params),
+                                )
+                                .await
+                                .map_err(query_err(self.query))?,
+                        ),
+                        extractor: self.extractor,
+                        mapper: self.mapper,
+                        query: self.query,
+                    };
+                    Ok(it)
+                }
+            }
+            pub struct I32Stream<T> {
+                inner: std::pin::Pin<Box<tokio_postgres::RowStream>>,
+                extractor: fn(&tokio_postgres::Row) -> i32,
+                mapper: fn(i32) -> T,
+                query: &'static str,
+            }
+            impl<T> futures::Stream for I32Stream<T> {
+                type Item = Result<T, Error>;
+                fn poll_next(
+                    self: std::pin::Pin<&mut Self>,
+                    cx: &mut std::task::Context<'_>,
+                ) -> std::task::Poll<Option<Self::Item>> {
+                    let this = self.get_mut();
+                    futures::Stream::poll_next(this.inner.as_mut(), cx).map(|it| {
+                        it.map(|res| {
+                            res.map(|row| (this.mapper)((this.extractor)(&row)))
+                                .map_err(query_err(this.query))
+                        })
+                    })
+                }
+            }
+            pub fn author_by_id() -> AuthorByIdStmt {
+                AuthorByIdStmt(cornucopia_async::private::Stmt::new(
+                    "SELECT id FROM author WHERE id = $1",
+                ))
+            }
+            pub struct AuthorByIdStmt(cornucopia_async::private::Stmt);
+            impl AuthorByIdStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
+                pub fn bind<'a, C: ::cornucopia_async::GenericClient>(
+                    &'a mut self,
+                    client: &'a C,
+                    id: &'a i32,
+                ) -> AuthorRowQuery<'a, C, super::AuthorRow, 1> {
+                    AuthorRowQuery {
+                        client,
+                        params: [id],
+                        stmt: &mut self.0,
+                        extractor: |row| super::AuthorRow { id: row.get(0) },
+                        mapper: |it| <super::AuthorRow>::from(it),
+                        capacity: 0,
+                        query: "author_by_id",
+                    }
+                }
+            }
+            pub fn author_ids() -> AuthorIdsStmt {
+                AuthorIdsStmt(cornucopia_async::private::Stmt::new(
+                    "SELECT id FROM author",
+                ))
+            }
+            pub struct AuthorIdsStmt(cornucopia_async::private::Stmt);
+            impl AuthorIdsStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
+                pub fn bind<'a, C: ::cornucopia_async::GenericClient>(
+                    &'a mut self,
+                    client: &'a C,
+                ) -> I32Query<'a, C, i32, 0> {
+                    I32Query {
+                        client,
+                        params: [],
+                        stmt: &mut self.0,
+                        extractor: |row| row.get(0),
+                        mapper: |it| it,
+                        capacity: 0,
+                        query: "author_ids",
+                    }
+                }
+            }
+            pub fn insert_author() -> InsertAuthorStmt {
+                InsertAuthorStmt(cornucopia_async::private::Stmt::new(
+                    "INSERT INTO author (id) VALUES ($1)",
+                ))
+            }
+            pub struct InsertAuthorStmt(cornucopia_async::private::Stmt);
+            impl InsertAuthorStmt {
+                pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                    self.0.statement()
+                }
+                pub async fn bind<'a, C: ::cornucopia_async::GenericClient>(
+                    &'a mut self,
+                    client: &'a C,
+                    id: &'a i32,
+                ) -> Result<u64, Error> {
+                    let stmt = self
+                        .0
+                        .prepare(client)
+                        .await
+                        .map_err(query_err("insert_author"))?;
+                    client
+                        .execute(stmt, &[id])
+                        .await
+                        .map_err(query_err("insert_author"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod float {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct FloatValues {
+            /// `float4`
+            pub nan: cornucopia_sync::OrderedF32,
+            /// `float8`
+            pub zero: cornucopia_sync::OrderedF64,
+            /// `float8[]`
+            pub list: Vec<cornucopia_sync::OrderedF64>,
+        }
+        pub struct FloatValuesBorrowed<'a> {
+            /// `float4`
+            pub nan: cornucopia_sync::OrderedF32,
+            /// `float8`
+            pub zero: cornucopia_sync::OrderedF64,
+            /// `float8[]`
+            pub list: cornucopia_sync::ArrayIterator<'a, cornucopia_sync::OrderedF64>,
+        }
+        impl<'a> From<FloatValuesBorrowed<'a>> for FloatValues {
+            #[inline]
+            fn from(FloatValuesBorrowed { nan, zero, list }: FloatValuesBorrowed<'a>) -> Self {
+                Self {
+                    nan,
+                    zero,
+                    list: list.map(|v| v).collect(),
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct OrderedF64Query<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> cornucopia_sync::OrderedF64,
+            mapper: fn(cornucopia_sync::OrderedF64) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> OrderedF64Query<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(cornucopia_sync::OrderedF64) -> R,
+            ) -> OrderedF64Query<'a, C, R, N> {
+                OrderedF64Query {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct FloatValuesQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> FloatValuesBorrowed,
+            mapper: fn(FloatValuesBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> FloatValuesQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(FloatValuesBorrowed) -> R,
+            ) -> FloatValuesQuery<'a, C, R, N> {
+                FloatValuesQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn float_echo() -> FloatEchoStmt {
+            FloatEchoStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT $1::float8 AS value",
+            ))
+        }
+        pub struct FloatEchoStmt(cornucopia_sync::private::Stmt);
+        impl FloatEchoStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                value: &'a cornucopia_sync::OrderedF64,
+            ) -> OrderedF64Query<'a, C, cornucopia_sync::OrderedF64, 1> {
+                OrderedF64Query {
+                    client,
+                    params: [value],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn float_values() -> FloatValuesStmt {
+            FloatValuesStmt(cornucopia_sync::private::Stmt::new("SELECT 'NaN'::float4 AS nan, '-0'::float8 AS zero, ARRAY[1.5, 'NaN']::float8[] AS list"))
+        }
+        pub struct FloatValuesStmt(cornucopia_sync::private::Stmt);
+        impl FloatValuesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> FloatValuesQuery<'a, C, FloatValues, 0> {
+                FloatValuesQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| FloatValuesBorrowed {
+                        nan: row.get(0),
+                        zero: row.get(1),
+                        list: row.get(2),
+                    },
+                    mapper: |it| <FloatValues>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {
+    pub mod public {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types)]
+        pub enum Priority {
+            trivial,
+            low,
+            medium,
+            high,
+            urgent,
+        }
+        impl<'a> postgres_types::ToSql for Priority {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                buf: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let s = match *self {
+                    Priority::trivial => "trivial",
+                    Priority::low => "low",
+                    Priority::medium => "medium",
+                    Priority::high => "high",
+                    Priority::urgent => "urgent",
+                };
+                buf.extend_from_slice(s.as_bytes());
+                std::result::Result::Ok(postgres_types::IsNull::No)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "priority" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 5 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "trivial" => true,
+                            "low" => true,
+                            "medium" => true,
+                            "high" => true,
+                            "urgent" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+        impl<'a> postgres_types::FromSql<'a> for Priority {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                buf: &'a [u8],
+            ) -> std::result::Result<
+                Priority,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                match std::str::from_utf8(buf)? {
+                    "trivial" => std::result::Result::Ok(Priority::trivial),
+                    "low" => std::result::Result::Ok(Priority::low),
+                    "medium" => std::result::Result::Ok(Priority::medium),
+                    "high" => std::result::Result::Ok(Priority::high),
+                    "urgent" => std::result::Result::Ok(Priority::urgent),
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
+                        "invalid variant `{}`",
+                        s
+                    ))),
+                }
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "priority" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 5 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "trivial" => true,
+                            "low" => true,
+                            "medium" => true,
+                            "high" => true,
+                            "urgent" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod hash {
+        #[derive(Debug)]
+        pub struct InsertTaskParams<T1: cornucopia_sync::StringSql> {
+            /// `text`
+            pub name: T1,
+            /// `priority`
+            pub priority: super::super::types::public::Priority,
+        }
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct Tasks {
+            /// `text`
+            pub name: String,
+            /// `priority`
+            pub priority: super::super::types::public::Priority,
+            /// `text[]`
+            pub tags: Vec<String>,
+        }
+        pub struct TasksBorrowed<'a> {
+            /// `text`
+            pub name: &'a str,
+            /// `priority`
+            pub priority: super::super::types::public::Priority,
+            /// `text[]`
+            pub tags: cornucopia_sync::ArrayIterator<'a, &'a str>,
+        }
+        impl<'a> From<TasksBorrowed<'a>> for Tasks {
+            #[inline]
+            fn from(
+                TasksBorrowed {
+                    name,
+                    priority,
+                    tags,
+                }: TasksBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    name: name.into(),
+                    priority,
+                    tags: tags.map(|v| v.into()).collect(),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ScoredTasks {
+            /// `text`
+            pub name: String,
+            /// `float8`
+            pub score: f64,
+        }
+        pub struct ScoredTasksBorrowed<'a> {
+            /// `text`
+            pub name: &'a str,
+            /// `float8`
+            pub score: f64,
+        }
+        impl<'a> From<ScoredTasksBorrowed<'a>> for ScoredTasks {
+            #[inline]
+            fn from(ScoredTasksBorrowed { name, score }: ScoredTasksBorrowed<'a>) -> Self {
+                Self {
+                    name: name.into(),
+                    score,
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct TasksQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> TasksBorrowed,
+            mapper: fn(TasksBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> TasksQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(TasksBorrowed) -> R) -> TasksQuery<'a, C, R, N> {
+                TasksQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct ScoredTasksQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> ScoredTasksBorrowed,
+            mapper: fn(ScoredTasksBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> ScoredTasksQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(ScoredTasksBorrowed) -> R,
+            ) -> ScoredTasksQuery<'a, C, R, N> {
+                ScoredTasksQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn insert_task() -> InsertTaskStmt {
+            InsertTaskStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO task (name, priority) VALUES ($1, $2)",
+            ))
+        }
+        pub struct InsertTaskStmt(cornucopia_sync::private::Stmt);
+        impl InsertTaskStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
+                &'a mut self,
+                client: &'a mut C,
+                name: &'a T1,
+                priority: &'a super::super::types::public::Priority,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[name, priority])
+            }
+        }
+        impl<'a, C: GenericClient, T1: cornucopia_sync::StringSql>
+            cornucopia_sync::Params<'a, InsertTaskParams<T1>, Result<u64, postgres::Error>, C>
+            for InsertTaskStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertTaskParams<T1>,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(client, &params.name, &params.priority)
+            }
+        }
+        pub fn tasks() -> TasksStmt {
+            TasksStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT name, priority, ARRAY[name] AS tags FROM task",
+            ))
+        }
+        pub struct TasksStmt(cornucopia_sync::private::Stmt);
+        impl TasksStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> TasksQuery<'a, C, Tasks, 0> {
+                TasksQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| TasksBorrowed {
+                        name: row.get(0),
+                        priority: row.get(1),
+                        tags: row.get(2),
+                    },
+                    mapper: |it| <Tasks>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn scored_tasks() -> ScoredTasksStmt {
+            ScoredTasksStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT name, 1.5::float8 AS score FROM task",
+            ))
+        }
+        pub struct ScoredTasksStmt(cornucopia_sync::private::Stmt);
+        impl ScoredTasksStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> ScoredTasksQuery<'a, C, ScoredTasks, 0> {
+                ScoredTasksQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| ScoredTasksBorrowed {
+                        name: row.get(0),
+                        score: row.get(1),
+                    },
+                    mapper: |it| <ScoredTasks>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
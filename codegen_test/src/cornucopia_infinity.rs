@@ -0,0 +1,243 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod infinity {
+        #[derive(Clone, Copy, Debug)]
+        pub struct InsertSubscriptionParams {
+            /// `int4`
+            pub id: i32,
+            /// `timestamptz`
+            pub since: cornucopia_sync::Timestamp<time::OffsetDateTime>,
+            /// `timestamp`
+            pub until: cornucopia_sync::Timestamp<time::PrimitiveDateTime>,
+        }
+        #[derive(Debug, Clone, PartialEq, Copy)]
+        pub struct Subscriptions {
+            /// `int4`
+            pub id: i32,
+            /// `timestamptz`
+            pub since: cornucopia_sync::Timestamp<time::OffsetDateTime>,
+            /// `timestamp`
+            pub until: cornucopia_sync::Timestamp<time::PrimitiveDateTime>,
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct SubscriptionsQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> Subscriptions,
+            mapper: fn(Subscriptions) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> SubscriptionsQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(Subscriptions) -> R) -> SubscriptionsQuery<'a, C, R, N> {
+                SubscriptionsQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct I32Query<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> i32,
+            mapper: fn(i32) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> I32Query<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(i32) -> R) -> I32Query<'a, C, R, N> {
+                I32Query {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn insert_subscription() -> InsertSubscriptionStmt {
+            InsertSubscriptionStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO subscription (id, since, until) VALUES ($1, $2, $3)",
+            ))
+        }
+        pub struct InsertSubscriptionStmt(cornucopia_sync::private::Stmt);
+        impl InsertSubscriptionStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                id: &'a i32,
+                since: &'a cornucopia_sync::Timestamp<time::OffsetDateTime>,
+                until: &'a cornucopia_sync::Timestamp<time::PrimitiveDateTime>,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[id, since, until])
+            }
+        }
+        impl<'a, C: GenericClient>
+            cornucopia_sync::Params<'a, InsertSubscriptionParams, Result<u64, postgres::Error>, C>
+            for InsertSubscriptionStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertSubscriptionParams,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(client, &params.id, &params.since, &params.until)
+            }
+        }
+        pub fn subscriptions() -> SubscriptionsStmt {
+            SubscriptionsStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT * FROM subscription ORDER BY id",
+            ))
+        }
+        pub struct SubscriptionsStmt(cornucopia_sync::private::Stmt);
+        impl SubscriptionsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> SubscriptionsQuery<'a, C, Subscriptions, 0> {
+                SubscriptionsQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| Subscriptions {
+                        id: row.get(0),
+                        since: row.get(1),
+                        until: row.get(2),
+                    },
+                    mapper: |it| <Subscriptions>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn open_ended_ids() -> OpenEndedIdsStmt {
+            OpenEndedIdsStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT id FROM subscription WHERE until = 'infinity' ORDER BY id",
+            ))
+        }
+        pub struct OpenEndedIdsStmt(cornucopia_sync::private::Stmt);
+        impl OpenEndedIdsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> I32Query<'a, C, i32, 0> {
+                I32Query {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it,
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
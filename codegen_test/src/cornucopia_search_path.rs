@@ -0,0 +1,241 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {
+    pub mod tenant {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(non_camel_case_types)]
+        pub enum EntryKind {
+            debit,
+            credit,
+        }
+        impl<'a> postgres_types::ToSql for EntryKind {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                buf: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let s = match *self {
+                    EntryKind::debit => "debit",
+                    EntryKind::credit => "credit",
+                };
+                buf.extend_from_slice(s.as_bytes());
+                std::result::Result::Ok(postgres_types::IsNull::No)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "entry_kind" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 2 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "debit" => true,
+                            "credit" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+        impl<'a> postgres_types::FromSql<'a> for EntryKind {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                buf: &'a [u8],
+            ) -> std::result::Result<
+                EntryKind,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                match std::str::from_utf8(buf)? {
+                    "debit" => std::result::Result::Ok(EntryKind::debit),
+                    "credit" => std::result::Result::Ok(EntryKind::credit),
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
+                        "invalid variant `{}`",
+                        s
+                    ))),
+                }
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                if ty.name() != "entry_kind" {
+                    return false;
+                }
+                match *ty.kind() {
+                    postgres_types::Kind::Enum(ref variants) => {
+                        if variants.len() != 2 {
+                            return false;
+                        }
+                        variants.iter().all(|v| match &**v {
+                            "debit" => true,
+                            "credit" => true,
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod ledger {
+        #[derive(Clone, Copy, Debug)]
+        pub struct InsertEntryParams {
+            /// `tenant.entry_kind`
+            pub kind: super::super::types::tenant::EntryKind,
+            /// `int4`
+            pub amount: i32,
+        }
+        #[derive(Debug, Clone, PartialEq, Copy)]
+        pub struct Entries {
+            /// `tenant.entry_kind`
+            pub kind: super::super::types::tenant::EntryKind,
+            /// `int4`
+            pub amount: i32,
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct EntriesQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> Entries,
+            mapper: fn(Entries) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> EntriesQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(self, mapper: fn(Entries) -> R) -> EntriesQuery<'a, C, R, N> {
+                EntriesQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn insert_entry() -> InsertEntryStmt {
+            InsertEntryStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO ledger (kind, amount) VALUES ($1, $2)",
+            ))
+        }
+        pub struct InsertEntryStmt(cornucopia_sync::private::Stmt);
+        impl InsertEntryStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                kind: &'a super::super::types::tenant::EntryKind,
+                amount: &'a i32,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[kind, amount])
+            }
+        }
+        impl<'a, C: GenericClient>
+            cornucopia_sync::Params<'a, InsertEntryParams, Result<u64, postgres::Error>, C>
+            for InsertEntryStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertEntryParams,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(client, &params.kind, &params.amount)
+            }
+        }
+        pub fn entries() -> EntriesStmt {
+            EntriesStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT kind, amount FROM ledger ORDER BY id",
+            ))
+        }
+        pub struct EntriesStmt(cornucopia_sync::private::Stmt);
+        impl EntriesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> EntriesQuery<'a, C, Entries, 0> {
+                EntriesQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| Entries {
+                        kind: row.get(0),
+                        amount: row.get(1),
+                    },
+                    mapper: |it| <Entries>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[derive(Debug)]
+pub struct InsertSplitBookParams<T1: cornucopia_sync::StringSql, T2: cornucopia_sync::StringSql> {
+    /// `text`
+    pub name: T1,
+    /// `text`, nullable
+    pub author: Option<T2>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitBook {
+    /// `text`
+    pub name: String,
+    /// `text`, nullable
+    pub author: Option<String>,
+}
+pub struct SplitBookBorrowed<'a> {
+    /// `text`
+    pub name: &'a str,
+    /// `text`, nullable
+    pub author: Option<&'a str>,
+}
+impl<'a> From<SplitBookBorrowed<'a>> for SplitBook {
+    #[inline]
+    fn from(SplitBookBorrowed { name, author }: SplitBookBorrowed<'a>) -> Self {
+        Self {
+            name: name.into(),
+            author: author.map(|v| v.into()),
+        }
+    }
+}
+use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+pub struct SplitBookQuery<'a, C: GenericClient, T, const N: usize> {
+    client: &'a mut C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    stmt: &'a mut cornucopia_sync::private::Stmt,
+    extractor: fn(&postgres::Row) -> SplitBookBorrowed,
+    mapper: fn(SplitBookBorrowed) -> T,
+    capacity: usize,
+}
+impl<'a, C, T: 'a, const N: usize> SplitBookQuery<'a, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(SplitBookBorrowed) -> R) -> SplitBookQuery<'a, C, R, N> {
+        SplitBookQuery {
+            client: self.client,
+            params: self.params,
+            stmt: self.stmt,
+            extractor: self.extractor,
+            mapper,
+            capacity: self.capacity,
+        }
+    }
+    pub fn one(self) -> Result<T, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        let row = self.client.query_one(stmt, &self.params)?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    }
+    pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+        let capacity = self.capacity;
+        self.vec_with_capacity(capacity)
+    }
+    pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+        self.iter()?
+            .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                vec.push(it?);
+                Ok(vec)
+            })
+    }
+    pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        Ok(self
+            .client
+            .query_opt(stmt, &self.params)?
+            .map(|row| (self.mapper)((self.extractor)(&row))))
+    }
+    pub fn execute(self) -> Result<u64, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        self.client.execute(stmt, &self.params)
+    }
+    pub fn iter(
+        self,
+    ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        let it = self
+            .client
+            .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+            .iterator()
+            .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+        Ok(it)
+    }
+}
+pub fn insert_split_book() -> InsertSplitBookStmt {
+    InsertSplitBookStmt(cornucopia_sync::private::Stmt::new(
+        "INSERT INTO Book (name, author) VALUES ($1, $2)",
+    ))
+}
+pub struct InsertSplitBookStmt(cornucopia_sync::private::Stmt);
+impl InsertSplitBookStmt {
+    pub fn statement(&self) -> Option<&postgres::Statement> {
+        self.0.statement()
+    }
+    pub fn bind<
+        'a,
+        C: GenericClient,
+        T1: cornucopia_sync::StringSql,
+        T2: cornucopia_sync::StringSql,
+    >(
+        &'a mut self,
+        client: &'a mut C,
+        name: &'a T1,
+        author: &'a Option<T2>,
+    ) -> Result<u64, postgres::Error> {
+        let stmt = self.0.prepare(client)?;
+        client.execute(stmt, &[name, author])
+    }
+}
+impl<'a, C: GenericClient, T1: cornucopia_sync::StringSql, T2: cornucopia_sync::StringSql>
+    cornucopia_sync::Params<'a, InsertSplitBookParams<T1, T2>, Result<u64, postgres::Error>, C>
+    for InsertSplitBookStmt
+{
+    fn params(
+        &'a mut self,
+        client: &'a mut C,
+        params: &'a InsertSplitBookParams<T1, T2>,
+    ) -> Result<u64, postgres::Error> {
+        self.bind(client, &params.name, &params.author)
+    }
+}
+pub fn split_books() -> SplitBooksStmt {
+    SplitBooksStmt(cornucopia_sync::private::Stmt::new(
+        "SELECT name, author FROM Book WHERE name LIKE 'Split%' ORDER BY name",
+    ))
+}
+pub struct SplitBooksStmt(cornucopia_sync::private::Stmt);
+impl SplitBooksStmt {
+    pub fn statement(&self) -> Option<&postgres::Statement> {
+        self.0.statement()
+    }
+    pub fn bind<'a, C: GenericClient>(
+        &'a mut self,
+        client: &'a mut C,
+    ) -> SplitBookQuery<'a, C, SplitBook, 0> {
+        SplitBookQuery {
+            client,
+            params: [],
+            stmt: &mut self.0,
+            extractor: |row| SplitBookBorrowed {
+                name: row.get(0),
+                author: row.get(1),
+            },
+            mapper: |it| <SplitBook>::from(it),
+            capacity: 0,
+        }
+    }
+}
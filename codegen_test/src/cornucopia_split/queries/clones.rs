@@ -0,0 +1,91 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+pub struct PublicCloneCompositeQuery<'a, C: GenericClient, T, const N: usize> {
+    client: &'a mut C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    stmt: &'a mut cornucopia_sync::private::Stmt,
+    extractor: fn(&postgres::Row) -> super::super::types::public::CloneCompositeBorrowed,
+    mapper: fn(super::super::types::public::CloneCompositeBorrowed) -> T,
+    capacity: usize,
+}
+impl<'a, C, T: 'a, const N: usize> PublicCloneCompositeQuery<'a, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(super::super::types::public::CloneCompositeBorrowed) -> R,
+    ) -> PublicCloneCompositeQuery<'a, C, R, N> {
+        PublicCloneCompositeQuery {
+            client: self.client,
+            params: self.params,
+            stmt: self.stmt,
+            extractor: self.extractor,
+            mapper,
+            capacity: self.capacity,
+        }
+    }
+    pub fn one(self) -> Result<T, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        let row = self.client.query_one(stmt, &self.params)?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    }
+    pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+        let capacity = self.capacity;
+        self.vec_with_capacity(capacity)
+    }
+    pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+        self.iter()?
+            .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                vec.push(it?);
+                Ok(vec)
+            })
+    }
+    pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        Ok(self
+            .client
+            .query_opt(stmt, &self.params)?
+            .map(|row| (self.mapper)((self.extractor)(&row))))
+    }
+    pub fn execute(self) -> Result<u64, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        self.client.execute(stmt, &self.params)
+    }
+    pub fn iter(
+        self,
+    ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error> {
+        let stmt = self.stmt.prepare(self.client)?;
+        let it = self
+            .client
+            .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+            .iterator()
+            .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+        Ok(it)
+    }
+}
+pub fn split_clones() -> SplitClonesStmt {
+    SplitClonesStmt(cornucopia_sync::private::Stmt::new(
+        "SELECT composite FROM clone",
+    ))
+}
+pub struct SplitClonesStmt(cornucopia_sync::private::Stmt);
+impl SplitClonesStmt {
+    pub fn statement(&self) -> Option<&postgres::Statement> {
+        self.0.statement()
+    }
+    pub fn bind<'a, C: GenericClient>(
+        &'a mut self,
+        client: &'a mut C,
+    ) -> PublicCloneCompositeQuery<'a, C, super::super::types::public::CloneComposite, 0> {
+        PublicCloneCompositeQuery {
+            client,
+            params: [],
+            stmt: &mut self.0,
+            extractor: |row| row.get(0),
+            mapper: |it| it.into(),
+            capacity: 0,
+        }
+    }
+}
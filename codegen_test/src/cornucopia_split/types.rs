@@ -0,0 +1,98 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+pub mod public {
+    #[derive(Debug, postgres_types :: FromSql, Clone, PartialEq)]
+    #[postgres(name = "clone_composite")]
+    pub struct CloneComposite {
+        #[postgres(name = "first")]
+        pub first: i32,
+        #[postgres(name = "second")]
+        pub second: std::string::String,
+    }
+    #[derive(Debug)]
+    pub struct CloneCompositeBorrowed<'a> {
+        pub first: i32,
+        pub second: &'a str,
+    }
+    impl<'a> std::convert::From<CloneCompositeBorrowed<'a>> for CloneComposite {
+        #[inline]
+        fn from(CloneCompositeBorrowed { first, second }: CloneCompositeBorrowed<'a>) -> Self {
+            Self {
+                first,
+                second: second.into(),
+            }
+        }
+    }
+    impl<'a> postgres_types::FromSql<'a> for CloneCompositeBorrowed<'a> {
+        fn from_sql(
+            ty: &postgres_types::Type,
+            out: &'a [u8],
+        ) -> std::result::Result<
+            CloneCompositeBorrowed<'a>,
+            std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+        > {
+            let mut first = None;
+            let mut second = None;
+            cornucopia_sync::private::read_composite(ty, out, |field, out| {
+                match field.name() {
+                    "first" => {
+                        first = Some(postgres_types::private::read_value(field.type_(), out)?)
+                    }
+                    "second" => {
+                        second = Some(postgres_types::private::read_value(field.type_(), out)?)
+                    }
+                    _ => {
+                        return std::result::Result::Err(std::convert::Into::into(format!(
+                            "unexpected field: {}",
+                            field.name()
+                        )))
+                    }
+                }
+                std::result::Result::Ok(())
+            })?;
+            std::result::Result::Ok(CloneCompositeBorrowed {
+                first: first.ok_or("missing field: first")?,
+                second: second.ok_or("missing field: second")?,
+            })
+        }
+        fn accepts(ty: &postgres_types::Type) -> bool {
+            ty.name() == "clone_composite" && ty.schema() == "public"
+        }
+    }
+    impl<'a> postgres_types::ToSql for CloneCompositeBorrowed<'a> {
+        fn to_sql(
+            &self,
+            ty: &postgres_types::Type,
+            out: &mut postgres_types::private::BytesMut,
+        ) -> std::result::Result<
+            postgres_types::IsNull,
+            std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+        > {
+            let CloneCompositeBorrowed { first, second } = self;
+            cornucopia_sync::private::write_composite(ty, out, |field, out| match field.name() {
+                "first" => postgres_types::ToSql::to_sql(first, field.type_(), out),
+                "second" => postgres_types::ToSql::to_sql(second, field.type_(), out),
+                _ => unreachable!(),
+            })
+        }
+        fn accepts(ty: &postgres_types::Type) -> bool {
+            cornucopia_sync::private::composite_accepts(ty, "clone_composite", 2, |f| {
+                match f.name() {
+                    "first" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
+                    "second" => <&'a str as postgres_types::ToSql>::accepts(f.type_()),
+                    _ => false,
+                }
+            })
+        }
+        fn to_sql_checked(
+            &self,
+            ty: &postgres_types::Type,
+            out: &mut postgres_types::private::BytesMut,
+        ) -> std::result::Result<
+            postgres_types::IsNull,
+            std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+        > {
+            postgres_types::__to_sql_checked(self, ty, out)
+        }
+    }
+}
@@ -0,0 +1,306 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod legacy {
+        #[derive(Debug)]
+        pub struct InsertLegacyTextParams<
+            T1: cornucopia_sync::StringSql,
+            T2: cornucopia_sync::StringSql,
+            T3: cornucopia_sync::StringSql,
+            T4: cornucopia_sync::ArraySql<Item = T3>,
+        > {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub name: T1,
+            /// `varchar`, nullable
+            pub note: Option<T2>,
+            /// `text[]`
+            pub tags: T4,
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct LegacyText {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub name: Vec<u8>,
+            /// `varchar`, nullable
+            pub note: Option<Vec<u8>>,
+            /// `text[]`
+            pub tags: Vec<Vec<u8>>,
+        }
+        pub struct LegacyTextBorrowed<'a> {
+            /// `int4`
+            pub id: i32,
+            /// `text`
+            pub name: cornucopia_sync::TextBytes<'a>,
+            /// `varchar`, nullable
+            pub note: Option<cornucopia_sync::TextBytes<'a>>,
+            /// `text[]`
+            pub tags: cornucopia_sync::ArrayIterator<'a, cornucopia_sync::TextBytes<'a>>,
+        }
+        impl<'a> From<LegacyTextBorrowed<'a>> for LegacyText {
+            #[inline]
+            fn from(
+                LegacyTextBorrowed {
+                    id,
+                    name,
+                    note,
+                    tags,
+                }: LegacyTextBorrowed<'a>,
+            ) -> Self {
+                Self {
+                    id,
+                    name: name.into(),
+                    note: note.map(|v| v.into()),
+                    tags: tags.map(|v| v.into()).collect(),
+                }
+            }
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct LegacyTextQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> LegacyTextBorrowed,
+            mapper: fn(LegacyTextBorrowed) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> LegacyTextQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(LegacyTextBorrowed) -> R,
+            ) -> LegacyTextQuery<'a, C, R, N> {
+                LegacyTextQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub struct Vecu8Query<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> cornucopia_sync::TextBytes<'_>,
+            mapper: fn(cornucopia_sync::TextBytes<'_>) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> Vecu8Query<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(cornucopia_sync::TextBytes<'_>) -> R,
+            ) -> Vecu8Query<'a, C, R, N> {
+                Vecu8Query {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn insert_legacy_text() -> InsertLegacyTextStmt {
+            InsertLegacyTextStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO legacy_text (id, name, note, tags) VALUES ($1, $2, $3, $4)",
+            ))
+        }
+        pub struct InsertLegacyTextStmt(cornucopia_sync::private::Stmt);
+        impl InsertLegacyTextStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+                T3: cornucopia_sync::StringSql,
+                T4: cornucopia_sync::ArraySql<Item = T3>,
+            >(
+                &'a mut self,
+                client: &'a mut C,
+                id: &'a i32,
+                name: &'a T1,
+                note: &'a Option<T2>,
+                tags: &'a T4,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[id, name, note, tags])
+            }
+        }
+        impl<
+                'a,
+                C: GenericClient,
+                T1: cornucopia_sync::StringSql,
+                T2: cornucopia_sync::StringSql,
+                T3: cornucopia_sync::StringSql,
+                T4: cornucopia_sync::ArraySql<Item = T3>,
+            >
+            cornucopia_sync::Params<
+                'a,
+                InsertLegacyTextParams<T1, T2, T3, T4>,
+                Result<u64, postgres::Error>,
+                C,
+            > for InsertLegacyTextStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertLegacyTextParams<T1, T2, T3, T4>,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(client, &params.id, &params.name, &params.note, &params.tags)
+            }
+        }
+        pub fn legacy_texts() -> LegacyTextsStmt {
+            LegacyTextsStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT * FROM legacy_text ORDER BY id",
+            ))
+        }
+        pub struct LegacyTextsStmt(cornucopia_sync::private::Stmt);
+        impl LegacyTextsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> LegacyTextQuery<'a, C, LegacyText, 0> {
+                LegacyTextQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| LegacyTextBorrowed {
+                        id: row.get(0),
+                        name: row.get(1),
+                        note: row.get(2),
+                        tags: row.get(3),
+                    },
+                    mapper: |it| <LegacyText>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+        pub fn legacy_names() -> LegacyNamesStmt {
+            LegacyNamesStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT name FROM legacy_text ORDER BY id",
+            ))
+        }
+        pub struct LegacyNamesStmt(cornucopia_sync::private::Stmt);
+        impl LegacyNamesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> Vecu8Query<'a, C, Vec<u8>, 0> {
+                Vecu8Query {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| row.get(0),
+                    mapper: |it| it.into(),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
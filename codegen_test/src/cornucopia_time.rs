@@ -0,0 +1,173 @@
+// This file was generated with `cornucopia`. Do not modify.
+
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod types {}
+#[allow(clippy::all, clippy::pedantic)]
+#[allow(unused_variables)]
+#[allow(unused_imports)]
+#[allow(dead_code)]
+pub mod queries {
+    pub mod temporal {
+        #[derive(Clone, Copy, Debug)]
+        pub struct InsertTemporalParams {
+            /// `timestamp`
+            pub timestamp_: time::PrimitiveDateTime,
+            /// `timestamptz`
+            pub timestamptz_: time::OffsetDateTime,
+            /// `date`
+            pub date_: time::Date,
+            /// `time`
+            pub time_: time::Time,
+        }
+        #[derive(Debug, Clone, PartialEq, Copy)]
+        pub struct TemporalValues {
+            /// `timestamp`
+            pub timestamp_: time::PrimitiveDateTime,
+            /// `timestamptz`
+            pub timestamptz_: time::OffsetDateTime,
+            /// `date`
+            pub date_: time::Date,
+            /// `time`
+            pub time_: time::Time,
+        }
+        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        pub struct TemporalValuesQuery<'a, C: GenericClient, T, const N: usize> {
+            client: &'a mut C,
+            params: [&'a (dyn postgres_types::ToSql + Sync); N],
+            stmt: &'a mut cornucopia_sync::private::Stmt,
+            extractor: fn(&postgres::Row) -> TemporalValues,
+            mapper: fn(TemporalValues) -> T,
+            capacity: usize,
+        }
+        impl<'a, C, T: 'a, const N: usize> TemporalValuesQuery<'a, C, T, N>
+        where
+            C: GenericClient,
+        {
+            pub fn map<R>(
+                self,
+                mapper: fn(TemporalValues) -> R,
+            ) -> TemporalValuesQuery<'a, C, R, N> {
+                TemporalValuesQuery {
+                    client: self.client,
+                    params: self.params,
+                    stmt: self.stmt,
+                    extractor: self.extractor,
+                    mapper,
+                    capacity: self.capacity,
+                }
+            }
+            pub fn one(self) -> Result<T, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                let row = self.client.query_one(stmt, &self.params)?;
+                Ok((self.mapper)((self.extractor)(&row)))
+            }
+            pub fn all(self) -> Result<Vec<T>, postgres::Error> {
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
+            }
+            pub fn opt(self) -> Result<Option<T>, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                Ok(self
+                    .client
+                    .query_opt(stmt, &self.params)?
+                    .map(|row| (self.mapper)((self.extractor)(&row))))
+            }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
+            pub fn iter(
+                self,
+            ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
+            {
+                let stmt = self.stmt.prepare(self.client)?;
+                let it = self
+                    .client
+                    .query_raw(stmt, cornucopia_sync::private::slice_iter(&self.params))?
+                    .iterator()
+                    .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))));
+                Ok(it)
+            }
+        }
+        pub fn insert_temporal() -> InsertTemporalStmt {
+            InsertTemporalStmt(cornucopia_sync::private::Stmt::new(
+                "INSERT INTO Everything (timestamp_, timestamptz_, date_, time_)
+VALUES ($1, $2, $3, $4)",
+            ))
+        }
+        pub struct InsertTemporalStmt(cornucopia_sync::private::Stmt);
+        impl InsertTemporalStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+                timestamp_: &'a time::PrimitiveDateTime,
+                timestamptz_: &'a time::OffsetDateTime,
+                date_: &'a time::Date,
+                time_: &'a time::Time,
+            ) -> Result<u64, postgres::Error> {
+                let stmt = self.0.prepare(client)?;
+                client.execute(stmt, &[timestamp_, timestamptz_, date_, time_])
+            }
+        }
+        impl<'a, C: GenericClient>
+            cornucopia_sync::Params<'a, InsertTemporalParams, Result<u64, postgres::Error>, C>
+            for InsertTemporalStmt
+        {
+            fn params(
+                &'a mut self,
+                client: &'a mut C,
+                params: &'a InsertTemporalParams,
+            ) -> Result<u64, postgres::Error> {
+                self.bind(
+                    client,
+                    &params.timestamp_,
+                    &params.timestamptz_,
+                    &params.date_,
+                    &params.time_,
+                )
+            }
+        }
+        pub fn temporal_values() -> TemporalValuesStmt {
+            TemporalValuesStmt(cornucopia_sync::private::Stmt::new(
+                "SELECT timestamp_, timestamptz_, date_, time_ FROM Everything",
+            ))
+        }
+        pub struct TemporalValuesStmt(cornucopia_sync::private::Stmt);
+        impl TemporalValuesStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
+            pub fn bind<'a, C: GenericClient>(
+                &'a mut self,
+                client: &'a mut C,
+            ) -> TemporalValuesQuery<'a, C, TemporalValues, 0> {
+                TemporalValuesQuery {
+                    client,
+                    params: [],
+                    stmt: &mut self.0,
+                    extractor: |row| TemporalValues {
+                        timestamp_: row.get(0),
+                        timestamptz_: row.get(1),
+                        date_: row.get(2),
+                        time_: row.get(3),
+                    },
+                    mapper: |it| <TemporalValues>::from(it),
+                    capacity: 0,
+                }
+            }
+        }
+    }
+}
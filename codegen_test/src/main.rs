@@ -1,4 +1,22 @@
 mod cornucopia;
+mod cornucopia_backend_async;
+mod cornucopia_backend_sync;
+mod cornucopia_bigint;
+mod cornucopia_by_name;
+mod cornucopia_cow;
+mod cornucopia_dump;
+mod cornucopia_errors;
+mod cornucopia_filter;
+mod cornucopia_float;
+mod cornucopia_hash;
+mod cornucopia_infinity;
+mod cornucopia_numeric;
+mod cornucopia_search_path;
+mod cornucopia_serde;
+mod cornucopia_split;
+mod cornucopia_text_bytes;
+mod cornucopia_time;
+mod cornucopia_yesql;
 
 use ::cornucopia_async::IterSql;
 use eui48::MacAddress;
@@ -7,32 +25,86 @@ use rust_decimal::Decimal;
 use serde_json::Value;
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     net::{IpAddr, Ipv4Addr},
+    ops::Bound,
 };
 use time::{OffsetDateTime, PrimitiveDateTime};
 use uuid::Uuid;
 
 use crate::cornucopia::{
+    foreign_keys::{public::*, ForeignKey},
     queries::{
-        copy::sync::{insert_clone, insert_copy, select_copy},
+        aggregate::{
+            sync::{count_authors, named_price_stats},
+            NamedPriceStats,
+        },
+        attributes::{
+            sync::{books_by_author, mapped_books},
+            AttributeBook, AuthorFilter, MappedBooks,
+        },
+        copy::sync::{
+            insert_clone, insert_clones, insert_copies, insert_copy, select_characters_in,
+            select_clone, select_clone_enum_array, select_copy, select_copy_domain,
+            select_copy_enum, select_copy_nested,
+        },
+        distinct_on::{
+            sync::{insert_post, latest_posts},
+            LatestPosts,
+        },
         domain::{
             sync::{
                 insert_nightmare_domain, select_nightmare_domain, select_nightmare_domain_null,
             },
             InsertNightmareDomainParams, SelectNightmareDomain, SelectNightmareDomainNull,
         },
+        enum_order::sync::{insert_task, tasks_by_priority},
+        group::{
+            sync::{insert_group_named, named_books, named_flags},
+            NamedBook, NamedBookBook, NamedBookNamed, NamedFlags, NamedFlagsFlag,
+        },
+        hstore::{
+            sync::{insert_setting, select_settings},
+            SelectSettings,
+        },
+        int_enum::{
+            sync::{insert_draft, select_draft},
+            BookStatus, Importance, SelectDraft,
+        },
+        jsonb::{
+            sync::{
+                insert_document, insert_document_path, query_documents, select_document_fields,
+                select_document_paths, select_documents_containing,
+            },
+            SelectDocumentFields,
+        },
         named::sync::{
             named, named_by_id, named_complex, new_named_complex, new_named_hidden,
             new_named_visible,
         },
         named::{Named, NamedComplex, NamedComplexParams, NamedParams},
-        nullity::sync::{new_nullity, nullity},
+        nullity::{
+            sync::{
+                new_nullable_texts, new_nullity, new_tagged, nullable_texts, nullity, select_tagged,
+            },
+            NullableTexts,
+        },
         nullity::{Nullity, NullityParams},
         params::sync::insert_book,
         params::{
-            sync::{find_books, params_use_twice, select_book},
-            SelectBook,
+            sync::{
+                book_names_limited, book_names_nested_limit, find_books, insert_session,
+                params_use_twice, row_order, row_order_shuffled, select_book, session_name_by_id,
+            },
+            RowOrder, SelectBook,
+        },
+        prelude::sync::{insert_attempt, select_attempts},
+        range::{
+            sync::{
+                echo_multirange, insert_booking, select_multiranges, select_overlapping_bookings,
+                select_ranges,
+            },
+            SelectMultiranges, SelectOverlappingBookings, SelectRanges,
         },
         stress::{
             sync::{
@@ -43,18 +115,30 @@ use crate::cornucopia::{
         },
         syntax::{
             sync::{r#typeof, tricky_sql10},
-            TrickySql10Params,
+            TrickySql10Params, TypeofColumn,
+        },
+        union::{
+            sync::{insert_union_book, union_books},
+            UnionBook,
+        },
+        view::{
+            sync::{book_count, book_summary, refresh_book_count},
+            BookCount, BookSummary,
         },
     },
     types::public::{
         CloneCompositeBorrowed, CopyComposite, CustomComposite, CustomCompositeBorrowed,
         DomainComposite, DomainCompositeParams, EnumWithDot, NamedComposite,
         NamedCompositeBorrowed, NamedCompositeWithDot, NightmareComposite,
-        NightmareCompositeParams, NullityComposite, NullityCompositeParams, SpongebobCharacter,
-        SyntaxComposite, SyntaxEnum,
+        NightmareCompositeParams, NullityComposite, NullityCompositeParams, Outcome,
+        OutcomeBorrowed, Priority, Result as PgResult, SpongebobCharacter, SyntaxComposite,
+        SyntaxEnum, Tagged, TaggedParams,
     },
 };
-use cornucopia_sync::Params;
+use crate::cornucopia_cow::queries::cow::{
+    select_cow_book, select_cow_everything_array, select_cow_grouped_book,
+};
+use cornucopia_sync::{JsonPath, Multirange, Params, Range};
 
 pub fn main() {
     let client = &mut Config::new()
@@ -65,18 +149,83 @@ pub fn main() {
         .dbname("postgres")
         .connect(NoTls)
         .unwrap();
+    let _ = check_async_send::<tokio_postgres::Client>;
+    let _ = check_named_stream::<tokio_postgres::Client>;
+    let _ = check_backend_async::<tokio_postgres::Client>;
     test_copy(client);
     test_params(client);
+    test_attributes(client);
+    test_cow(client);
+    test_view(client);
     test_named(client);
     test_nullity(client);
     test_stress(client);
     test_domain(client);
     test_trait_sql(client);
     test_keyword_escaping(client);
+    test_int_enum(client);
+    test_foreign_keys();
+    test_range(client);
+    test_hstore(client);
+    test_prelude_collision(client);
+    test_serialize_borrowed(client);
+    test_jsonb(client);
+    test_count(client);
+    test_union(client);
+    test_group(client);
+    test_query_errors(client);
+    test_numeric_as_f64(client);
+    test_ordered_floats(client);
+    test_enum_order(client);
+    test_column_enums(client);
+    test_distinct_on(client);
+    test_prelude(client);
+    test_limit_capacity(client);
+    test_yesql(client);
+    test_cache(client);
+    test_dyn_params(client);
+    test_ping(client);
+    test_search_path(client);
+    test_reg_types(client);
+    test_insert_select(client);
+    test_filter(client);
+    test_altered_composite(client);
+    test_nullable_default(client);
+    test_statements(client);
+    test_explain(client);
+    test_text_params(client);
+    test_full_text(client);
+    test_param_types(client);
+    test_columns_by_name(client);
+    test_text_as_bytes(client);
+    test_split_modules(client);
+    test_numeric_aggregate(client);
+    test_execute_select(client);
+    test_prepared_statement(client);
+    test_temp_table(client);
+    test_bigint_as_string(client);
+    test_infinite_timestamps(client);
+    test_time_backend(client);
+    test_hash(client);
+    test_backends(client);
+    #[cfg(feature = "ser")]
+    test_serde_feature(client);
+    #[cfg(feature = "gated")]
+    test_gated(client);
 }
 
 pub fn moving<T>(_item: T) {}
 
+/// Custom type some columns are converted to
+#[derive(serde::Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Title(pub String);
+
+impl From<&str> for Title {
+    fn from(value: &str) -> Self {
+        Title(value.to_string())
+    }
+}
+
 pub fn test_params(client: &mut Client) {
     assert_eq!(
         1,
@@ -104,6 +253,106 @@ pub fn test_params(client: &mut Client) {
         ]
     );
     params_use_twice().bind(client, &"name").unwrap();
+    // Row fields keep the order of the first query's columns
+    let expected = RowOrder {
+        z: "z".into(),
+        r: "r".into(),
+        c: "c".into(),
+        a: "a".into(),
+    };
+    assert_eq!(row_order().bind(client).one().unwrap(), expected);
+    assert_eq!(row_order_shuffled().bind(client).one().unwrap(), expected);
+    // A single `uuid` column returned by an insert is extracted as a scalar
+    let id: Uuid = insert_session().bind(client, &"admin").one().unwrap();
+    assert_eq!(
+        session_name_by_id().bind(client, &id).one().unwrap(),
+        "admin".to_string()
+    );
+}
+
+// Test attributes attached to generated structs
+pub fn test_attributes(client: &mut Client) {
+    let filter = AuthorFilter {
+        author: "Marcel Proust",
+    };
+    let books = books_by_author()
+        .params(client, &filter.clone())
+        .all()
+        .unwrap()
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+    assert!(books.contains(&AttributeBook {
+        name: "In Search of Lost Time".into(),
+        author: Some("Marcel Proust".into()),
+    }));
+    // Mapped columns are converted to their custom type
+    let books = mapped_books().bind(client, &"Marcel Proust").all().unwrap();
+    assert_eq!(
+        books,
+        [MappedBooks {
+            name: Title("In Search of Lost Time".into()),
+            author: Some(Title("Marcel Proust".into())),
+        }]
+    );
+}
+
+// Test `Cow` row structs
+pub fn test_cow(client: &mut Client) {
+    let books = select_cow_book().bind(client).all().unwrap();
+    assert!(books.iter().all(|it| matches!(it.name, Cow::Owned(_))));
+    assert_eq!(
+        books
+            .iter()
+            .map(|it| (it.author.as_deref(), it.name.as_ref()))
+            .collect::<Vec<_>>(),
+        select_book()
+            .bind(client)
+            .all()
+            .unwrap()
+            .iter()
+            .map(|it| (it.author.as_deref(), it.name.as_str()))
+            .collect::<Vec<_>>()
+    );
+    // Mapping borrows from the row
+    assert!(select_cow_book()
+        .bind(client)
+        .map(|it| matches!(it.name, Cow::Borrowed(_)))
+        .all()
+        .unwrap()
+        .into_iter()
+        .all(|it| it));
+    select_cow_everything_array().bind(client).all().unwrap();
+    // Groups borrow their text fields too
+    assert!(select_cow_grouped_book()
+        .bind(client)
+        .map(|it| matches!(it.info.name, Cow::Borrowed(_)))
+        .all()
+        .unwrap()
+        .into_iter()
+        .all(|it| it));
+}
+
+// Test view row structs
+pub fn test_view(client: &mut Client) {
+    let books = select_book().bind(client).all().unwrap();
+    assert_eq!(
+        book_summary().bind(client).all().unwrap(),
+        books
+            .iter()
+            .map(|it| BookSummary {
+                name: it.name.clone(),
+                author: it.author.clone(),
+                name_len: Some(it.name.chars().count() as i32),
+            })
+            .collect::<Vec<_>>()
+    );
+    refresh_book_count().bind(client).unwrap();
+    assert_eq!(
+        book_count().bind(client).one().unwrap(),
+        BookCount {
+            count: books.len() as i64
+        }
+    );
 }
 
 pub fn test_trait_sql(client: &mut Client) {
@@ -172,6 +421,51 @@ pub fn test_nullity(client: &mut Client) {
             texts: vec![Some("Hello".to_string()), Some("world".to_string()), None],
         }
     );
+
+    // Nullable arrays are wrapped in an `Option` as a whole
+    new_nullable_texts()
+        .bind(client, &Some(["a", "b"].as_slice()), &"texts 1")
+        .unwrap();
+    new_nullable_texts()
+        .bind(client, &None::<&[&str]>, &"texts 2")
+        .unwrap();
+    assert_eq!(
+        nullable_texts()
+            .bind(client)
+            .map(|it| it.texts.map(|texts| texts.count()))
+            .all()
+            .unwrap(),
+        vec![Some(2), None]
+    );
+    assert_eq!(
+        nullable_texts().bind(client).all().unwrap(),
+        vec![
+            NullableTexts {
+                name: "texts 1".into(),
+                texts: Some(vec!["a".into(), "b".into()]),
+            },
+            NullableTexts {
+                name: "texts 2".into(),
+                texts: None,
+            },
+        ]
+    );
+
+    // Composites round-trip arrays containing nulls
+    new_tagged()
+        .bind(
+            client,
+            &TaggedParams {
+                tags: &[Some("a"), None, Some("b")],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        select_tagged().bind(client).one().unwrap(),
+        Tagged {
+            tags: vec![Some("a".into()), None, Some("b".into())],
+        }
+    );
 }
 
 pub fn test_named(client: &mut Client) {
@@ -297,6 +591,30 @@ pub fn test_named(client: &mut Client) {
             }
         ],
     );
+
+    // Params are only borrowed, the same struct can be bound repeatedly
+    let params = NamedParams {
+        name: "reused",
+        price: None,
+    };
+    let mut stmt = new_named_hidden();
+    let ids = (0..3)
+        .map(|_| stmt.params(client, &params).one().unwrap().id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [last_id + 1, last_id + 2, last_id + 3]);
+
+    // Single field rows can return their field directly
+    assert_eq!(
+        stmt.params(client, &params).one_scalar().unwrap(),
+        last_id + 4
+    );
+    assert_eq!(
+        new_named_visible()
+            .bind(client, &"scalar", &Some(1.0))
+            .opt_scalar()
+            .unwrap(),
+        Some(last_id + 5)
+    );
 }
 
 // Test we correctly implement borrowed version and copy derive
@@ -312,6 +630,26 @@ pub fn test_copy(client: &mut Client) {
     moving(copy_row); // Ignore if copied
     moving(copy_row);
 
+    // Rows of copy scalars, enums, domains and composites are copy
+    let enum_row = select_copy_enum().bind(client).one().unwrap();
+    moving(enum_row);
+    moving(enum_row);
+    let domain_row = select_copy_domain().bind(client).one().unwrap();
+    moving(domain_row);
+    moving(domain_row);
+    let nested_row = select_copy_nested().bind(client).one().unwrap();
+    moving(nested_row);
+    moving(nested_row);
+    assert_eq!(nested_row.composite, copy_params);
+    assert_eq!(enum_row.character, SpongebobCharacter::Squidward);
+
+    // Rows containing an array are not
+    let array_row = select_clone_enum_array().bind(client).one().unwrap();
+    assert_eq!(
+        array_row.clone().characters,
+        [SpongebobCharacter::Squidward]
+    );
+
     // Test clone
     let clone_params = CloneCompositeBorrowed {
         first: 42,
@@ -319,6 +657,36 @@ pub fn test_copy(client: &mut Client) {
     };
     insert_clone().bind(client, &clone_params).unwrap();
     select_copy().bind(client).one().unwrap();
+
+    // Arrays of composites can be bound as a single param
+    let clones = [
+        CloneCompositeBorrowed {
+            first: 1,
+            second: "one",
+        },
+        CloneCompositeBorrowed {
+            first: 2,
+            second: "two",
+        },
+    ];
+    assert_eq!(insert_clones().bind(client, &clones.as_slice()).unwrap(), 2);
+    assert_eq!(
+        insert_copies()
+            .bind(client, &vec![copy_params, copy_params])
+            .unwrap(),
+        2
+    );
+    assert_eq!(select_clone().bind(client).all().unwrap().len(), 3);
+    assert_eq!(select_copy().bind(client).all().unwrap().len(), 3);
+    // Enum arrays params bind from slices
+    let characters = [SpongebobCharacter::Squidward, SpongebobCharacter::Bob];
+    assert_eq!(
+        select_characters_in()
+            .bind(client, &characters.as_slice())
+            .all()
+            .unwrap(),
+        [SpongebobCharacter::Bob, SpongebobCharacter::Squidward]
+    );
 }
 
 // Test domain erasing
@@ -365,6 +733,25 @@ pub fn test_domain(client: &mut Client) {
     };
     let actual = select_nightmare_domain_null().bind(client).one().unwrap();
     assert_eq!(expected, actual);
+
+    // Params owning large values are neither `Copy` nor `Clone`, they are only borrowed by
+    // each execution so the same struct can be reused
+    let owned = InsertNightmareDomainParams {
+        arr: vec![json.clone(); 64],
+        json: json.clone(),
+        nb: 7,
+        txt: "Hello world".repeat(64),
+        composite: None,
+    };
+    let mut stmt = insert_nightmare_domain();
+    for _ in 0..3 {
+        assert_eq!(1, stmt.params(client, &owned).unwrap());
+    }
+    let rows = select_nightmare_domain().bind(client).all().unwrap();
+    assert_eq!(rows.len(), 4);
+    assert!(rows[1..]
+        .iter()
+        .all(|row| row.txt == owned.txt && row.arr == owned.arr));
 }
 
 // Test hard cases
@@ -569,5 +956,1211 @@ pub fn test_keyword_escaping(client: &mut Client) {
         r#enum: SyntaxEnum::r#box,
     };
     tricky_sql10().params(client, &params).unwrap();
-    r#typeof().bind(client).all().unwrap();
+    let rows = r#typeof().bind(client).all().unwrap();
+    // Serialized rows keep the original column names
+    let row = serde_json::to_value(&rows[0]).unwrap();
+    assert!(row.get("trick:y").is_some());
+    assert!(row.get("async").is_some());
+}
+
+// Test int-coded enums
+pub fn test_int_enum(client: &mut Client) {
+    assert_eq!(Ok(BookStatus::Archived), BookStatus::try_from(-1));
+    assert_eq!(Err(42), BookStatus::try_from(42));
+    assert_eq!(2, i16::from(BookStatus::Published));
+    assert_eq!(Ok(Importance::Urgent), Importance::try_from(100000));
+    assert_eq!(100000, i32::from(Importance::Urgent));
+    insert_draft()
+        .bind(
+            client,
+            &"Dune",
+            &BookStatus::Published,
+            &Some(BookStatus::Draft),
+            &Importance::Urgent,
+        )
+        .unwrap();
+    insert_draft()
+        .bind(
+            client,
+            &"Dune Messiah",
+            &BookStatus::Archived,
+            &None,
+            &Importance::Low,
+        )
+        .unwrap();
+    assert_eq!(
+        select_draft().bind(client).all().unwrap(),
+        vec![
+            SelectDraft {
+                title: "Dune".into(),
+                status: BookStatus::Published,
+                previous_status: Some(BookStatus::Draft),
+                priority: Importance::Urgent,
+            },
+            SelectDraft {
+                title: "Dune Messiah".into(),
+                status: BookStatus::Archived,
+                previous_status: None,
+                priority: Importance::Low,
+            }
+        ]
+    );
+}
+
+// Test foreign keys metadata
+pub fn test_foreign_keys() {
+    assert_eq!(
+        BOOK_AUTHOR_AUTHOR_ID_FKEY,
+        ForeignKey {
+            schema: "public",
+            name: "book_author_author_id_fkey",
+            table: "book_author",
+            columns: &["author_id"],
+            foreign_schema: "public",
+            foreign_table: "author",
+            foreign_columns: &["id"],
+        }
+    );
+    assert_eq!(
+        BOOK_AUTHOR_ISBN_EDITION_FKEY,
+        ForeignKey {
+            schema: "public",
+            name: "book_author_isbn_edition_fkey",
+            table: "book_author",
+            columns: &["isbn", "edition"],
+            foreign_schema: "public",
+            foreign_table: "edition",
+            foreign_columns: &["isbn", "number"],
+        }
+    );
+}
+
+// Test range types
+pub fn test_range(client: &mut Client) {
+    let at = |hour: u8| OffsetDateTime::UNIX_EPOCH.replace_hour(hour).unwrap();
+    insert_booking()
+        .bind(client, &"garden", &(at(8)..at(10)).into())
+        .unwrap();
+    insert_booking()
+        .bind(
+            client,
+            &"kitchen",
+            &(Bound::Excluded(at(12)), Bound::Unbounded).into(),
+        )
+        .unwrap();
+    assert_eq!(
+        select_overlapping_bookings()
+            .bind(client, &(at(9)..=at(11)).into())
+            .all()
+            .unwrap(),
+        vec![SelectOverlappingBookings {
+            room: "garden".into(),
+            during: Range::Nonempty(Bound::Included(at(8)), Bound::Excluded(at(10))),
+        }]
+    );
+    assert_eq!(
+        select_overlapping_bookings()
+            .bind(client, &Range::Empty)
+            .all()
+            .unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        select_ranges().bind(client).one().unwrap(),
+        SelectRanges {
+            int4: (1..10).into(),
+            int8: Range::Nonempty(Bound::Unbounded, Bound::Excluded(11)),
+            date: Range::Empty,
+        }
+    );
+    // Multiranges are decoded as a list of ranges
+    assert_eq!(
+        select_multiranges().bind(client).one().unwrap(),
+        SelectMultiranges {
+            int4: vec![(1..5).into(), (10..20).into()],
+            tstz: vec![],
+        }
+    );
+    // Overlapping ranges are merged by postgres
+    assert_eq!(
+        echo_multirange()
+            .bind(client, &Multirange(vec![(1..5).into(), (3..8).into()]))
+            .one()
+            .unwrap(),
+        vec![(1..8).into()]
+    );
+}
+
+pub fn test_hstore(client: &mut Client) {
+    let opts = HashMap::from([
+        ("theme".to_string(), Some("dark".to_string())),
+        ("font".to_string(), None),
+    ]);
+    insert_setting().bind(client, &"editor", &opts).unwrap();
+    // Borrowed entries are decoded lazily
+    assert_eq!(
+        select_settings()
+            .bind(client)
+            .map(|it| it.opts.filter(|(_, v)| v.is_some()).count())
+            .one()
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        select_settings().bind(client).one().unwrap(),
+        SelectSettings {
+            name: "editor".into(),
+            opts,
+        }
+    );
+}
+
+// Borrowed rows serialize like their owned counterpart
+pub fn test_serialize_borrowed(client: &mut Client) {
+    let owned = serde_json::to_value(select_settings().bind(client).one().unwrap()).unwrap();
+    let borrowed = select_settings()
+        .bind(client)
+        .map(|it| serde_json::to_value(it).unwrap())
+        .one()
+        .unwrap();
+    assert_eq!(borrowed, owned);
+    let owned = serde_json::to_value(select_multiranges().bind(client).one().unwrap()).unwrap();
+    let borrowed = select_multiranges()
+        .bind(client)
+        .map(|it| serde_json::to_value(it).unwrap())
+        .one()
+        .unwrap();
+    assert_eq!(borrowed, owned);
+    let owned = select_clone_enum_array().bind(client).one().unwrap();
+    let borrowed = select_clone_enum_array()
+        .bind(client)
+        .map(|it| serde_json::to_string(&it).unwrap())
+        .one()
+        .unwrap();
+    assert_eq!(borrowed, serde_json::to_string(&owned).unwrap());
+}
+
+pub fn test_prelude_collision(client: &mut Client) {
+    insert_attempt()
+        .bind(
+            client,
+            &OutcomeBorrowed {
+                result: PgResult::Failure,
+                message: "timeout",
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        select_attempts().bind(client).one().unwrap(),
+        Outcome {
+            result: PgResult::Failure,
+            message: "timeout".into(),
+        }
+    );
+}
+
+pub fn test_jsonb(client: &mut Client) {
+    let data = serde_json::json!({
+        "title": "Dune",
+        "tags": ["sf"],
+        "meta": { "author": "Frank Herbert" },
+    });
+    insert_document().bind(client, &data).unwrap();
+    insert_document()
+        .bind(client, &serde_json::json!({ "title": "Untagged" }))
+        .unwrap();
+    // `@>` infers its parameter as `jsonb`
+    assert_eq!(
+        select_documents_containing()
+            .bind(client, &serde_json::json!({ "tags": ["sf"] }))
+            .all()
+            .unwrap(),
+        vec![data]
+    );
+    // `->>` and `#>>` infer `text`, `->` infers `jsonb`
+    assert_eq!(
+        select_document_fields()
+            .bind(client, &"tags")
+            .one()
+            .unwrap(),
+        SelectDocumentFields {
+            title: "Dune".into(),
+            tags: serde_json::json!(["sf"]),
+            author: Some("Frank Herbert".into()),
+        }
+    );
+    // `jsonpath` values are exchanged in their text form, normalized by postgres
+    insert_document_path()
+        .bind(client, &"author", &JsonPath::from("$.meta.author"))
+        .unwrap();
+    let stored = select_document_paths().bind(client).one().unwrap();
+    assert_eq!(stored.path.as_str(), r#"$."meta"."author""#);
+    assert_eq!(
+        query_documents().bind(client, &stored.path).all().unwrap(),
+        vec![serde_json::json!("Frank Herbert")]
+    );
+}
+
+// Columns of a set operation are nullable if any branch returns null
+pub fn test_union(client: &mut Client) {
+    let mut transaction = client.transaction().unwrap();
+    insert_union_book()
+        .bind(&mut transaction, &"Union A", &Some("Ann"))
+        .unwrap();
+    insert_union_book()
+        .bind(&mut transaction, &"Union B", &None::<&str>)
+        .unwrap();
+    assert_eq!(
+        union_books().bind(&mut transaction).all().unwrap(),
+        [
+            UnionBook {
+                name: "Union A".into(),
+                author: Some("Ann".into()),
+            },
+            UnionBook {
+                name: "Union B".into(),
+                author: None,
+            },
+        ]
+    );
+    transaction.rollback().unwrap();
+}
+
+// Columns sharing a grouped prefix are nested in a struct
+pub fn test_group(client: &mut Client) {
+    let mut transaction = client.transaction().unwrap();
+    transaction
+        .batch_execute(
+            "DELETE FROM named; DELETE FROM Book;
+            INSERT INTO Book (name, author) VALUES ('Grouped', 'Group author');",
+        )
+        .unwrap();
+    insert_group_named()
+        .bind(&mut transaction, &"Group author", &Some(2.5), &true)
+        .unwrap();
+    let id = named_flags().bind(&mut transaction).one().unwrap().id;
+    assert_eq!(
+        named_books().bind(&mut transaction).all().unwrap(),
+        [NamedBook {
+            named: NamedBookNamed {
+                id,
+                name: "Group author".into(),
+                price: Some(2.5),
+            },
+            show: true,
+            book: NamedBookBook {
+                name: "Grouped".into(),
+                author: Some("Group author".into()),
+            },
+        }]
+    );
+    // Borrowed rows nest borrowed groups
+    assert_eq!(
+        named_books()
+            .bind(&mut transaction)
+            .map(|it| it.book.name.len())
+            .one()
+            .unwrap(),
+        "Grouped".len()
+    );
+    assert_eq!(
+        named_flags().bind(&mut transaction).one().unwrap(),
+        NamedFlags {
+            id,
+            flag: NamedFlagsFlag {
+                show: true,
+                price: Some(2.5),
+            },
+        }
+    );
+    transaction.rollback().unwrap();
+}
+
+pub fn test_count(client: &mut Client) {
+    let expected = client.query("SELECT id FROM author", &[]).unwrap().len() as i64;
+    // `COUNT` never returns NULL, so no `Option` is needed
+    let count: i64 = count_authors().bind(client).one().unwrap();
+    assert_eq!(count, expected);
+}
+
+pub fn test_numeric_aggregate(client: &mut Client) {
+    fn is_copy<T: Copy>(_: T) {}
+    let mut transaction = client.transaction().unwrap();
+    transaction.execute("DELETE FROM named", &[]).unwrap();
+    transaction
+        .execute(
+            "INSERT INTO named (name, price, show) VALUES ('a', 1.25, true), ('b', 2.5, true)",
+            &[],
+        )
+        .unwrap();
+    let stats = named_price_stats().bind(&mut transaction).one().unwrap();
+    is_copy(stats);
+    assert_eq!(
+        stats,
+        NamedPriceStats {
+            total: Some(Decimal::new(375, 2)),
+            count: 2
+        }
+    );
+    transaction.execute("DELETE FROM named", &[]).unwrap();
+    // `SUM` of no rows is NULL
+    let stats = named_price_stats().bind(&mut transaction).one().unwrap();
+    assert_eq!(stats.total, None);
+    transaction.rollback().unwrap();
+}
+
+pub fn test_query_errors(client: &mut Client) {
+    use crate::cornucopia_errors::queries::errors::sync::{
+        author_by_id, author_ids, insert_author,
+    };
+    use std::error::Error as _;
+
+    assert_eq!(insert_author().bind(client, &42).unwrap(), 1);
+    assert_eq!(author_by_id().bind(client, &42).one_scalar().unwrap(), 42);
+    assert!(author_ids().bind(client).all().unwrap().contains(&42));
+    // Failures carry the name of the query alongside the driver error
+    let err = insert_author().bind(client, &42).unwrap_err();
+    assert_eq!(err.to_string(), "query `insert_author` failed");
+    assert!(err.source().is_some());
+    let err = author_by_id().bind(client, &-1).one().unwrap_err();
+    assert_eq!(err.to_string(), "query `author_by_id` failed");
+}
+
+pub fn test_numeric_as_f64(client: &mut Client) {
+    use crate::cornucopia_numeric::queries::numeric::{
+        float_echo, numeric_doubled, numeric_echo, numeric_values,
+    };
+    use cornucopia_sync::{NumericF64, TextParam};
+
+    for value in [0., 1., -1.5, 0.1, 1e-12, 123456789.98765, 1e20] {
+        assert_eq!(
+            numeric_echo()
+                .bind(client, &NumericF64(value))
+                .one()
+                .unwrap(),
+            value
+        );
+    }
+    // Exact values can be bound from their text representation
+    assert_eq!(
+        numeric_echo()
+            .bind(client, &TextParam("0.1"))
+            .one()
+            .unwrap(),
+        0.1
+    );
+    assert_eq!(
+        numeric_doubled()
+            .bind(client, &TextParam("12345678901234567890.12"))
+            .one()
+            .unwrap(),
+        "24691357802469135780.24"
+    );
+    assert!(numeric_doubled()
+        .bind(client, &TextParam("1,25"))
+        .one()
+        .is_err());
+    // `float8` columns keep their own query struct
+    assert_eq!(float_echo().bind(client, &0.5).one().unwrap(), 0.5);
+    let values = numeric_values().bind(client).one().unwrap();
+    assert_eq!(values.big, 123456789.000123);
+    assert_eq!(values.small, -0.00042);
+    assert!(values.nan.is_nan());
+    assert_eq!(values.list, vec![1.5, 0., -20000.]);
+}
+
+// Test floats mapped to totally ordered wrappers
+pub fn test_ordered_floats(client: &mut Client) {
+    use crate::cornucopia_float::queries::float::{float_echo, float_values};
+    use cornucopia_sync::{OrderedF32, OrderedF64};
+
+    let nan = OrderedF64(f64::NAN);
+    assert_eq!(float_echo().bind(client, &nan).one().unwrap(), nan);
+    let values = float_values().bind(client).one().unwrap();
+    assert_eq!(values.nan, OrderedF32(f32::NAN));
+    assert!(values.zero < OrderedF64(0.));
+    assert_eq!(values.list, [OrderedF64(1.5), nan]);
+    let set: std::collections::HashSet<_> = [nan, nan, OrderedF64(0.)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+// Generated async futures and streams can be moved to another task
+pub async fn check_async_send<C: cornucopia_async::GenericClient>(client: &C) {
+    use crate::cornucopia::queries::params::{async_::*, InsertBookParams};
+    use cornucopia_async::Params;
+
+    let mut stmt = insert_book();
+    assert_send(&stmt.bind(client, &None::<&str>, &"Necronomicon"));
+    let mut stmt = insert_book();
+    let params = InsertBookParams {
+        author: None::<&str>,
+        name: "Necronomicon",
+    };
+    assert_send(&stmt.params(client, &params));
+    let mut stmt = select_book();
+    assert_send(&stmt.bind(client).all());
+    let mut stmt = select_book();
+    assert_send(&stmt.bind(client).one());
+    let mut stmt = select_book();
+    assert_send(&stmt.bind(client).opt());
+    let mut stmt = select_book();
+    assert_send(&stmt.bind(client).iter().await.unwrap());
+    assert_send(&cornucopia_async::ping(client));
+}
+
+// Named streams can be returned without boxing them
+pub async fn check_named_stream<C: cornucopia_async::GenericClient>(
+    client: &C,
+) -> crate::cornucopia_errors::queries::errors::async_::I32Stream<i32> {
+    use crate::cornucopia_errors::queries::errors::async_::author_ids;
+
+    let stream = author_ids().bind(client).iter().await.unwrap();
+    assert_send(&stream);
+    stream
+}
+
+// Test that the async backend generated alongside the sync one is usable
+pub async fn check_backend_async<C: cornucopia_async::GenericClient>(client: &C) {
+    use crate::cornucopia_backend_async::queries::sqlx::posts;
+
+    assert_send(&posts().bind(client).all().await);
+}
+
+// Test that enums are ordered as declared in the database
+pub fn test_enum_order(client: &mut Client) {
+    for (name, priority) in [
+        ("laundry", Priority::medium),
+        ("taxes", Priority::high),
+        ("nap", Priority::low),
+        ("fire", Priority::urgent),
+        ("dust", Priority::trivial),
+    ] {
+        insert_task().bind(client, &name, &priority).unwrap();
+    }
+    let db_sorted = tasks_by_priority().bind(client).all().unwrap();
+    let mut rust_sorted = db_sorted.clone();
+    rust_sorted.sort();
+    assert_eq!(db_sorted, rust_sorted);
+    assert_eq!(
+        db_sorted,
+        [
+            Priority::trivial,
+            Priority::low,
+            Priority::medium,
+            Priority::high,
+            Priority::urgent
+        ]
+    );
+    assert!(Priority::low < Priority::high);
+}
+
+// Test row column identifier enums
+pub fn test_column_enums(client: &mut Client) {
+    assert_eq!(
+        TypeofColumn::ALL,
+        [
+            TypeofColumn::TrickY,
+            TypeofColumn::Async,
+            TypeofColumn::Enum
+        ]
+    );
+    assert_eq!(TypeofColumn::TrickY.name(), "trick:y");
+    assert_eq!(TypeofColumn::Async.name(), "async");
+    // Column names can be used to build queries dynamically
+    let columns = TypeofColumn::ALL
+        .iter()
+        .map(|c| format!("\"{}\"", c.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let row = client
+        .query_one(&format!("SELECT {columns} FROM syntax LIMIT 1"), &[])
+        .unwrap();
+    assert_eq!(row.columns().len(), TypeofColumn::ALL.len());
+    assert_eq!(row.columns()[0].name(), TypeofColumn::TrickY.name());
+}
+
+// Test `SELECT DISTINCT ON` queries
+pub fn test_distinct_on(client: &mut Client) {
+    let now = PrimitiveDateTime::new(OffsetDateTime::now_utc().date(), time::Time::MIDNIGHT);
+    let hours = |n| now + time::Duration::hours(n);
+    for (author_id, title, subtitle, created_at) in [
+        (1, "old", None, hours(-2)),
+        (1, "new", Some("sub"), hours(-1)),
+        (1, "future", None, hours(1)),
+        (2, "only", None, hours(-3)),
+    ] {
+        insert_post()
+            .bind(client, &author_id, &title, &subtitle, &created_at)
+            .unwrap();
+    }
+    assert_eq!(
+        latest_posts().bind(client, &now).all().unwrap(),
+        [
+            LatestPosts {
+                author_id: 1,
+                title: "new".into(),
+                subtitle: Some("sub".into()),
+                created_at: hours(-1),
+            },
+            LatestPosts {
+                author_id: 2,
+                title: "only".into(),
+                subtitle: None,
+                created_at: hours(-3),
+            },
+        ]
+    );
+}
+
+// Test the prelude re-exporting every module
+pub fn test_prelude(client: &mut Client) {
+    use crate::cornucopia::prelude::{sync::tasks_by_priority, TypeofColumn};
+    assert!(!tasks_by_priority().bind(client).all().unwrap().is_empty());
+    assert_eq!(TypeofColumn::Async.name(), "async");
+    // Names defined by several modules are only reachable through their module
+    use crate::cornucopia::queries::collision::sync::select_book;
+    assert!(!select_book().bind(client).all().unwrap().is_empty());
+}
+
+// Test rows collected into pre-allocated vectors
+pub fn test_limit_capacity(client: &mut Client) {
+    let names = book_names_limited().bind(client).all().unwrap();
+    assert!(names.len() < 10 && names.capacity() >= 10);
+    // Nested query limits are not an upper bound, the capacity can be given explicitly
+    let names = book_names_nested_limit()
+        .bind(client)
+        .vec_with_capacity(64)
+        .unwrap();
+    assert!(!names.is_empty() && names.capacity() >= 64);
+}
+
+// Test queries annotated the yesql way
+pub fn test_yesql(client: &mut Client) {
+    use crate::cornucopia_yesql::queries::yesql::{
+        author_exists, author_ids_above, count_authors_above, insert_author,
+    };
+    for id in [1001, 1002] {
+        insert_author().bind(client, &id).unwrap();
+    }
+    assert_eq!(
+        author_ids_above().bind(client, &1000).all().unwrap(),
+        [1001, 1002]
+    );
+    assert!(author_exists().bind(client, &1001).one().unwrap());
+    assert!(!author_exists().bind(client, &1003).one().unwrap());
+    assert_eq!(count_authors_above().bind(client, &1000).one().unwrap(), 2);
+}
+
+// Test in-memory caches of lookup tables
+pub fn test_cache(client: &mut Client) {
+    use crate::cornucopia::queries::cache::sync::{insert_country, CountriesCache};
+    for (code, name) in [("FR", "France"), ("JP", "Japan")] {
+        insert_country().bind(client, &code, &name).unwrap();
+    }
+    let cache = CountriesCache::load_all(client).unwrap();
+    assert_eq!(cache.rows().len(), 2);
+    assert_eq!(cache.get("JP").unwrap().name, "Japan");
+    assert!(cache.get("US").is_none());
+    // Rows are shared, not cloned
+    let rows = cache.clone().rows().clone();
+    assert!(std::sync::Arc::ptr_eq(&rows, cache.rows()));
+}
+
+// Test binding parameters as a slice of trait objects
+pub fn test_dyn_params(client: &mut Client) {
+    use crate::cornucopia::queries::params::sync::{insert_book, select_book_by_name};
+    let name = "Dyn Book";
+    insert_book()
+        .bind_dyn(client, &[&Some("Dyn Author"), &name])
+        .unwrap();
+    let book = select_book_by_name()
+        .bind_dyn(client, &[&name])
+        .unwrap()
+        .one()
+        .unwrap();
+    assert_eq!(book.name, name);
+    // Arity is checked at runtime
+    assert_eq!(
+        select_book_by_name().bind_dyn(client, &[]).err(),
+        Some(cornucopia_sync::ArityError {
+            expected: 1,
+            got: 0
+        })
+    );
+    assert!(insert_book().bind_dyn(client, &[&name]).is_err());
+}
+
+// Test the connection health check
+pub fn test_ping(client: &mut Client) {
+    cornucopia_sync::ping(client).unwrap();
+    let mut transaction = client.transaction().unwrap();
+    cornucopia_sync::ping(&mut transaction).unwrap();
+}
+
+// Test queries prepared with a custom search_path
+pub fn test_search_path(client: &mut Client) {
+    use crate::cornucopia_search_path::{
+        queries::ledger::{entries, insert_entry},
+        types::tenant::EntryKind,
+    };
+    client.batch_execute("SET search_path TO tenant").unwrap();
+    insert_entry()
+        .bind(client, &EntryKind::credit, &42)
+        .unwrap();
+    let entries = entries().bind(client).all().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].kind, EntryKind::credit);
+    client.batch_execute("RESET search_path").unwrap();
+}
+
+// Test OID alias types like `regclass`
+pub fn test_reg_types(client: &mut Client) {
+    use crate::cornucopia::queries::catalog::sync::{author_table, table_name};
+    use cornucopia_sync::RegOid;
+
+    let author = author_table().bind(client).one().unwrap();
+    assert_eq!(author.table_name, "author");
+    assert_eq!(author.type_oid, RegOid(postgres::types::Type::INT4.oid()));
+    let name = table_name().bind(client, &author.table_oid).one().unwrap();
+    assert_eq!(name, "author");
+}
+
+// Test `INSERT ... SELECT` and `SELECT ... INTO` classification
+pub fn test_insert_select(client: &mut Client) {
+    use crate::cornucopia::queries::insert_select::sync::{
+        copy_books_of, copy_books_of_returning, snapshot_books,
+    };
+    let mut transaction = client.transaction().unwrap();
+    transaction
+        .batch_execute("INSERT INTO book (name, author) VALUES ('Dune', 'Herbert')")
+        .unwrap();
+    assert_eq!(
+        copy_books_of().bind(&mut transaction, &"Herbert").unwrap(),
+        1
+    );
+    let names = copy_books_of_returning()
+        .bind(&mut transaction, &"Herbert")
+        .all()
+        .unwrap();
+    assert_eq!(names, vec!["Dune".to_string(), "Dune".to_string()]);
+    snapshot_books().bind(&mut transaction).unwrap();
+    let count: i64 = transaction
+        .query_one("SELECT count(*) FROM book_snapshot", &[])
+        .unwrap()
+        .get(0);
+    assert_eq!(
+        count,
+        transaction
+            .query_one("SELECT count(*) FROM book", &[])
+            .unwrap()
+            .get::<_, i64>(0)
+    );
+    transaction.rollback().unwrap();
+}
+
+// Test generating only the queries matching a filter
+pub fn test_filter(client: &mut Client) {
+    use crate::cornucopia_filter::queries::{collision, params};
+    assert_eq!(
+        collision::select_book().bind(client).all().unwrap(),
+        crate::cornucopia::queries::collision::sync::select_book()
+            .bind(client)
+            .all()
+            .unwrap()
+    );
+    assert_eq!(
+        params::select_book()
+            .bind(client)
+            .map(|book| book.name.to_string())
+            .all()
+            .unwrap(),
+        crate::cornucopia::queries::params::sync::select_book()
+            .bind(client)
+            .map(|book| book.name.to_string())
+            .all()
+            .unwrap()
+    );
+}
+
+// Test composites altered after generation, whose fields are matched by name
+pub fn test_altered_composite(client: &mut Client) {
+    use crate::cornucopia::{
+        queries::altered::sync::echo_altered,
+        types::public::{AlteredComposite, AlteredCompositeParams},
+    };
+    let params = AlteredCompositeParams {
+        id: 42,
+        name: "forty-two",
+        tags: &["answer"],
+    };
+    let expected = AlteredComposite {
+        id: 42,
+        name: "forty-two".to_string(),
+        tags: vec!["answer".to_string()],
+    };
+    assert_eq!(
+        echo_altered().bind(client, &params).one().unwrap(),
+        expected
+    );
+    // The server now sends `id` last, while the generated struct still declares it first
+    client
+        .batch_execute(
+            "ALTER TYPE altered_composite DROP ATTRIBUTE id;
+            ALTER TYPE altered_composite ADD ATTRIBUTE id INT;",
+        )
+        .unwrap();
+    client.clear_type_cache();
+    assert_eq!(
+        echo_altered().bind(client, &params).one().unwrap(),
+        expected
+    );
+}
+
+// Test module-wide nullable columns, overridden per column
+pub fn test_nullable_default(client: &mut Client) {
+    use crate::cornucopia::queries::nullable_default::sync::{
+        nullable_books, nullable_books_override,
+    };
+    let mut transaction = client.transaction().unwrap();
+    transaction
+        .batch_execute("DELETE FROM book; INSERT INTO book (name) VALUES ('Anonymous')")
+        .unwrap();
+    let book = nullable_books().bind(&mut transaction).one().unwrap();
+    assert_eq!(book.name, Some("Anonymous".to_string()));
+    assert_eq!(book.author, None);
+    let book = nullable_books_override()
+        .bind(&mut transaction)
+        .one()
+        .unwrap();
+    assert_eq!(book.name, "Anonymous");
+    assert_eq!(book.author, None);
+    transaction.rollback().unwrap();
+}
+
+// Test preparing the statements of a whole module at once
+pub fn test_statements(client: &mut Client) {
+    use crate::cornucopia::queries::params::sync::Statements;
+    let mut statements = Statements::new();
+    statements.prepare_all(client).unwrap();
+    assert_eq!(
+        statements.select_book.bind(client).all().unwrap(),
+        select_book().bind(client).all().unwrap()
+    );
+}
+
+// Test the companion queries returning the plan of a query
+pub fn test_explain(client: &mut Client) {
+    use crate::cornucopia::queries::explain::sync::{
+        author_by_id_explain, insert_author_id_explain,
+    };
+    let plan = author_by_id_explain().bind(client, &1).one().unwrap();
+    assert!(plan[0]["Plan"]["Node Type"].is_string());
+    let plan = insert_author_id_explain().bind(client, &-1).one().unwrap();
+    assert_eq!(plan[0]["Plan"]["Node Type"], "ModifyTable");
+    // Explaining an insert does not run it
+    let count: i64 = client
+        .query_one("SELECT count(*) FROM author WHERE id = -1", &[])
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 0);
+}
+
+// Params cast from text are bound from strings and validated by the database
+pub fn test_text_params(client: &mut Client) {
+    use crate::cornucopia::queries::text_params::sync::{
+        interval_from_text, nullable_interval_from_text,
+    };
+    use cornucopia_sync::TextParam;
+    assert_eq!(
+        interval_from_text()
+            .bind(client, &TextParam("3 days"))
+            .one()
+            .unwrap(),
+        "3 days"
+    );
+    assert!(interval_from_text()
+        .bind(client, &TextParam("three days"))
+        .one()
+        .is_err());
+    assert!(nullable_interval_from_text()
+        .bind(client, &None::<TextParam<&str>>)
+        .one()
+        .unwrap());
+    assert!(!nullable_interval_from_text()
+        .bind(client, &Some(TextParam(String::from("1 hour"))))
+        .one()
+        .unwrap());
+}
+
+// Full-text ranks are `real`, mapped to a non-null `f32`
+pub fn test_full_text(client: &mut Client) {
+    use crate::cornucopia::queries::full_text::{
+        sync::{insert_article, search_articles, search_articles_cd},
+        SearchArticles, SearchArticlesCd,
+    };
+    for (title, body) in [
+        ("cats", "The cat sat on the mat with another cat"),
+        ("dogs", "A dog barked at the cat"),
+        ("birds", "Birds sing in the morning"),
+    ] {
+        insert_article().bind(client, &title, &body).unwrap();
+    }
+    let results: Vec<SearchArticles> = search_articles().bind(client, &"cat").all().unwrap();
+    assert_eq!(
+        results
+            .iter()
+            .map(|it| it.title.as_str())
+            .collect::<Vec<_>>(),
+        ["cats", "dogs"]
+    );
+    assert!(results[0].rank > results[1].rank);
+    // Articles without a match are still ranked, at zero
+    let results: Vec<SearchArticlesCd> = search_articles_cd().bind(client, &"cat").all().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[2].title, "birds");
+    assert_eq!(results[2].rank, 0.);
+}
+
+// Annotated param types override the ones Postgres would infer
+pub fn test_param_types(client: &mut Client) {
+    use crate::cornucopia::queries::param_types::sync::{case_value, echo_ids};
+    assert_eq!(case_value().bind(client, &42).one().unwrap(), Some(42));
+    assert_eq!(
+        echo_ids()
+            .bind(client, &[1i64, 2].as_slice())
+            .one()
+            .unwrap(),
+        vec![1i64, 2]
+    );
+}
+
+// Columns read by name are extracted even when the database orders them differently
+pub fn test_columns_by_name(client: &mut Client) {
+    use crate::cornucopia_by_name::queries::by_name::{
+        reordered_names, select_reordered, SelectReordered,
+    };
+    // Recreate the table with its columns swapped, as a diverging migration would
+    client
+        .batch_execute(
+            "DROP TABLE reordered;
+            CREATE TABLE reordered (name TEXT NOT NULL, id INT NOT NULL);
+            INSERT INTO reordered (name, id) VALUES ('Necronomicon', 1);",
+        )
+        .unwrap();
+    assert_eq!(
+        select_reordered().bind(client).all().unwrap(),
+        [SelectReordered {
+            id: 1,
+            name: "Necronomicon".into(),
+        }]
+    );
+    assert_eq!(
+        reordered_names().bind(client).all().unwrap(),
+        ["Necronomicon"]
+    );
+}
+
+// Modules generated in distinct files
+pub fn test_split_modules(client: &mut Client) {
+    use crate::cornucopia_split::{
+        queries::{
+            books::{insert_split_book, split_books, SplitBook},
+            clones::split_clones,
+        },
+        types::public::CloneComposite,
+    };
+    let mut transaction = client.transaction().unwrap();
+    insert_split_book()
+        .bind(&mut transaction, &"Split A", &Some("Ann"))
+        .unwrap();
+    insert_split_book()
+        .bind(&mut transaction, &"Split B", &None::<&str>)
+        .unwrap();
+    assert_eq!(
+        split_books().bind(&mut transaction).all().unwrap(),
+        [
+            SplitBook {
+                name: "Split A".into(),
+                author: Some("Ann".into()),
+            },
+            SplitBook {
+                name: "Split B".into(),
+                author: None,
+            },
+        ]
+    );
+    transaction.execute("DELETE FROM clone", &[]).unwrap();
+    transaction
+        .execute("INSERT INTO clone VALUES (ROW(1, 'split'))", &[])
+        .unwrap();
+    assert_eq!(
+        split_clones().bind(&mut transaction).all().unwrap(),
+        [CloneComposite {
+            first: 1,
+            second: "split".into(),
+        }]
+    );
+    transaction.rollback().unwrap();
+}
+
+// Text columns read as bytes from a database storing values that are not valid UTF-8
+pub fn test_text_as_bytes(client: &mut Client) {
+    use crate::cornucopia_text_bytes::queries::legacy::{
+        insert_legacy_text, legacy_names, legacy_texts, LegacyText,
+    };
+    client
+        .batch_execute("DROP DATABASE IF EXISTS legacy_ascii")
+        .unwrap();
+    client
+        .batch_execute(
+            "CREATE DATABASE legacy_ascii ENCODING 'SQL_ASCII' LC_COLLATE 'C' LC_CTYPE 'C' TEMPLATE template0",
+        )
+        .unwrap();
+    let legacy = &mut Config::new()
+        .user("postgres")
+        .password("postgres")
+        .host("127.0.0.1")
+        .port(5435)
+        .dbname("legacy_ascii")
+        .connect(NoTls)
+        .unwrap();
+    legacy
+        .batch_execute(
+            "SET client_encoding = 'SQL_ASCII';
+            CREATE TABLE legacy_text (id INT NOT NULL, name TEXT NOT NULL, note VARCHAR, tags TEXT[] NOT NULL);
+            INSERT INTO legacy_text (id, name, note, tags) VALUES (2, E'caf\\xe9', E'\\xff', ARRAY[E'na\\xefve']);",
+        )
+        .unwrap();
+    insert_legacy_text()
+        .bind(legacy, &1, &"plain", &None::<&str>, &["ascii"].as_slice())
+        .unwrap();
+    assert_eq!(
+        legacy_texts().bind(legacy).all().unwrap(),
+        [
+            LegacyText {
+                id: 1,
+                name: b"plain".to_vec(),
+                note: None,
+                tags: vec![b"ascii".to_vec()],
+            },
+            LegacyText {
+                id: 2,
+                name: b"caf\xe9".to_vec(),
+                note: Some(b"\xff".to_vec()),
+                tags: vec![b"na\xefve".to_vec()],
+            },
+        ]
+    );
+    assert_eq!(
+        legacy_names().bind(legacy).all().unwrap(),
+        [b"plain".to_vec(), b"caf\xe9".to_vec()]
+    );
+}
+
+// Side-effecting selects can be run discarding their rows
+pub fn test_execute_select(client: &mut Client) {
+    use crate::cornucopia::queries::execute::sync::{
+        lock_advisory, marker, set_marker, unlock_advisory,
+    };
+    assert_eq!(set_marker().bind(client, &"executed").execute().unwrap(), 1);
+    assert_eq!(marker().bind(client).one().unwrap(), "executed");
+    // Queries annotated with `--# execute` return their row count directly
+    assert_eq!(lock_advisory().bind(client, &42).unwrap(), 1);
+    assert_eq!(unlock_advisory().bind(client, &42).unwrap(), 1);
+}
+
+// The prepared statement of a query is accessible once it was used
+pub fn test_prepared_statement(client: &mut Client) {
+    use crate::cornucopia::queries::execute::sync::set_marker;
+    let mut stmt = set_marker();
+    assert!(stmt.statement().is_none());
+    stmt.bind(client, &"prepared").execute().unwrap();
+    let prepared = stmt.statement().unwrap();
+    assert_eq!(prepared.params(), [postgres::types::Type::TEXT]);
+    assert_eq!(prepared.columns()[0].name(), "set_config");
+}
+
+// Queries reading a temporary table are prepared after their setup
+pub fn test_temp_table(client: &mut Client) {
+    use crate::cornucopia::queries::temp_table::{sync::book_counts, BookCounts};
+    let mut transaction = client.transaction().unwrap();
+    transaction
+        .batch_execute(
+            "DELETE FROM book;
+            INSERT INTO book (name, author) VALUES ('Necronomicon', 'Alhazred'), ('Al Azif', 'Alhazred');
+            CREATE TEMP TABLE book_count AS SELECT author, count(*) AS books FROM book GROUP BY author;",
+        )
+        .unwrap();
+    assert_eq!(
+        book_counts().bind(&mut transaction).all().unwrap(),
+        [BookCounts {
+            author: "Alhazred".into(),
+            books: 2,
+        }]
+    );
+    transaction.rollback().unwrap();
+}
+
+// Big numbers are serialized as strings to keep their precision in JavaScript
+pub fn test_bigint_as_string(client: &mut Client) {
+    use crate::cornucopia_bigint::queries::big_numbers::big_numbers;
+    let row = big_numbers().bind(client).one().unwrap();
+    assert_eq!(
+        serde_json::to_value(row).unwrap(),
+        serde_json::json!({ "id": "9007199254740993", "amount": "1.50", "maybe": null, "small": 7 })
+    );
+}
+
+// Infinite timestamps are decoded instead of failing
+pub fn test_infinite_timestamps(client: &mut Client) {
+    use crate::cornucopia_infinity::queries::infinity::{
+        insert_subscription, open_ended_ids, subscriptions, Subscriptions,
+    };
+    use cornucopia_sync::Timestamp;
+    let since = OffsetDateTime::from_unix_timestamp(1_000_000_000).unwrap();
+    let until = PrimitiveDateTime::new(since.date(), since.time());
+    let rows = [
+        Subscriptions {
+            id: 1,
+            since: Timestamp::Finite(since),
+            until: Timestamp::Finite(until),
+        },
+        Subscriptions {
+            id: 2,
+            since: Timestamp::NegInfinity,
+            until: Timestamp::PosInfinity,
+        },
+    ];
+    for row in &rows {
+        insert_subscription()
+            .bind(client, &row.id, &row.since, &row.until)
+            .unwrap();
+    }
+    assert_eq!(subscriptions().bind(client).all().unwrap(), rows);
+    assert_eq!(open_ended_ids().bind(client).all().unwrap(), [2]);
+    assert!(Timestamp::NegInfinity < rows[0].until && rows[0].until < Timestamp::PosInfinity);
+}
+
+pub fn test_time_backend(client: &mut Client) {
+    use crate::cornucopia_time::queries::temporal::{
+        insert_temporal, temporal_values, TemporalValues,
+    };
+    let timestamptz = OffsetDateTime::from_unix_timestamp(1_000_000_000).unwrap();
+    let row = TemporalValues {
+        timestamp_: PrimitiveDateTime::new(timestamptz.date(), timestamptz.time()),
+        timestamptz_: timestamptz,
+        date_: timestamptz.date(),
+        time_: timestamptz.time(),
+    };
+    let mut transaction = client.transaction().unwrap();
+    transaction.batch_execute("DELETE FROM Everything").unwrap();
+    insert_temporal()
+        .bind(
+            &mut transaction,
+            &row.timestamp_,
+            &row.timestamptz_,
+            &row.date_,
+            &row.time_,
+        )
+        .unwrap();
+    assert_eq!(
+        temporal_values().bind(&mut transaction).all().unwrap(),
+        [row]
+    );
+}
+
+// Rows whose fields are all hashable can be collected into a set
+pub fn test_hash(client: &mut Client) {
+    use crate::cornucopia_hash::{
+        queries::hash::{insert_task, scored_tasks, tasks, Tasks},
+        types::public::Priority,
+    };
+    let mut transaction = client.transaction().unwrap();
+    transaction.batch_execute("DELETE FROM task").unwrap();
+    for (name, priority) in [
+        ("deploy", Priority::high),
+        ("deploy", Priority::high),
+        ("review", Priority::low),
+    ] {
+        insert_task()
+            .bind(&mut transaction, &name, &priority)
+            .unwrap();
+    }
+    let set: HashSet<Tasks> = tasks()
+        .bind(&mut transaction)
+        .all()
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(
+        set,
+        HashSet::from([
+            Tasks {
+                name: "deploy".to_string(),
+                priority: Priority::high,
+                tags: vec!["deploy".to_string()],
+            },
+            Tasks {
+                name: "review".to_string(),
+                priority: Priority::low,
+                tags: vec!["review".to_string()],
+            },
+        ])
+    );
+    // Rows holding a float keep only `PartialEq`
+    assert_eq!(
+        scored_tasks().bind(&mut transaction).all().unwrap().len(),
+        3
+    );
+}
+
+// Backends generated from a single preparation share the same rows
+pub fn test_backends(client: &mut Client) {
+    use crate::cornucopia_backend_sync::queries::sqlx::{posts, Posts};
+    let mut transaction = client.transaction().unwrap();
+    transaction
+        .batch_execute(
+            "DELETE FROM post;
+            INSERT INTO post (author_id, title, created_at) VALUES (1, 'Necronomicon', '2020-01-01 00:00:00');",
+        )
+        .unwrap();
+    assert_eq!(
+        posts().bind(&mut transaction).all().unwrap(),
+        [Posts {
+            author_id: 1,
+            post_title: "Necronomicon".into(),
+            subtitle: None,
+            created_at: PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2020, time::Month::January, 1).unwrap(),
+                time::Time::MIDNIGHT,
+            ),
+        }]
+    );
+    transaction.rollback().unwrap();
+}
+
+// Serde derives gated behind a cargo feature
+#[cfg(feature = "ser")]
+pub fn test_serde_feature(client: &mut Client) {
+    use crate::cornucopia_serde::queries::serde_rows::serde_row;
+    let row = serde_row().bind(client).one().unwrap();
+    assert_eq!(
+        serde_json::to_value(row).unwrap(),
+        serde_json::json!({ "book.name": "Necronomicon", "character": "Bob" })
+    );
+}
+
+// Test module gated behind a cargo feature
+#[cfg(feature = "gated")]
+pub fn test_gated(client: &mut Client) {
+    use crate::cornucopia::queries::gated::sync::gated_author_ids;
+    let expected: Vec<i32> = client
+        .query("SELECT id FROM author ORDER BY id", &[])
+        .unwrap()
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(gated_author_ids().bind(client).all().unwrap(), expected);
 }
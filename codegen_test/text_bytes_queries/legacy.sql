@@ -0,0 +1,8 @@
+--: LegacyText(note?)
+
+--! insert_legacy_text (note?)
+INSERT INTO legacy_text (id, name, note, tags) VALUES (:id, :name, :note, :tags);
+--! legacy_texts: LegacyText
+SELECT * FROM legacy_text ORDER BY id;
+--! legacy_names
+SELECT name FROM legacy_text ORDER BY id;
@@ -0,0 +1,5 @@
+--! insert_temporal
+INSERT INTO Everything (timestamp_, timestamptz_, date_, time_)
+VALUES (:timestamp_, :timestamptz_, :date_, :time_);
+--! temporal_values
+SELECT timestamp_, timestamptz_, date_, time_ FROM Everything;
@@ -0,0 +1,13 @@
+-- name: insert-author!
+-- Adds an author
+INSERT INTO author (id) VALUES (:id);
+
+-- name: author_ids_above
+SELECT id FROM author WHERE id > :id ORDER BY id;
+
+-- name: author-exists$
+SELECT EXISTS (SELECT 1 FROM author WHERE id = :id) AS exists;
+
+-- Native annotations are still recognized
+--! count_authors_above
+SELECT COUNT(*) FROM author WHERE id > :id;
@@ -0,0 +1,526 @@
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use postgres::Client;
+use postgres_types::{Field, Kind, Type};
+use serde::{Deserialize, Serialize};
+
+use crate::schema_catalog::SchemaCatalog;
+
+use self::error::Error;
+
+/// Types of the params and columns of a prepared statement
+pub(crate) struct StatementTypes {
+    pub(crate) params: Vec<Type>,
+    pub(crate) columns: Vec<Column>,
+}
+
+/// A column of a prepared statement, like the driver's but constructible from a cache
+pub(crate) struct Column {
+    name: String,
+    ty: Type,
+}
+
+impl Column {
+    pub(crate) fn new(name: String, ty: Type) -> Self {
+        Self { name, ty }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn type_(&self) -> &Type {
+        &self.ty
+    }
+}
+
+/// A column of a foreign key constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ForeignKeyColumn {
+    pub(crate) schema: String,
+    pub(crate) constraint: String,
+    pub(crate) table: String,
+    pub(crate) column: String,
+    pub(crate) foreign_schema: String,
+    pub(crate) foreign_table: String,
+    pub(crate) foreign_column: String,
+}
+
+/// Source of the database metadata queries are prepared against
+pub(crate) trait Catalog {
+    /// Runs statements altering the session, e.g. `SET statement_timeout = 100`
+    fn batch_execute(&mut self, sql: &str) -> Result<(), CatalogError>;
+    /// Prepares `sql`, returning the types of its params and columns
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError>;
+    /// Columns of the view or materialized view named `name`, empty if there is none
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError>;
+    /// Columns having a single column unique index in one of `tables`
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError>;
+    /// Columns of every foreign key constraint, ordered by schema, table, constraint and position
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError>;
+}
+
+/// Error of a [`Catalog`] lookup
+#[derive(Debug)]
+pub enum CatalogError {
+    Db(postgres::Error),
+    /// The lookup of this item was not recorded in the offline cache
+    Uncached(String),
+    /// The query is not in the offline cache and is too complex to be prepared from the schema
+    Unsupported(String),
+}
+
+impl CatalogError {
+    /// Points to the fix of a lookup missing from the offline cache
+    pub(crate) fn help(&self) -> Option<String> {
+        match self {
+            CatalogError::Db(_) => None,
+            CatalogError::Uncached(_) => Some(
+                "save the offline cache again, e.g. with `cornucopia schema --save-offline`"
+                    .to_string(),
+            ),
+            CatalogError::Unsupported(_) => Some(
+                "only simple queries on tables and views are prepared from the schema files, \
+                save an offline cache with `cornucopia schema --save-offline` for the others"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Db(err) => write!(f, "{err:#}"),
+            CatalogError::Uncached(item) => write!(f, "{item} is not in the offline cache"),
+            CatalogError::Unsupported(reason) => write!(f, "cannot prepare offline: {reason}"),
+        }
+    }
+}
+
+impl Catalog for Client {
+    fn batch_execute(&mut self, sql: &str) -> Result<(), CatalogError> {
+        Client::batch_execute(self, sql).map_err(CatalogError::Db)
+    }
+
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError> {
+        let stmt = Client::prepare(self, sql).map_err(CatalogError::Db)?;
+        Ok(StatementTypes {
+            params: stmt.params().to_vec(),
+            columns: stmt
+                .columns()
+                .iter()
+                .map(|it| Column {
+                    name: it.name().to_string(),
+                    ty: it.type_().clone(),
+                })
+                .collect(),
+        })
+    }
+
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError> {
+        Ok(self
+            .query(
+                "SELECT a.attname::text
+                FROM pg_catalog.pg_attribute a
+                JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+                WHERE c.oid = to_regclass($1) AND c.relkind IN ('v', 'm')
+                    AND a.attnum > 0 AND NOT a.attisdropped
+                ORDER BY a.attnum",
+                &[&name],
+            )
+            .map_err(CatalogError::Db)?
+            .iter()
+            .map(|row| row.get(0))
+            .collect())
+    }
+
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError> {
+        Ok(self
+            .query(
+                "SELECT a.attname::text
+                FROM pg_catalog.pg_index i
+                JOIN pg_catalog.pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = i.indkey[0]
+                WHERE i.indisunique AND i.indnatts = 1
+                    AND i.indrelid IN (SELECT to_regclass(t) FROM unnest($1::text[]) t)",
+                &[&tables],
+            )
+            .map_err(CatalogError::Db)?
+            .iter()
+            .map(|row| row.get(0))
+            .collect())
+    }
+
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError> {
+        Ok(self
+            .query(
+                "SELECT tc.table_schema::text, tc.constraint_name::text, tc.table_name::text,
+                    kcu.column_name::text, ref.table_schema::text, ref.table_name::text,
+                    ref.column_name::text
+                FROM information_schema.table_constraints tc
+                JOIN information_schema.key_column_usage kcu
+                    ON kcu.constraint_schema = tc.constraint_schema
+                    AND kcu.constraint_name = tc.constraint_name
+                    AND kcu.table_name = tc.table_name
+                JOIN information_schema.referential_constraints rc
+                    ON rc.constraint_schema = tc.constraint_schema
+                    AND rc.constraint_name = tc.constraint_name
+                JOIN information_schema.key_column_usage ref
+                    ON ref.constraint_schema = rc.unique_constraint_schema
+                    AND ref.constraint_name = rc.unique_constraint_name
+                    AND ref.ordinal_position = kcu.position_in_unique_constraint
+                WHERE tc.constraint_type = 'FOREIGN KEY'
+                ORDER BY tc.table_schema, tc.table_name, tc.constraint_name, kcu.ordinal_position",
+                &[],
+            )
+            .map_err(CatalogError::Db)?
+            .iter()
+            .map(|row| ForeignKeyColumn {
+                schema: row.get(0),
+                constraint: row.get(1),
+                table: row.get(2),
+                column: row.get(3),
+                foreign_schema: row.get(4),
+                foreign_table: row.get(5),
+                foreign_column: row.get(6),
+            })
+            .collect())
+    }
+}
+
+/// Live catalog recording the result of every lookup in an [`OfflineCache`]
+pub(crate) struct Recorder<'a> {
+    client: &'a mut Client,
+    cache: OfflineCache,
+}
+
+impl<'a> Recorder<'a> {
+    pub(crate) fn new(client: &'a mut Client) -> Self {
+        Self {
+            client,
+            cache: OfflineCache::default(),
+        }
+    }
+
+    /// Returns the recorded cache, tagged with the hash of the schema it was recorded against.
+    /// The foreign keys are always included, so that the cache does not depend on the settings.
+    pub(crate) fn finish(mut self, schema: String) -> Result<OfflineCache, CatalogError> {
+        if self.cache.foreign_keys.is_none() {
+            self.foreign_keys()?;
+        }
+        self.cache.schema = schema;
+        Ok(self.cache)
+    }
+}
+
+impl Catalog for Recorder<'_> {
+    fn batch_execute(&mut self, sql: &str) -> Result<(), CatalogError> {
+        <Client as Catalog>::batch_execute(self.client, sql)
+    }
+
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError> {
+        let stmt = <Client as Catalog>::prepare(self.client, sql)?;
+        self.cache.statements.insert(
+            sql.to_string(),
+            CachedStatement {
+                params: stmt.params.iter().map(CachedType::from).collect(),
+                columns: stmt
+                    .columns
+                    .iter()
+                    .map(|it| CachedField {
+                        name: it.name.clone(),
+                        ty: (&it.ty).into(),
+                    })
+                    .collect(),
+            },
+        );
+        Ok(stmt)
+    }
+
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError> {
+        let columns = self.client.view_columns(name)?;
+        self.cache.views.insert(name.to_string(), columns.clone());
+        Ok(columns)
+    }
+
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError> {
+        let columns = self.client.unique_columns(tables)?;
+        self.cache
+            .unique_columns
+            .insert(tables.join(" "), columns.clone());
+        Ok(columns)
+    }
+
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError> {
+        let foreign_keys = self.client.foreign_keys()?;
+        self.cache.foreign_keys = Some(foreign_keys.clone());
+        Ok(foreign_keys)
+    }
+}
+
+/// Metadata of the queries prepared against a schema, from which they can be prepared again
+/// without a database
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct OfflineCache {
+    /// Hash of the schema files the queries were prepared against, see [`schema_hash`]
+    schema: String,
+    /// Prepared statements by SQL
+    statements: BTreeMap<String, CachedStatement>,
+    /// Columns of the views by quoted name
+    views: BTreeMap<String, Vec<String>>,
+    /// Unique columns by space separated list of tables
+    unique_columns: BTreeMap<String, Vec<String>>,
+    foreign_keys: Option<Vec<ForeignKeyColumn>>,
+}
+
+impl OfflineCache {
+    /// Loads the cache at `path`, checking that it was saved against the schema hashed to `schema`
+    pub(crate) fn load(path: &Path, schema: &str) -> Result<Self, Error> {
+        let display = path.display().to_string();
+        let content = std::fs::read_to_string(path).map_err(|err| Error::Io {
+            path: display.clone(),
+            err,
+        })?;
+        let cache: Self = serde_json::from_str(&content).map_err(|err| Error::Parse {
+            path: display.clone(),
+            err,
+        })?;
+        if cache.schema != schema {
+            return Err(Error::Stale { path: display });
+        }
+        Ok(cache)
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> Result<(), Error> {
+        let mut content = serde_json::to_string_pretty(self).expect("the cache is serializable");
+        content.push('\n');
+        std::fs::write(path, content).map_err(|err| Error::Write {
+            path: path.display().to_string(),
+            err,
+        })
+    }
+}
+
+impl Catalog for OfflineCache {
+    fn batch_execute(&mut self, _: &str) -> Result<(), CatalogError> {
+        // The session settings only mattered when the cache was saved
+        Ok(())
+    }
+
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError> {
+        let stmt = self
+            .statements
+            .get(sql)
+            .ok_or_else(|| CatalogError::Uncached("this query".to_string()))?;
+        Ok(StatementTypes {
+            params: stmt.params.iter().map(Type::from).collect(),
+            columns: stmt
+                .columns
+                .iter()
+                .map(|it| Column {
+                    name: it.name.clone(),
+                    ty: (&it.ty).into(),
+                })
+                .collect(),
+        })
+    }
+
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError> {
+        self.views
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CatalogError::Uncached(format!("view {name}")))
+    }
+
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError> {
+        self.unique_columns
+            .get(&tables.join(" "))
+            .cloned()
+            .ok_or_else(|| CatalogError::Uncached("the unique columns of this query".to_string()))
+    }
+
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError> {
+        self.foreign_keys
+            .clone()
+            .ok_or_else(|| CatalogError::Uncached("the list of foreign keys".to_string()))
+    }
+}
+
+/// Catalog without any database, looking up the offline cache if some is given and falling back
+/// on the schema files
+pub(crate) struct Offline {
+    cache: Option<OfflineCache>,
+    schema: SchemaCatalog,
+}
+
+impl Offline {
+    pub(crate) fn new(cache: Option<OfflineCache>, schema: SchemaCatalog) -> Self {
+        Self { cache, schema }
+    }
+
+    fn lookup<T>(
+        &mut self,
+        lookup: impl Fn(&mut dyn Catalog) -> Result<T, CatalogError>,
+    ) -> Result<T, CatalogError> {
+        match self.cache.as_mut().map(|cache| lookup(cache)) {
+            Some(Err(CatalogError::Uncached(_))) | None => lookup(&mut self.schema),
+            Some(result) => result,
+        }
+    }
+}
+
+impl Catalog for Offline {
+    fn batch_execute(&mut self, _: &str) -> Result<(), CatalogError> {
+        // There is no session to alter
+        Ok(())
+    }
+
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError> {
+        self.lookup(|catalog| catalog.prepare(sql))
+    }
+
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError> {
+        self.lookup(|catalog| catalog.view_columns(name))
+    }
+
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError> {
+        self.lookup(|catalog| catalog.unique_columns(tables))
+    }
+
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError> {
+        self.lookup(|catalog| catalog.foreign_keys())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedStatement {
+    params: Vec<CachedType>,
+    columns: Vec<CachedField>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: CachedType,
+}
+
+/// Serializable description of a Postgres type, down to the types it is made of
+#[derive(Serialize, Deserialize)]
+struct CachedType {
+    oid: u32,
+    schema: String,
+    name: String,
+    kind: CachedKind,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CachedKind {
+    Simple,
+    Pseudo,
+    Enum(Vec<String>),
+    Array(Box<CachedType>),
+    Range(Box<CachedType>),
+    Multirange(Box<CachedType>),
+    Domain(Box<CachedType>),
+    Composite(Vec<CachedField>),
+}
+
+impl From<&Type> for CachedType {
+    fn from(ty: &Type) -> Self {
+        let kind = match ty.kind() {
+            Kind::Simple => CachedKind::Simple,
+            Kind::Enum(variants) => CachedKind::Enum(variants.clone()),
+            Kind::Array(inner) => CachedKind::Array(Box::new(inner.into())),
+            Kind::Range(inner) => CachedKind::Range(Box::new(inner.into())),
+            Kind::Multirange(inner) => CachedKind::Multirange(Box::new(inner.into())),
+            Kind::Domain(inner) => CachedKind::Domain(Box::new(inner.into())),
+            Kind::Composite(fields) => CachedKind::Composite(
+                fields
+                    .iter()
+                    .map(|it| CachedField {
+                        name: it.name().to_string(),
+                        ty: it.type_().into(),
+                    })
+                    .collect(),
+            ),
+            // Unsupported either way
+            _ => CachedKind::Pseudo,
+        };
+        Self {
+            oid: ty.oid(),
+            schema: ty.schema().to_string(),
+            name: ty.name().to_string(),
+            kind,
+        }
+    }
+}
+
+impl From<&CachedType> for Type {
+    fn from(ty: &CachedType) -> Self {
+        // Like the driver, use the constants of the built-in types so that they compare equal
+        if let Some(ty) = Type::from_oid(ty.oid) {
+            return ty;
+        }
+        let kind = match &ty.kind {
+            CachedKind::Simple => Kind::Simple,
+            CachedKind::Pseudo => Kind::Pseudo,
+            CachedKind::Enum(variants) => Kind::Enum(variants.clone()),
+            CachedKind::Array(inner) => Kind::Array(inner.as_ref().into()),
+            CachedKind::Range(inner) => Kind::Range(inner.as_ref().into()),
+            CachedKind::Multirange(inner) => Kind::Multirange(inner.as_ref().into()),
+            CachedKind::Domain(inner) => Kind::Domain(inner.as_ref().into()),
+            CachedKind::Composite(fields) => Kind::Composite(
+                fields
+                    .iter()
+                    .map(|it| Field::new(it.name.clone(), (&it.ty).into()))
+                    .collect(),
+            ),
+        };
+        Type::new(ty.name.clone(), ty.oid, kind, ty.schema.clone())
+    }
+}
+
+/// Hash of the content of `schema_files`, ignoring carriage returns so that it does not depend
+/// on the line endings of a checkout
+pub(crate) fn schema_hash<P: AsRef<Path>>(schema_files: &[P]) -> Result<String, Error> {
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust versions
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for path in schema_files {
+        let path = path.as_ref();
+        let content = std::fs::read(path).map_err(|err| Error::Io {
+            path: path.display().to_string(),
+            err,
+        })?;
+        for byte in content.into_iter().filter(|it| *it != b'\r') {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    Ok(format!("{hash:016x}"))
+}
+
+pub(crate) mod error {
+    use miette::Diagnostic;
+    use thiserror::Error as ThisError;
+
+    #[derive(Debug, ThisError, Diagnostic)]
+    pub enum Error {
+        #[error("Could not read `{path}`: ({err})")]
+        Io { path: String, err: std::io::Error },
+        #[error("Could not parse the offline cache `{path}`: {err}")]
+        #[diagnostic(help("save the offline cache again"))]
+        Parse {
+            path: String,
+            err: serde_json::Error,
+        },
+        #[error("Could not write the offline cache `{path}`: ({err})")]
+        Write { path: String, err: std::io::Error },
+        #[error("The offline cache `{path}` was saved against another schema")]
+        #[diagnostic(help(
+            "save the offline cache again after changing the schema, e.g. with `cornucopia schema --save-offline`"
+        ))]
+        Stale { path: String },
+    }
+}
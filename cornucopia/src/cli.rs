@@ -1,8 +1,16 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand};
+use log::{LevelFilter, Log, Metadata, Record};
 
-use crate::{conn, container, error::Error, generate_live, generate_managed, CodegenSettings};
+use crate::{
+    check_managed, conn,
+    error::{Error, MultipleErrors},
+    filter_modules, format_generated_code, generate_files, generate_live_modules,
+    generate_live_modules_split, init_project, prepare_managed, prepare_offline, read_modules,
+    verify_generated_code, verify_generated_files, write_generated_code, write_generated_files,
+    CodegenSettings, TimeBackend,
+};
 
 /// Command line interface to interact with Cornucopia SQL.
 #[derive(Parser, Debug)]
@@ -11,12 +19,21 @@ struct Args {
     /// Use `podman` instead of `docker`
     #[clap(short, long)]
     podman: bool,
-    /// Folder containing the queries
+    /// Folder containing the queries. Repeat to merge several folders into the same output
     #[clap(short, long, default_value = "queries/")]
-    queries_path: PathBuf,
+    queries_path: Vec<PathBuf>,
     /// Destination folder for generated modules
     #[clap(short, long, default_value = "src/cornucopia.rs")]
     destination: PathBuf,
+    /// Generate one file per module in the `destination` directory instead of a single file
+    #[clap(long)]
+    split: bool,
+    /// Compare the generated code with the destination instead of writing it, failing if it is stale
+    #[clap(long)]
+    verify: bool,
+    /// Command the generated code is piped through before being written, e.g. `rustfmt --edition 2021`
+    #[clap(long)]
+    formatter: Option<String>,
     #[clap(subcommand)]
     action: Action,
     /// Generate synchronous rust code
@@ -28,19 +45,127 @@ struct Args {
     /// Derive serde's `Serialize` trait for generated types.
     #[clap(long)]
     serialize: bool,
+    /// Only derive serde's traits when the consuming crate enables this cargo feature.
+    #[clap(long, requires = "serialize")]
+    serde_feature: Option<String>,
+    /// Serialize `bigint` and `numeric` fields as strings, which JavaScript parses without losing precision.
+    #[clap(long, requires = "serialize")]
+    bigint_as_string: bool,
+    /// Derive sqlx's `FromRow` trait for owned row structs.
+    #[clap(long)]
+    sqlx: bool,
+    /// Derive `Eq` and `Hash` for row structs whose fields are all hashable.
+    #[clap(long)]
+    hash: bool,
+    /// Generate a single row struct using `Cow` for text fields instead of a borrowed variant.
+    #[clap(long)]
+    cow: bool,
+    /// Generate constants describing the foreign keys of the database schema.
+    #[clap(long)]
+    foreign_keys: bool,
+    /// Generate a `prelude` module re-exporting all query functions and types.
+    #[clap(long)]
+    prelude: bool,
+    /// Wrap driver errors in a generated error carrying the name of the failed query.
+    #[clap(long)]
+    query_errors: bool,
+    /// Map `numeric` to `f64` instead of `rust_decimal::Decimal`, at the cost of precision.
+    #[clap(long)]
+    numeric_as_f64: bool,
+    /// Map `real` and `double precision` to totally ordered wrappers, for which `NaN` equals itself.
+    #[clap(long)]
+    ordered_floats: bool,
+    /// Derive `PartialOrd` and `Ord` for enums, following the variants' order in the database.
+    #[clap(long)]
+    enum_ord: bool,
+    /// Generate an enum of each row's columns, giving their SQL name for query builders.
+    #[clap(long)]
+    column_enums: bool,
+    /// Also recognize yesql/aiosql `-- name: <ident>` query annotations.
+    #[clap(long)]
+    yesql: bool,
+    /// Generate a `bind_dyn` method binding a slice of `&dyn ToSql`, checked for arity at runtime
+    #[clap(long)]
+    dyn_params: bool,
+    /// Use fully qualified paths in query modules instead of `use` statements
+    #[clap(long)]
+    qualified_paths: bool,
+    /// Generate a `Statements` struct per module holding all its statements, prepared by `prepare_all`
+    #[clap(long)]
+    statements_struct: bool,
+    /// Return a named stream type from async `iter` instead of an `impl Stream`
+    #[clap(long)]
+    named_streams: bool,
+    /// Read row columns by name instead of by index, resilient to reordered columns but slower
+    #[clap(long)]
+    columns_by_name: bool,
+    /// Read `text` and `varchar` columns as raw bytes, for databases with a non-UTF8 encoding
+    #[clap(long)]
+    text_as_bytes: bool,
+    /// Read `timestamp` and `timestamptz` columns as a `Timestamp` enum accepting infinite values
+    #[clap(long)]
+    infinite_timestamps: bool,
+    /// Crate providing the types of temporal columns: `chrono`, `time` or `none`
+    #[clap(long, default_value = "time")]
+    time_backend: TimeBackend,
+    /// Abort the preparation of a query taking longer than this many milliseconds
+    #[clap(long)]
+    statement_timeout: Option<u32>,
+    /// Print progress while preparing queries. Repeat to also time each query (`-vv`) and print its SQL (`-vvv`)
+    #[clap(short, long, action = ArgAction::Count)]
+    verbose: u8,
+    /// Only print errors
+    #[clap(long, conflicts_with = "verbose")]
+    quiet: bool,
 }
 
 #[derive(Debug, Subcommand)]
 enum Action {
     /// Generate your modules against your own db
     Live {
-        /// Postgres url to the database
+        /// Postgres url to the database. When built with the `native-tls` feature,
+        /// TLS is negotiated according to the url's `sslmode`
+        #[clap(env = "DATABASE_URL")]
         url: String,
+        /// Schemas unqualified names resolve against while preparing queries, e.g. `tenant,public`
+        #[clap(long)]
+        search_path: Option<String>,
+        /// Only generate the queries matching this glob on the module name, query name
+        /// or `module::query` path, e.g. `users` or `*::insert_*`
+        #[clap(long)]
+        filter: Option<String>,
+        /// Name of the session in `pg_stat_activity`, defaults to the url's or `cornucopia-codegen`
+        #[clap(long)]
+        application_name: Option<String>,
     },
     /// Generate your modules against schema files
     Schema {
         /// SQL files containing the database schema
         schema_files: Vec<PathBuf>,
+        /// Also save the types of the queries to this offline cache, for the `offline` command
+        #[clap(long)]
+        save_offline: Option<PathBuf>,
+    },
+    /// Generate your modules from schema files, without any database. Only simple queries on
+    /// tables and views are supported, unless an offline cache is given
+    Offline {
+        /// SQL files containing the database schema, e.g. a `pg_dump --schema-only` output
+        schema_files: Vec<PathBuf>,
+        /// Offline cache saved against the schema files by `schema --save-offline`
+        #[clap(long)]
+        cache: Option<PathBuf>,
+    },
+    /// Check that all queries prepare against schema files, without generating code.
+    /// Every query error is reported
+    Check {
+        /// SQL files containing the database schema
+        schema_files: Vec<PathBuf>,
+    },
+    /// Scaffold a new project with a starter migration and an example query file
+    Init {
+        /// Directory in which `migrations` and `queries` are created
+        #[clap(default_value = ".")]
+        path: PathBuf,
     },
 }
 
@@ -50,36 +175,209 @@ pub fn run() -> Result<(), Error> {
         podman,
         queries_path,
         destination,
+        split,
+        verify,
+        formatter,
         action,
         sync,
         r#async,
         serialize,
+        serde_feature,
+        sqlx,
+        hash,
+        cow,
+        foreign_keys,
+        prelude,
+        query_errors,
+        numeric_as_f64,
+        ordered_floats,
+        enum_ord,
+        column_enums,
+        yesql,
+        dyn_params,
+        qualified_paths,
+        statements_struct,
+        named_streams,
+        columns_by_name,
+        text_as_bytes,
+        infinite_timestamps,
+        bigint_as_string,
+        time_backend,
+        statement_timeout,
+        verbose,
+        quiet,
     } = Args::parse();
 
+    let level = match (quiet, verbose) {
+        (true, _) => LevelFilter::Off,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Info,
+        (false, 2) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    };
+    // Only fails if a logger was already installed
+    if log::set_logger(&StderrLogger).is_ok() {
+        log::set_max_level(level);
+    }
+
     let settings = CodegenSettings {
         gen_async: r#async || !sync,
         gen_sync: sync,
         derive_ser: serialize,
+        // The settings are used until the end of the process
+        serde_feature: serde_feature.map(|it| &*Box::leak(it.into_boxed_str())),
+        derive_sqlx: sqlx,
+        derive_hash: hash,
+        gen_cow: cow,
+        gen_foreign_keys: foreign_keys,
+        gen_prelude: prelude,
+        gen_query_errors: query_errors,
+        numeric_as_f64,
+        ordered_floats,
+        enum_ord,
+        column_enums,
+        yesql,
+        dyn_params,
+        qualified_paths,
+        statements_struct,
+        named_streams,
+        columns_by_name,
+        text_as_bytes,
+        infinite_timestamps,
+        bigint_as_string,
+        time_backend,
+        statement_timeout,
     };
 
-    match action {
-        Action::Live { url } => {
-            let mut client = conn::from_url(&url)?;
-            generate_live(&mut client, &queries_path, Some(&destination), settings)?;
+    // Paths are relative to the destination directory when splitting
+    let generated_files = match action {
+        Action::Live {
+            url,
+            search_path,
+            filter,
+            application_name,
+        } => {
+            let mut client = conn::from_url(&url, application_name.as_deref())?;
+            if let Some(search_path) = search_path {
+                conn::set_search_path(&mut client, &search_path)?;
+            }
+            let mut modules = read_modules(&queries_path, settings)?;
+            if let Some(filter) = filter {
+                filter_modules(&mut modules, &filter);
+            }
+            if split {
+                generate_live_modules_split(&mut client, modules, None::<&Path>, settings)?
+            } else {
+                let generated_code =
+                    generate_live_modules(&mut client, modules, None::<&Path>, settings)?;
+                vec![(destination.clone(), generated_code)]
+            }
         }
-        Action::Schema { schema_files } => {
-            // Run the generate command. If the command is unsuccessful, cleanup Cornucopia's container
-            if let Err(e) = generate_managed(
-                queries_path,
+        Action::Schema {
+            schema_files,
+            save_offline,
+        } => {
+            let modules = read_modules(&queries_path, settings)?;
+            // Cornucopia's container is cleaned up even if the command is unsuccessful
+            let preparation = prepare_managed(
+                modules,
                 &schema_files,
-                Some(destination),
+                save_offline.as_deref(),
                 podman,
                 settings,
-            ) {
-                container::cleanup(podman).ok();
-                return Err(e);
-            }
+            )?;
+            generate_files(&preparation, split, &destination, settings)
+        }
+        Action::Offline {
+            schema_files,
+            cache,
+        } => {
+            let modules = read_modules(&queries_path, settings)?;
+            let preparation = prepare_offline(modules, &schema_files, cache.as_deref(), settings)?;
+            generate_files(&preparation, split, &destination, settings)
+        }
+        Action::Check { schema_files } => {
+            check_managed(&queries_path, &schema_files, podman, settings)
+                .map_err(MultipleErrors::from)?;
+            return Ok(());
+        }
+        Action::Init { path } => {
+            init_project(&path)?;
+            let migrations = path.join("migrations");
+            let migration = migrations.join("0001_init.sql");
+            let queries = path.join("queries");
+            let (migrations, migration, queries) =
+                (migrations.display(), migration.display(), queries.display());
+            println!(
+                "Created `{migration}` and `{queries}`.
+
+Generate the queries module with:
+    cornucopia --queries-path {queries} --destination src/cornucopia.rs schema {migration}
+
+Or regenerate it on every build from a `build.rs`:
+    fn main() {{
+        println!(\"cargo:rerun-if-changed={migrations}\");
+        println!(\"cargo:rerun-if-changed={queries}\");
+        let status = std::process::Command::new(\"cornucopia\")
+            .args([\"--queries-path\", \"{queries}\", \"schema\", \"{migration}\"])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }}"
+            );
+            return Ok(());
         }
     };
+    let mut formatted_files = Vec::with_capacity(generated_files.len());
+    for (path, generated_code) in generated_files {
+        let generated_code = match &formatter {
+            Some(formatter) => format_generated_code(&generated_code, formatter)?,
+            None => generated_code,
+        };
+        formatted_files.push((path, generated_code));
+    }
+    if verify {
+        if split {
+            verify_generated_files(&destination, &formatted_files)?;
+        } else {
+            // Report every stale file
+            let errors: Vec<_> = formatted_files
+                .iter()
+                .filter_map(|(path, generated_code)| {
+                    verify_generated_code(path, generated_code).err()
+                })
+                .collect();
+            if !errors.is_empty() {
+                return Err(Error::from_many(errors));
+            }
+        }
+    } else if split {
+        write_generated_files(&destination, &formatted_files)?;
+    } else {
+        for (path, generated_code) in &formatted_files {
+            write_generated_code(path, generated_code)?;
+        }
+    }
     Ok(())
 }
+
+/// Prints cornucopia's log records on stderr
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with("cornucopia")
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "[{}] {}",
+                record.level().as_str().to_lowercase(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
@@ -1,32 +1,43 @@
 use core::str;
-use std::fmt::{Display, Write};
+use std::{
+    fmt::{Display, Write},
+    path::{Path, PathBuf},
+};
 
 use codegen_template::code;
+use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
+use postgres_types::Type;
 
 use crate::{
     prepare_queries::{
-        Ident, Preparation, PreparedContent, PreparedField, PreparedItem, PreparedModule,
-        PreparedQuery, PreparedType,
+        Ident, Preparation, PreparedContent, PreparedField, PreparedForeignKey, PreparedIntEnum,
+        PreparedItem, PreparedModule, PreparedQuery, PreparedType, RowMember,
     },
+    utils::literal_limit,
     CodegenSettings,
 };
 
+/// Header of every generated file
+const HEADER: &str = "// This file was generated with `cornucopia`. Do not modify.\n\n";
+
+/// Largest number of rows pre-allocated from a query `LIMIT`
+const MAX_LIMIT_CAPACITY: usize = 1024;
+
 pub struct GenCtx {
     // Current module depth
     pub depth: u8,
     // Should use async client and generate async code
     pub is_async: bool,
-    // Should serializable struct
-    pub gen_derive: bool,
+    pub settings: CodegenSettings,
 }
 
 impl GenCtx {
-    pub fn new(depth: u8, is_async: bool, gen_derive: bool) -> Self {
+    pub fn new(depth: u8, settings: CodegenSettings) -> Self {
         Self {
             depth,
-            is_async,
-            gen_derive,
+            is_async: settings.gen_async,
+            settings,
         }
     }
 
@@ -42,6 +53,61 @@ impl GenCtx {
             "cornucopia_sync"
         }
     }
+
+    /// Path of the client trait bounding the generated queries
+    pub fn generic_client(&self) -> &'static str {
+        match (self.settings.qualified_paths, self.is_async) {
+            (true, true) => "::cornucopia_async::GenericClient",
+            (true, false) => "::postgres::GenericClient",
+            (false, _) => "GenericClient",
+        }
+    }
+
+    /// Error type returned by the generated queries
+    pub fn error_ty(&self) -> &'static str {
+        match (self.settings.gen_query_errors, self.is_async) {
+            (true, _) => "Error",
+            (false, true) => "tokio_postgres::Error",
+            (false, false) => "postgres::Error",
+        }
+    }
+
+    /// `Serialize` entry of the `derive` list, empty when not derived or gated behind a feature
+    pub fn ser_str(&self) -> &'static str {
+        if self.settings.derive_ser && self.settings.serde_feature.is_none() {
+            "serde::Serialize,"
+        } else {
+            ""
+        }
+    }
+
+    /// `Eq` and `Hash` entries of a row `derive` list, when every field of the row is hashable
+    pub fn hash_str(&self, row: &PreparedItem) -> &'static str {
+        if self.settings.derive_hash && row.is_hash {
+            "Eq, Hash,"
+        } else {
+            ""
+        }
+    }
+
+    /// Feature gated `Serialize` derive, to put before the `derive` list
+    pub fn ser_attr(&self) -> String {
+        match self.settings.serde_feature {
+            Some(feature) if self.settings.derive_ser => {
+                format!("#[cfg_attr(feature = \"{feature}\", derive(serde::Serialize))]")
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Code attaching the name of the failed query to a driver error
+    pub fn map_err(&self, query: &str) -> String {
+        if self.settings.gen_query_errors {
+            format!(".map_err(query_err({query}))")
+        } else {
+            String::new()
+        }
+    }
 }
 
 impl PreparedField {
@@ -89,6 +155,104 @@ impl PreparedField {
         )
     }
 
+    /// Doc comment giving the PostgreSQL type and nullability of this field
+    pub fn doc(&self) -> String {
+        let mut doc = format!("/// `{}`", self.ty.pg_name());
+        if self.is_nullable {
+            doc.push_str(", nullable");
+        }
+        if self.is_inner_nullable {
+            doc.push_str(", with nullable elements");
+        }
+        doc.push('\n');
+        doc
+    }
+
+    /// Serde attribute keeping the column name when its Rust ident was sanitized, and
+    /// serializing big numbers as strings if requested
+    pub fn serde_attrs(&self, ctx: &GenCtx) -> String {
+        if !ctx.settings.derive_ser {
+            return String::new();
+        }
+        let Ident { db, rs } = &self.ident;
+        let mut attrs = Vec::new();
+        if rs.trim_start_matches("r#") != db {
+            attrs.push(format!("rename = \"{}\"", db.escape_default()));
+        }
+        if ctx.settings.bigint_as_string && self.ty.is_big_number() {
+            let serialize = if self.is_nullable {
+                "serialize_option_string"
+            } else {
+                "serialize_string"
+            };
+            attrs.push(format!(
+                "serialize_with = \"{}::private::{serialize}\"",
+                ctx.client_name()
+            ));
+        }
+        if attrs.is_empty() {
+            return String::new();
+        }
+        let serde = format!("serde({})", attrs.join(", "));
+        match ctx.settings.serde_feature {
+            Some(feature) => format!("#[cfg_attr(feature = \"{feature}\", {serde})]"),
+            None => format!("#[{serde}]"),
+        }
+    }
+
+    /// Sqlx attribute matching the column name when its Rust ident was sanitized
+    pub fn sqlx_rename(&self, ctx: &GenCtx) -> String {
+        let Ident { db, rs } = &self.ident;
+        if ctx.settings.derive_sqlx && rs.trim_start_matches("r#") != db {
+            format!("#[sqlx(rename = \"{}\")]", db.escape_default())
+        } else {
+            String::new()
+        }
+    }
+
+    pub fn cow_struct(&self, ctx: &GenCtx) -> String {
+        match self.ty.cow_ty() {
+            Some(it) if self.is_nullable => format!("Option<std::borrow::Cow<'a, {it}>>"),
+            Some(it) => format!("std::borrow::Cow<'a, {it}>"),
+            None => self.own_struct(ctx),
+        }
+    }
+
+    /// Index of this field's column in a row, its name when reading columns by name
+    pub fn row_index(&self, idx: usize, ctx: &GenCtx) -> String {
+        if ctx.settings.columns_by_name {
+            format!("\"{}\"", self.ident.db.escape_default())
+        } else {
+            idx.to_string()
+        }
+    }
+
+    /// Code extracting this field from a row as its `Cow` or owned type
+    pub fn cow_extract(&self, idx: usize, ctx: &GenCtx) -> String {
+        let idx = self.row_index(idx, ctx);
+        if self.ty.cow_ty().is_some() {
+            if self.is_nullable {
+                format!("row.get::<_, Option<_>>({idx}).map(std::borrow::Cow::Borrowed)")
+            } else {
+                format!("std::borrow::Cow::Borrowed(row.get({idx}))")
+            }
+        } else {
+            let get = format!("row.get::<_, {}>({idx})", self.brw_ty(false, ctx));
+            self.owning_call(Some(&get))
+        }
+    }
+
+    pub fn cow_owning_assign(&self) -> String {
+        let name = &self.ident.rs;
+        match self.ty.cow_ty() {
+            Some(_) if self.is_nullable => {
+                format!("{name}: {name}.map(|v| std::borrow::Cow::Owned(v.into_owned()))")
+            }
+            Some(_) => format!("{name}: std::borrow::Cow::Owned({name}.into_owned())"),
+            None => name.clone(),
+        }
+    }
+
     pub fn owning_assign(&self) -> String {
         let call = self.owning_call(None);
         if call == self.ident.rs {
@@ -99,6 +263,36 @@ impl PreparedField {
     }
 }
 
+/// Fully qualify prelude types in `ty`, as user types generated in the same
+/// module (an enum named `Option` for example) would shadow them.
+fn qualify_prelude(ty: &str) -> String {
+    let mut out = String::with_capacity(ty.len());
+    let mut rest = ty;
+    while let Some(start) = rest.find(|c: char| c.is_alphabetic() || c == '_') {
+        let (before, ident) = rest.split_at(start);
+        out.push_str(before);
+        let len = ident
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(ident.len());
+        let (ident, after) = ident.split_at(len);
+        let path = match ident {
+            "Option" => "std::option::Option",
+            "String" => "std::string::String",
+            "Vec" => "std::vec::Vec",
+            "Box" => "std::boxed::Box",
+            _ => ident,
+        };
+        if out.ends_with("::") || out.ends_with('\'') {
+            out.push_str(ident);
+        } else {
+            out.push_str(path);
+        }
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
+
 fn enum_sql(w: &mut impl Write, name: &str, enum_name: &str, variants: &[Ident]) {
     let enum_names = std::iter::repeat(enum_name);
     let db_variants_ident = variants.iter().map(|v| &v.db);
@@ -111,7 +305,7 @@ fn enum_sql(w: &mut impl Write, name: &str, enum_name: &str, variants: &[Ident])
                 &self,
                 ty: &postgres_types::Type,
                 buf: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>,> {
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,> {
                 let s = match *self {
                     $($enum_names::$rs_variants_ident => "$db_variants_ident",)
                 };
@@ -139,7 +333,7 @@ fn enum_sql(w: &mut impl Write, name: &str, enum_name: &str, variants: &[Ident])
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>> {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -147,10 +341,10 @@ fn enum_sql(w: &mut impl Write, name: &str, enum_name: &str, variants: &[Ident])
             fn from_sql(
                 ty: &postgres_types::Type,
                 buf: &'a [u8],
-            ) -> Result<$enum_name, Box<dyn std::error::Error + Sync + Send>,> {
+            ) -> std::result::Result<$enum_name, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,> {
                 match std::str::from_utf8(buf)? {
-                    $("$db_variants_ident" => Ok($enum_names::$rs_variants_ident),)
-                    s => Result::Err(Into::into(format!(
+                    $("$db_variants_ident" => std::result::Result::Ok($enum_names::$rs_variants_ident),)
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
                         "invalid variant `{}`",
                         s
                     ))),
@@ -198,68 +392,39 @@ fn struct_tosql(
     let db_fields_ident = fields.iter().map(|p| &p.ident.db);
     let rs_fields_ident = fields.iter().map(|p| &p.ident.rs);
     let write_ty = fields.iter().map(|p| p.ty.sql_wrapped(&p.ident.rs, ctx));
-    let accept_ty = fields.iter().map(|p| p.ty.accept_to_sql(ctx));
+    let accept_ty = fields
+        .iter()
+        .map(|p| qualify_prelude(&p.ty.accept_to_sql(ctx)));
     let nb_fields = fields.len();
+    let client = ctx.client_name();
 
+    // The encoding of composites is shared by the client's helpers
     code!(w =>
         impl<'a> postgres_types::ToSql for $struct_name$post $lifetime {
             fn to_sql(
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>,> {
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,> {
                 let $struct_name$post {
                     $($rs_fields_ident,)
                 } = self;
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                out.extend_from_slice(&(fields.len() as i32).to_be_bytes());
-                for field in fields {
-                    out.extend_from_slice(&field.type_().oid().to_be_bytes());
-                    let base = out.len();
-                    out.extend_from_slice(&[0; 4]);
-                    let r = match field.name() {
-                        $("$db_fields_ident" => postgres_types::ToSql::to_sql($write_ty,field.type_(), out),)
-                        _ => unreachable!()
-                    };
-                    let count = match r? {
-                        postgres_types::IsNull::Yes => -1,
-                        postgres_types::IsNull::No => {
-                            let len = out.len() - base - 4;
-                            if len > i32::max_value() as usize {
-                                return Err(Into::into("value too large to transmit"));
-                            }
-                            len as i32
-                        }
-                    };
-                    out[base..base + 4].copy_from_slice(&count.to_be_bytes());
-                }
-                Ok(postgres_types::IsNull::No)
+                $client::private::write_composite(ty, out, |field, out| match field.name() {
+                    $("$db_fields_ident" => postgres_types::ToSql::to_sql($write_ty,field.type_(), out),)
+                    _ => unreachable!()
+                })
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
-                if ty.name() != "$name" {
-                    return false;
-                }
-                match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => {
-                        if fields.len() != $nb_fields {
-                            return false;
-                        }
-                        fields.iter().all(|f| match f.name() {
-                            $("$db_fields_ident" => <$accept_ty as postgres_types::ToSql>::accepts(f.type_()),)
-                            _ => false,
-                        })
-                    }
+                $client::private::composite_accepts(ty, "$name", $nb_fields, |f| match f.name() {
+                    $("$db_fields_ident" => <$accept_ty as postgres_types::ToSql>::accepts(f.type_()),)
                     _ => false,
-                }
+                })
             }
             fn to_sql_checked(
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>> {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -272,29 +437,33 @@ fn composite_fromsql(
     fields: &[PreparedField],
     name: &str,
     schema: &str,
+    ctx: &GenCtx,
 ) {
+    let client = ctx.client_name();
+    let init_names = fields.iter().map(|p| &p.ident.rs);
+    let read_names = fields.iter().map(|p| &p.ident.rs);
+    let read_db_names = fields.iter().map(|p| &p.ident.db);
     let field_names = fields.iter().map(|p| &p.ident.rs);
-    let read_idx = 0..fields.len();
+    let unwrap_names = fields.iter().map(|p| &p.ident.rs);
+    let missing_db_names = fields.iter().map(|p| &p.ident.db);
     code!(w =>
         impl<'a> postgres_types::FromSql<'a> for ${struct_name}Borrowed<'a> {
             fn from_sql(ty: &postgres_types::Type, out: &'a [u8]) ->
-                Result<${struct_name}Borrowed<'a>, Box<dyn std::error::Error + Sync + Send>>
+                std::result::Result<${struct_name}Borrowed<'a>, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>>
             {
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                let mut out = out;
-                let num_fields = postgres_types::private::read_be_i32(&mut out)?;
-                if num_fields as usize != fields.len() {
-                    return std::result::Result::Err(
-                        std::convert::Into::into(format!("invalid field count: {} vs {}", num_fields, fields.len())));
-                }
-                $(
-                    let _oid = postgres_types::private::read_be_i32(&mut out)?;
-                    let $field_names = postgres_types::private::read_value(fields[$read_idx].type_(), &mut out)?;
-                )
-                Ok(${struct_name}Borrowed { $($field_names,) })
+                $(let mut $init_names = None;)
+                // Fields are matched by name, in the order sent by the server
+                $client::private::read_composite(ty, out, |field, out| {
+                    match field.name() {
+                        $("$read_db_names" => $read_names = Some(postgres_types::private::read_value(field.type_(), out)?),)
+                        _ => return std::result::Result::Err(
+                            std::convert::Into::into(format!("unexpected field: {}", field.name()))),
+                    }
+                    std::result::Result::Ok(())
+                })?;
+                std::result::Result::Ok(${struct_name}Borrowed {
+                    $($field_names: $unwrap_names.ok_or("missing field: $missing_db_names")?,)
+                })
             }
 
             fn accepts(ty: &postgres_types::Type) -> bool {
@@ -304,6 +473,96 @@ fn composite_fromsql(
     );
 }
 
+/// Generates an enum mapped from an integer column. Conversions go through `TryFrom<i16>`.
+fn gen_int_enum(w: &mut impl Write, int_enum: &PreparedIntEnum, ctx: &GenCtx) {
+    let PreparedIntEnum {
+        name,
+        variants,
+        pg_ty,
+    } = int_enum;
+    // Unmapped enums use the smallest representation that fits their discriminants
+    let repr = match pg_ty {
+        Some(ty) if *ty == Type::INT4 => "i32",
+        Some(_) => "i16",
+        None if variants
+            .iter()
+            .all(|(_, value)| i16::try_from(*value).is_ok()) =>
+        {
+            "i16"
+        }
+        None => "i32",
+    };
+    let enum_names = std::iter::repeat(name);
+    let variants_ident = variants.iter().map(|(v, _)| &v.rs);
+    let variants_value = variants.iter().map(|(_, value)| value);
+    let hash_str = if ctx.settings.derive_hash {
+        "Hash,"
+    } else {
+        ""
+    };
+    let ser_str = ctx.ser_str();
+    let ser_attr = ctx.ser_attr();
+    code!(w =>
+        $ser_attr
+        #[derive($ser_str Debug, Clone, Copy, PartialEq, Eq, $hash_str)]
+        #[allow(non_camel_case_types)]
+        #[repr($repr)]
+        pub enum $name {
+            $($variants_ident = $variants_value,)
+        }
+        impl std::convert::TryFrom<$repr> for $name {
+            type Error = $repr;
+            #[inline]
+            fn try_from(value: $repr) -> std::result::Result<Self, Self::Error> {
+                match value {
+                    $($variants_value => std::result::Result::Ok($enum_names::$variants_ident),)
+                    _ => std::result::Result::Err(value),
+                }
+            }
+        }
+        impl std::convert::From<$name> for $repr {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value as $repr
+            }
+        }
+        impl<'a> postgres_types::FromSql<'a> for $name {
+            fn from_sql(
+                ty: &postgres_types::Type,
+                buf: &'a [u8],
+            ) -> std::result::Result<$name, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>> {
+                let value = <$repr as postgres_types::FromSql>::from_sql(ty, buf)?;
+                <$name as std::convert::TryFrom<$repr>>::try_from(value).map_err(|value| std::convert::Into::into(format!(
+                    "invalid discriminant `{}`",
+                    value
+                )))
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                <$repr as postgres_types::FromSql>::accepts(ty)
+            }
+        }
+        impl postgres_types::ToSql for $name {
+            fn to_sql(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>> {
+                postgres_types::ToSql::to_sql(&<$repr as std::convert::From<$name>>::from(*self), ty, out)
+            }
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                <$repr as postgres_types::ToSql>::accepts(ty)
+            }
+            fn to_sql_checked(
+                &self,
+                ty: &postgres_types::Type,
+                out: &mut postgres_types::private::BytesMut,
+            ) -> std::result::Result<postgres_types::IsNull, std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>> {
+                postgres_types::__to_sql_checked(self, ty, out)
+            }
+        }
+    );
+}
+
 fn gen_params_struct(w: &mut impl Write, params: &PreparedItem, ctx: &GenCtx) {
     let PreparedItem {
         name,
@@ -311,6 +570,7 @@ fn gen_params_struct(w: &mut impl Write, params: &PreparedItem, ctx: &GenCtx) {
         is_copy,
         is_named,
         is_ref,
+        attributes,
         ..
     } = params;
     if *is_named {
@@ -323,58 +583,290 @@ fn gen_params_struct(w: &mut impl Write, params: &PreparedItem, ctx: &GenCtx) {
             .map(|p| p.param_ergo_ty(traits, ctx))
             .collect::<Vec<_>>();
         let fields_name = fields.iter().map(|p| &p.ident.rs);
+        let fields_doc = fields.iter().map(|p| p.doc());
         let traits_idx = (1..=traits.len()).into_iter().map(idx_char);
+        let attributes = attributes.iter();
         code!(w =>
             #[derive($copy Debug)]
+            $(#[$attributes])
             pub struct $name<$lifetime $($traits_idx: $traits,)> {
-                $(pub $fields_name: $fields_ty,)
+                $($fields_doc pub $fields_name: $fields_ty,)
             }
         );
     }
 }
 
 fn gen_row_structs(w: &mut impl Write, row: &PreparedItem, ctx: &GenCtx) {
+    if row.is_named {
+        if ctx.settings.column_enums {
+            gen_column_enum(w, row);
+        }
+        for group in &row.groups {
+            if ctx.settings.gen_cow && !group.item.is_copy {
+                gen_cow_row_struct(w, &group.item, ctx);
+            } else {
+                let prefix = format!("{}_", group.field.db);
+                gen_owned_row_struct(w, &group.item, &prefix, ctx);
+            }
+        }
+        if ctx.settings.gen_cow && !row.is_copy {
+            gen_cow_row_struct(w, row, ctx);
+        } else {
+            gen_owned_row_struct(w, row, "", ctx);
+        }
+    }
+}
+
+/// Generates a row struct, and its borrowed variant if it is not `Copy`.
+/// Sqlx renames the fields of a group struct to their prefixed column.
+fn gen_owned_row_struct(w: &mut impl Write, row: &PreparedItem, prefix: &str, ctx: &GenCtx) {
     let PreparedItem {
         name,
-        fields,
         is_copy,
-        is_named,
+        attributes,
         ..
     } = row;
-    if *is_named {
-        // Generate row struct
-        let fields_name = fields.iter().map(|p| &p.ident.rs);
-        let fields_ty = fields.iter().map(|p| p.own_struct(ctx));
-        let copy = if *is_copy { "Copy" } else { "" };
-        let ser_str = if ctx.gen_derive {
-            "serde::Serialize,"
+    let members = row.members();
+    let fields_name = members.iter().map(|m| m.name());
+    let fields_ty = members.iter().map(|m| m.own_ty(ctx));
+    let copy = if *is_copy { "Copy" } else { "" };
+    let hash_str = ctx.hash_str(row);
+    let ser_str = ctx.ser_str();
+    let ser_attr = ctx.ser_attr();
+    let sqlx_str = if ctx.settings.derive_sqlx {
+        "sqlx::FromRow,"
+    } else {
+        ""
+    };
+    let attributes = attributes.iter();
+    let fields_serde = members.iter().map(|m| m.serde_attrs(ctx));
+    let fields_sqlx_rename = members.iter().map(|m| m.sqlx_attr(prefix, ctx));
+    let fields_doc = members.iter().map(|m| m.doc());
+    code!(w =>
+        $ser_attr
+        #[derive($ser_str $sqlx_str Debug, Clone, PartialEq, $hash_str $copy)]
+        $(#[$attributes])
+        pub struct $name {
+            $($fields_doc $fields_serde $fields_sqlx_rename pub $fields_name : $fields_ty,)
+        }
+    );
+
+    if !is_copy {
+        let fields_name = members.iter().map(|m| m.name());
+        let fields_ty = members.iter().map(|m| m.brw_ty(ctx));
+        let fields_doc = members.iter().map(|m| m.doc());
+        let brw_ser = brw_ser(&row.fields, ctx);
+        let is_brw_ser = brw_ser.is_some();
+        let fields_serde = members.iter().map(|m| {
+            if is_brw_ser {
+                m.serde_attrs(ctx)
+            } else {
+                String::new()
+            }
+        });
+        let (brw_ser_str, brw_ser_attr) = brw_ser.unwrap_or_default();
+        let brw_derive = if brw_ser_str.is_empty() {
+            String::new()
         } else {
-            ""
+            format!("#[derive({brw_ser_str})]")
         };
+        let from_own_assign = members.iter().map(|m| m.owning_assign());
+        let destructure = members.iter().map(|m| m.name());
         code!(w =>
-            #[derive($ser_str Debug, Clone, PartialEq,$copy)]
-            pub struct $name {
-                $(pub $fields_name : $fields_ty,)
+            $brw_ser_attr
+            $brw_derive
+            pub struct ${name}Borrowed<'a> {
+                $($fields_doc $fields_serde pub $fields_name : $fields_ty,)
+            }
+            impl<'a> From<${name}Borrowed<'a>> for $name {
+                #[inline]
+                fn from(${name}Borrowed { $($destructure,) }: ${name}Borrowed<'a>) -> Self {
+                    Self {
+                        $($from_own_assign,)
+                    }
+                }
             }
         );
+    };
+}
 
-        if !is_copy {
-            let fields_name = fields.iter().map(|p| &p.ident.rs);
-            let fields_ty = fields.iter().map(|p| p.brw_ty(true, ctx));
-            let from_own_assign = fields.iter().map(|f| f.owning_assign());
-            code!(w =>
-                pub struct ${name}Borrowed<'a> {
-                    $(pub $fields_name : $fields_ty,)
+impl RowMember<'_> {
+    fn name(&self) -> &str {
+        match self {
+            RowMember::Column(field, _) => &field.ident.rs,
+            RowMember::Group(group) => &group.field.rs,
+        }
+    }
+
+    fn doc(&self) -> String {
+        match self {
+            RowMember::Column(field, _) => field.doc(),
+            RowMember::Group(group) => format!("/// Columns prefixed by `{}_`\n", group.field.db),
+        }
+    }
+
+    fn serde_attrs(&self, ctx: &GenCtx) -> String {
+        match self {
+            RowMember::Column(field, _) => field.serde_attrs(ctx),
+            RowMember::Group(_) => String::new(),
+        }
+    }
+
+    /// Sqlx attribute matching the column of this field, groups being flattened in their row
+    fn sqlx_attr(&self, prefix: &str, ctx: &GenCtx) -> String {
+        match self {
+            RowMember::Column(field, _) if prefix.is_empty() => field.sqlx_rename(ctx),
+            RowMember::Column(field, _) if ctx.settings.derive_sqlx => format!(
+                "#[sqlx(rename = \"{}{}\")]",
+                prefix.escape_default(),
+                field.ident.db.escape_default()
+            ),
+            RowMember::Group(_) if ctx.settings.derive_sqlx => "#[sqlx(flatten)]".to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn own_ty(&self, ctx: &GenCtx) -> String {
+        match self {
+            RowMember::Column(field, _) => field.own_struct(ctx),
+            RowMember::Group(group) => group.item.path(ctx),
+        }
+    }
+
+    fn brw_ty(&self, ctx: &GenCtx) -> String {
+        match self {
+            RowMember::Column(field, _) => field.brw_ty(true, ctx),
+            RowMember::Group(group) if group.item.is_copy => group.item.path(ctx),
+            RowMember::Group(group) => format!("{}Borrowed<'a>", group.item.path(ctx)),
+        }
+    }
+
+    fn owning_assign(&self) -> String {
+        match self {
+            RowMember::Column(field, _) => field.owning_assign(),
+            RowMember::Group(group) => format!("{0}: {0}.into()", group.field.rs),
+        }
+    }
+
+    fn cow_ty(&self, ctx: &GenCtx) -> String {
+        match self {
+            RowMember::Column(field, _) => field.cow_struct(ctx),
+            RowMember::Group(group) if group.item.is_cow() => {
+                format!("{}<'a>", group.item.path(ctx))
+            }
+            RowMember::Group(group) => group.item.path(ctx),
+        }
+    }
+
+    fn cow_owning_assign(&self) -> String {
+        match self {
+            RowMember::Column(field, _) => field.cow_owning_assign(),
+            RowMember::Group(group) if group.item.is_cow() => {
+                format!("{0}: {0}.into_owned()", group.field.rs)
+            }
+            RowMember::Group(group) => group.field.rs.clone(),
+        }
+    }
+
+    /// Code extracting this field from a row, each column being extracted by `extract`
+    /// from its index among the row's fields
+    fn extract(&self, post: &str, ctx: &GenCtx, extract: impl Fn(usize) -> String) -> String {
+        match self {
+            RowMember::Column(_, idx) => extract(*idx),
+            RowMember::Group(group) => {
+                let fields = group
+                    .item
+                    .fields
+                    .iter()
+                    .zip(&group.columns)
+                    .map(|(field, idx)| format!("{}: {},", field.ident.rs, extract(*idx)))
+                    .collect::<String>();
+                format!("{}{post} {{ {fields} }}", group.item.path(ctx))
+            }
+        }
+    }
+}
+
+/// `Serialize` derive and attribute of a borrowed struct, `None` when one of its
+/// fields cannot be serialized borrowed
+fn brw_ser(fields: &[PreparedField], ctx: &GenCtx) -> Option<(&'static str, String)> {
+    fields
+        .iter()
+        .all(|p| p.ty.is_brw_ser())
+        .then(|| (ctx.ser_str(), ctx.ser_attr()))
+}
+
+/// Generates an enum identifying the columns of a row, giving their name in SQL
+fn gen_column_enum(w: &mut impl Write, row: &PreparedItem) {
+    let PreparedItem { name, fields, .. } = row;
+    let variants = fields
+        .iter()
+        .map(|p| p.ident.rs.trim_start_matches("r#").to_upper_camel_case())
+        .collect::<Vec<_>>();
+    let enum_variants = variants.iter();
+    let all_variants = variants.iter();
+    let match_variants = variants.iter();
+    let columns = fields.iter().map(|p| format!("{:?}", p.ident.db));
+    let nb_columns = fields.len();
+    code!(w =>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ${name}Column {
+            $($enum_variants,)
+        }
+        impl ${name}Column {
+            pub const ALL: [Self; $nb_columns] = [$(Self::$all_variants,)];
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$match_variants => $columns,)
                 }
-                impl<'a> From<${name}Borrowed<'a>> for $name {
-                    fn from(${name}Borrowed { $($fields_name,) }: ${name}Borrowed<'a>) -> Self {
-                        Self {
-                            $($from_own_assign,)
-                        }
+            }
+        }
+    );
+}
+
+/// Generates a single row struct borrowing its text fields using `Cow`
+fn gen_cow_row_struct(w: &mut impl Write, row: &PreparedItem, ctx: &GenCtx) {
+    let PreparedItem {
+        name, attributes, ..
+    } = row;
+    let members = row.members();
+    let fields_name = members.iter().map(|m| m.name());
+    let fields_ty = members.iter().map(|m| m.cow_ty(ctx));
+    let fields_serde = members.iter().map(|m| m.serde_attrs(ctx));
+    let fields_doc = members.iter().map(|m| m.doc());
+    let attributes = attributes.iter();
+    let hash_str = ctx.hash_str(row);
+    let ser_str = ctx.ser_str();
+    let ser_attr = ctx.ser_attr();
+    if row.is_cow() {
+        let destructure = members.iter().map(|m| m.name());
+        let owning_assign = members.iter().map(|m| m.cow_owning_assign());
+        code!(w =>
+            $ser_attr
+            #[derive($ser_str Debug, Clone, PartialEq, $hash_str)]
+            $(#[$attributes])
+            pub struct $name<'a> {
+                $($fields_doc $fields_serde pub $fields_name : $fields_ty,)
+            }
+            impl<'a> $name<'a> {
+                #[inline]
+                pub fn into_owned(self) -> $name<'static> {
+                    let $name { $($destructure,) } = self;
+                    $name {
+                        $($owning_assign,)
                     }
                 }
-            );
-        };
+            }
+        );
+    } else {
+        code!(w =>
+            $ser_attr
+            #[derive($ser_str Debug, Clone, PartialEq, $hash_str)]
+            $(#[$attributes])
+            pub struct $name {
+                $($fields_doc $fields_serde pub $fields_name : $fields_ty,)
+            }
+        );
     }
 }
 
@@ -387,33 +879,35 @@ fn gen_row_query(w: &mut impl Write, row: &PreparedItem, ctx: &GenCtx) {
         ..
     } = row;
     // Generate query struct
-    let borrowed_str = if *is_copy { "" } else { "Borrowed" };
-    let (client_mut, fn_async, fn_await, backend, collect, raw_type, raw_pre, raw_post, client) =
-        if ctx.is_async {
-            (
-                "",
-                "async",
-                ".await",
-                "tokio_postgres",
-                "try_collect().await",
-                "futures::Stream",
-                "",
-                ".into_stream()",
-                "cornucopia_async",
-            )
-        } else {
-            (
-                "mut",
-                "",
-                "",
-                "postgres",
-                "collect()",
-                "Iterator",
-                ".iterator()",
-                "",
-                "cornucopia_sync",
-            )
-        };
+    let borrowed_str = if *is_copy || ctx.settings.gen_cow {
+        ""
+    } else {
+        "Borrowed"
+    };
+    let (client_mut, fn_async, fn_await, backend, push, raw_type, client) = if ctx.is_async {
+        (
+            "",
+            "async",
+            ".await",
+            "tokio_postgres",
+            "vec.push(it); futures::future::ready(Ok(vec))",
+            "futures::Stream",
+            "cornucopia_async",
+        )
+    } else {
+        (
+            "mut",
+            "",
+            "",
+            "postgres",
+            "vec.push(it?); Ok(vec)",
+            "Iterator",
+            "cornucopia_sync",
+        )
+    };
+
+    // Async streams can be moved to another task
+    let raw_send = if ctx.is_async { "+ Send" } else { "" };
 
     let row_struct = if *is_named {
         format!("{}{borrowed_str}", row.path(ctx))
@@ -421,15 +915,125 @@ fn gen_row_query(w: &mut impl Write, row: &PreparedItem, ctx: &GenCtx) {
         fields[0].brw_ty(false, ctx)
     };
 
+    let err_ty = ctx.error_ty();
+    let generic_client = ctx.generic_client();
+    let map_err = ctx.map_err("self.query");
+    let (query_field, query_copy) = if ctx.settings.gen_query_errors {
+        ("query: &'static str,", "query: self.query,")
+    } else {
+        ("", "")
+    };
+
+    // Extension trait methods are called by their full path when they are not imported
+    let it = format!("self.iter(){fn_await}?");
+    let fold = format!("Vec::with_capacity(capacity), |mut vec, it| {{ {push} }}");
+    let raw = format!(
+        "self.client.query_raw(stmt, {client}::private::slice_iter(&self.params)){fn_await}{map_err}?"
+    );
+    let map = format!("move |res| res.map(|row| (self.mapper)((self.extractor)(&row))){map_err}");
+    let (collect, rows) = match (ctx.settings.qualified_paths, ctx.is_async) {
+        (true, true) => (
+            format!("::futures::TryStreamExt::try_fold({it}, {fold}).await"),
+            format!(
+                "::futures::TryStreamExt::into_stream(::futures::StreamExt::map({raw}, {map}))"
+            ),
+        ),
+        (true, false) => (
+            format!("{it}.try_fold({fold})"),
+            format!("::postgres::fallible_iterator::FallibleIterator::iterator({raw}).map({map})"),
+        ),
+        (false, true) => (
+            format!("{it}.try_fold({fold}).await"),
+            format!("{raw}.map({map}).into_stream()"),
+        ),
+        (false, false) => (
+            format!("{it}.try_fold({fold})"),
+            format!("{raw}.iterator().map({map})"),
+        ),
+    };
+
+    // Async streams can be given a nameable type, at the cost of a boxed allocation
+    let named_stream = ctx.is_async && ctx.settings.named_streams;
+    let (iter_ty, rows) = if named_stream {
+        (
+            format!("{name}Stream<T>"),
+            format!(
+                "{name}Stream {{ inner: Box::pin({raw}), extractor: self.extractor, mapper: self.mapper, {query_copy} }}"
+            ),
+        )
+    } else {
+        (
+            format!("impl {raw_type}<Item = Result<T, {err_ty}>> {raw_send} + 'a"),
+            rows,
+        )
+    };
+    let stream_impl = |w: &mut dyn Write| {
+        if named_stream {
+            let map_err = ctx.map_err("this.query");
+            code!(w =>
+                pub struct ${name}Stream<T> {
+                    inner: std::pin::Pin<Box<tokio_postgres::RowStream>>,
+                    extractor: fn(&tokio_postgres::Row) -> $row_struct,
+                    mapper: fn($row_struct) -> T,
+                    $query_field
+                }
+                impl<T> futures::Stream for ${name}Stream<T> {
+                    type Item = Result<T, $err_ty>;
+
+                    fn poll_next(
+                        self: std::pin::Pin<&mut Self>,
+                        cx: &mut std::task::Context<'_>,
+                    ) -> std::task::Poll<Option<Self::Item>> {
+                        let this = self.get_mut();
+                        futures::Stream::poll_next(this.inner.as_mut(), cx).map(|it| {
+                            it.map(|res| res.map(|row| (this.mapper)((this.extractor)(&row)))$map_err)
+                        })
+                    }
+                }
+            );
+        }
+    };
+
+    // Named rows with a single field can also return it directly
+    let scalar_impl = |w: &mut dyn Write| {
+        if let (true, [field]) = (*is_named, fields.as_slice()) {
+            let scalar_ty = field.own_struct(ctx);
+            let brw_ty = field.brw_ty(false, ctx);
+            let owning = field.owning_call(Some("scalar"));
+            code!(w =>
+                pub $fn_async fn one_scalar(self) -> Result<$scalar_ty, $err_ty> {
+                    let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
+                    let row = self.client.query_one(stmt, &self.params)$fn_await$map_err?;
+                    let scalar = row.get::<_, $brw_ty>(0);
+                    Ok($owning)
+                }
+
+                pub $fn_async fn opt_scalar(self) -> Result<Option<$scalar_ty>, $err_ty> {
+                    let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
+                    Ok(self
+                        .client
+                        .query_opt(stmt, &self.params)
+                        $fn_await$map_err?
+                        .map(|row| {
+                            let scalar = row.get::<_, $brw_ty>(0);
+                            $owning
+                        }))
+                }
+            );
+        }
+    };
+
     code!(w =>
-    pub struct ${name}Query<'a, C: GenericClient, T, const N: usize> {
+    pub struct ${name}Query<'a, C: $generic_client, T, const N: usize> {
         client: &'a $client_mut C,
         params: [&'a (dyn postgres_types::ToSql + Sync); N],
         stmt: &'a mut $client::private::Stmt,
         extractor: fn(&$backend::Row) -> $row_struct,
         mapper: fn($row_struct) -> T,
+        capacity: usize,
+        $query_field
     }
-    impl<'a, C, T:'a, const N: usize> ${name}Query<'a, C, T, N> where C: GenericClient {
+    impl<'a, C, T:'a, const N: usize> ${name}Query<'a, C, T, N> where C: $generic_client {
         pub fn map<R>(self, mapper: fn($row_struct) -> R) -> ${name}Query<'a,C,R,N> {
             ${name}Query {
                 client: self.client,
@@ -437,42 +1041,201 @@ fn gen_row_query(w: &mut impl Write, row: &PreparedItem, ctx: &GenCtx) {
                 stmt: self.stmt,
                 extractor: self.extractor,
                 mapper,
+                capacity: self.capacity,
+                $query_copy
             }
         }
 
-        pub $fn_async fn one(self) -> Result<T, $backend::Error> {
-            let stmt = self.stmt.prepare(self.client)$fn_await?;
-            let row = self.client.query_one(stmt, &self.params)$fn_await?;
+        pub $fn_async fn one(self) -> Result<T, $err_ty> {
+            let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
+            let row = self.client.query_one(stmt, &self.params)$fn_await$map_err?;
             Ok((self.mapper)((self.extractor)(&row)))
         }
 
-        pub $fn_async fn all(self) -> Result<Vec<T>, $backend::Error> {
-            self.iter()$fn_await?.$collect
+        pub $fn_async fn all(self) -> Result<Vec<T>, $err_ty> {
+            let capacity = self.capacity;
+            self.vec_with_capacity(capacity)$fn_await
+        }
+
+        pub $fn_async fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, $err_ty> {
+            $collect
         }
 
-        pub $fn_async fn opt(self) -> Result<Option<T>, $backend::Error> {
-            let stmt = self.stmt.prepare(self.client)$fn_await?;
+        pub $fn_async fn opt(self) -> Result<Option<T>, $err_ty> {
+            let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
             Ok(self
                 .client
                 .query_opt(stmt, &self.params)
-                $fn_await?
+                $fn_await$map_err?
                 .map(|row| (self.mapper)((self.extractor)(&row))))
         }
 
+        pub $fn_async fn execute(self) -> Result<u64, $err_ty> {
+            let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
+            self.client.execute(stmt, &self.params)$fn_await$map_err
+        }
+
         pub $fn_async fn iter(
             self,
-        ) -> Result<impl $raw_type<Item = Result<T, $backend::Error>> + 'a, $backend::Error> {
-            let stmt = self.stmt.prepare(self.client)$fn_await?;
-            let it = self
-                .client
-                .query_raw(stmt, $client::private::slice_iter(&self.params))
-                $fn_await?
-                $raw_pre
-                .map(move |res| res.map(|row| (self.mapper)((self.extractor)(&row))))
-                $raw_post;
+        ) -> Result<$iter_ty, $err_ty> {
+            let stmt = self.stmt.prepare(self.client)$fn_await$map_err?;
+            let it = $rows;
             Ok(it)
         }
+
+        $!scalar_impl
+    }
+    $!stream_impl);
+}
+
+/// Generates an in-memory cache of all the rows of a query, indexed by its key column
+fn gen_query_cache(
+    w: &mut impl Write,
+    module: &PreparedModule,
+    query: &PreparedQuery,
+    ctx: &GenCtx,
+) {
+    let (key, (row_idx, _)) = match (&query.cache, &query.row) {
+        (Some(key), Some(row)) => (key, row),
+        _ => return,
+    };
+    let row = module.rows.get_index(*row_idx).unwrap().1;
+    let is_cow = ctx.settings.gen_cow && row.is_named && row.is_cow();
+    let (row_ty, key_ty, key_expr) = if row.is_named {
+        let idx = row.fields.iter().position(|f| &f.ident.db == key).unwrap();
+        let field = &row.fields[idx];
+        let (row_ty, key_ty) = if is_cow {
+            (
+                format!("{}<'static>", row.path(ctx)),
+                field.cow_struct(ctx).replace("'a", "'static"),
+            )
+        } else {
+            (row.path(ctx), field.own_struct(ctx))
+        };
+        (row_ty, key_ty, format!("{}.clone()", row.field_access(idx)))
+    } else {
+        let ty = row.fields[0].own_struct(ctx);
+        (ty.clone(), ty, "row.clone()".to_string())
+    };
+    let (client_mut, fn_async, fn_await) = if ctx.is_async {
+        ("", "async", ".await")
+    } else {
+        ("mut", "", "")
+    };
+    let err_ty = ctx.error_ty();
+    let generic_client = ctx.generic_client();
+    let name = &query.ident.rs;
+    let struct_name = query.ident.type_ident();
+    code!(w =>
+        #[derive(Debug, Clone)]
+        pub struct ${struct_name}Cache {
+            rows: std::sync::Arc<[$row_ty]>,
+            index: std::collections::HashMap<$key_ty, usize>,
+        }
+        impl ${struct_name}Cache {
+            pub $fn_async fn load_all<C: $generic_client>(client: &$client_mut C) -> Result<Self, $err_ty> {
+                let rows: std::sync::Arc<[$row_ty]> = $name().bind(client).all()$fn_await?.into();
+                let index = rows
+                    .iter()
+                    .enumerate()
+                    .map(|(i, row)| ($key_expr, i))
+                    .collect();
+                Ok(Self { rows, index })
+            }
+            pub fn rows(&self) -> &std::sync::Arc<[$row_ty]> {
+                &self.rows
+            }
+            pub fn get<Q>(&self, key: &Q) -> Option<&$row_ty>
+            where
+                $key_ty: std::borrow::Borrow<Q>,
+                Q: std::hash::Hash + Eq + ?Sized,
+            {
+                self.index.get(key).map(|i| &self.rows[*i])
+            }
+        }
+    );
+}
+
+/// Generates a struct holding the statements of all the queries of a module
+fn gen_statements(w: &mut impl Write, module: &PreparedModule, ctx: &GenCtx) {
+    if module.queries.is_empty() {
+        return;
+    }
+    let queries = || module.queries.values().map(|query| &query.ident);
+    let fields_name = queries().map(|ident| &ident.rs);
+    let fields_ty = queries().map(|ident| format!("{}Stmt", ident.type_ident()));
+    let init_name = queries().map(|ident| &ident.rs);
+    let init_fn = queries().map(|ident| &ident.rs);
+    let (client_mut, fn_async, fn_await) = if ctx.is_async {
+        ("", "async", ".await")
+    } else {
+        ("mut", "", "")
+    };
+    let prepare = queries().map(|ident| {
+        let map_err = ctx.map_err(&format!("\"{}\"", ident.rs));
+        format!("self.{}.0.prepare(client){fn_await}{map_err}?;", ident.rs)
     });
+    let err_ty = ctx.error_ty();
+    let generic_client = ctx.generic_client();
+    code!(w =>
+        pub struct Statements {
+            $(pub $fields_name: $fields_ty,)
+        }
+        impl Statements {
+            pub fn new() -> Self {
+                Self {
+                    $($init_name: $init_fn(),)
+                }
+            }
+            pub $fn_async fn prepare_all<C: $generic_client>(&mut self, client: &$client_mut C) -> Result<(), $err_ty> {
+                $($prepare)
+                Ok(())
+            }
+        }
+        impl Default for Statements {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    );
+}
+
+/// Generates an error type wrapping driver errors with the name of the failed query
+fn gen_query_error(w: &mut impl Write, ctx: &GenCtx) {
+    if !ctx.settings.gen_query_errors {
+        return;
+    }
+    let backend = if ctx.is_async {
+        "tokio_postgres"
+    } else {
+        "postgres"
+    };
+    code!(w =>
+        #[derive(Debug)]
+        pub enum Error {
+            Query {
+                query: &'static str,
+                source: $backend::Error,
+            },
+        }
+        impl std::fmt::Display for Error {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Error::Query { query, .. } => write!(f, "query `{query}` failed"),
+                }
+            }
+        }
+        impl std::error::Error for Error {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    Error::Query { source, .. } => Some(source),
+                }
+            }
+        }
+        fn query_err(query: &'static str) -> impl Fn($backend::Error) -> Error {
+            move |source| Error::Query { query, source }
+        }
+    );
 }
 
 pub fn idx_char(idx: usize) -> String {
@@ -485,13 +1248,16 @@ fn gen_query_fn<W: Write>(w: &mut W, module: &PreparedModule, query: &PreparedQu
         row,
         sql,
         param,
+        ..
     } = query;
 
-    let (client_mut, fn_async, fn_await, backend, client) = if ctx.is_async {
-        ("", "async", ".await", "tokio_postgres", "cornucopia_async")
+    let (client_mut, fn_async, fn_await, client, backend) = if ctx.is_async {
+        ("", "async", ".await", "cornucopia_async", "tokio_postgres")
     } else {
-        ("mut", "", "", "postgres", "cornucopia_sync")
+        ("mut", "", "", "cornucopia_sync", "postgres")
     };
+    let err_ty = ctx.error_ty();
+    let generic_client = ctx.generic_client();
 
     let struct_name = ident.type_ident();
     let (param, param_field, order) = match param {
@@ -523,52 +1289,126 @@ fn gen_query_fn<W: Write>(w: &mut W, module: &PreparedModule, query: &PreparedQu
 
             // TODO find a way to clean this mess
             #[allow(clippy::type_complexity)]
-            let (row_struct_name, extractor, mapper): (_, Box<dyn Fn(&mut W)>, _) = if *is_named {
-                let path = item.path(ctx);
-                (
-                    path.clone(),
-                    Box::new(|w: _| {
-                        let path = item.path(ctx);
-                        let post = if *is_copy { "" } else { "Borrowed" };
-                        let fields_name = fields.iter().map(|p| &p.ident.rs);
-                        let fields_idx = (0..fields.len()).map(|i| index[i]);
-                        code!(w => $path$post {
-                            $($fields_name: row.get($fields_idx),)
-                        })
-                    }),
-                    code!(<$path>::from(it)),
-                )
+            let (row_struct_name, extractor, mapper): (_, Box<dyn Fn(&mut W)>, _) =
+                if *is_named && ctx.settings.gen_cow && !*is_copy {
+                    let path = item.path(ctx);
+                    let (row_struct_name, mapper) = if item.is_cow() {
+                        (format!("{path}<'static>"), "it.into_owned()".to_string())
+                    } else {
+                        (path.clone(), "it".to_string())
+                    };
+                    (
+                        row_struct_name,
+                        Box::new(|w: _| {
+                            let path = item.path(ctx);
+                            let members = item.members();
+                            let fields_name = members.iter().map(|m| m.name());
+                            let fields_extract = members.iter().map(|m| {
+                                m.extract("", ctx, |i| fields[i].cow_extract(index[i], ctx))
+                            });
+                            code!(w => $path {
+                                $($fields_name: $fields_extract,)
+                            })
+                        }),
+                        mapper,
+                    )
+                } else if *is_named {
+                    let path = item.path(ctx);
+                    (
+                        path.clone(),
+                        Box::new(|w: _| {
+                            let path = item.path(ctx);
+                            let post = if *is_copy { "" } else { "Borrowed" };
+                            let members = item.members();
+                            let fields_name = members.iter().map(|m| m.name());
+                            let fields_extract = members.iter().map(|m| {
+                                let group_post = match m {
+                                    RowMember::Group(group) if !group.item.is_copy => "Borrowed",
+                                    _ => "",
+                                };
+                                m.extract(group_post, ctx, |i| {
+                                    format!("row.get({})", fields[i].row_index(index[i], ctx))
+                                })
+                            });
+                            code!(w => $path$post {
+                                $($fields_name: $fields_extract,)
+                            })
+                        }),
+                        code!(<$path>::from(it)),
+                    )
+                } else {
+                    let field = &fields[0];
+                    (
+                        field.own_struct(ctx),
+                        Box::new(|w: _| {
+                            let idx = field.row_index(0, ctx);
+                            code!(w => row.get($idx))
+                        }),
+                        field.owning_call(Some("it")),
+                    )
+                };
+            let query_name = if ctx.settings.gen_query_errors {
+                format!("query: \"{}\",", ident.rs)
             } else {
-                let field = &fields[0];
-                (
-                    field.own_struct(ctx),
-                    Box::new(|w: _| code!(w => row.get(0))),
-                    field.owning_call(Some("it")),
-                )
+                String::new()
             };
+            // Pre-size collected rows using the query limit, a mere upper bound
+            let capacity = literal_limit(sql).map_or(0, |limit| limit.min(MAX_LIMIT_CAPACITY));
             code!(w =>
-                pub fn bind<'a, C: GenericClient,$($traits_idx: $traits,)>(&'a mut self, client: &'a $client_mut C, $($params_name: &'a $params_ty,) ) -> ${row_name}Query<'a,C, $row_struct_name, $nb_params> {
+                pub fn bind<'a, C: $generic_client,$($traits_idx: $traits,)>(&'a mut self, client: &'a $client_mut C, $($params_name: &'a $params_ty,) ) -> ${row_name}Query<'a,C, $row_struct_name, $nb_params> {
                     ${row_name}Query {
                         client,
                         params: [$($params_name,)],
                         stmt: &mut self.0,
                         extractor: |row| { $!extractor },
                         mapper: |it| { $mapper },
+                        capacity: $capacity,
+                        $query_name
                     }
                 }
             );
+            if ctx.settings.dyn_params {
+                code!(w =>
+                    /// Binds parameters given as a slice, failing if its length differs from the query's
+                    pub fn bind_dyn<'a, C: $generic_client>(&'a mut self, client: &'a $client_mut C, params: &[&'a (dyn postgres_types::ToSql + Sync)]) -> Result<${row_name}Query<'a,C, $row_struct_name, $nb_params>, $client::ArityError> {
+                        let params = <[_; $nb_params]>::try_from(params).map_err(|_| $client::ArityError {
+                            expected: $nb_params,
+                            got: params.len(),
+                        })?;
+                        Ok(${row_name}Query {
+                            client,
+                            params,
+                            stmt: &mut self.0,
+                            extractor: |row| { $!extractor },
+                            mapper: |it| { $mapper },
+                            capacity: $capacity,
+                            $query_name
+                        })
+                    }
+                );
+            }
         } else {
             // Execute fn
             let params_wrap = order.iter().map(|idx| {
                 let p = &param_field[*idx];
                 p.ty.sql_wrapped(&p.ident.rs, ctx)
             });
+            let map_err = ctx.map_err(&format!("\"{}\"", ident.rs));
             code!(w =>
-                pub $fn_async fn bind<'a, C: GenericClient,$($traits_idx: $traits,)>(&'a mut self, client: &'a $client_mut C, $($params_name: &'a $params_ty,)) -> Result<u64, $backend::Error> {
-                    let stmt = self.0.prepare(client)$fn_await?;
-                    client.execute(stmt, &[ $($params_wrap,) ])$fn_await
+                pub $fn_async fn bind<'a, C: $generic_client,$($traits_idx: $traits,)>(&'a mut self, client: &'a $client_mut C, $($params_name: &'a $params_ty,)) -> Result<u64, $err_ty> {
+                    let stmt = self.0.prepare(client)$fn_await$map_err?;
+                    client.execute(stmt, &[ $($params_wrap,) ])$fn_await$map_err
                 }
             );
+            if ctx.settings.dyn_params {
+                code!(w =>
+                    /// Binds parameters given as a slice, the driver rejecting it if its length differs from the query's
+                    pub $fn_async fn bind_dyn<'a, C: $generic_client>(&'a mut self, client: &'a $client_mut C, params: &[&'a (dyn postgres_types::ToSql + Sync)]) -> Result<u64, $err_ty> {
+                        let stmt = self.0.prepare(client)$fn_await$map_err?;
+                        client.execute(stmt, params)$fn_await$map_err
+                    }
+                );
+            }
         }
     };
     // Gen statement struct
@@ -581,6 +1421,10 @@ fn gen_query_fn<W: Write>(w: &mut W, module: &PreparedModule, query: &PreparedQu
             }
             pub struct ${struct_name}Stmt($client::private::Stmt);
             impl ${struct_name}Stmt {
+                pub fn statement(&self) -> Option<&$backend::Statement> {
+                    self.0.statement()
+                }
+
                 $!lazy_impl
             }
         );
@@ -598,14 +1442,18 @@ fn gen_query_fn<W: Write>(w: &mut W, module: &PreparedModule, query: &PreparedQu
             if let Some((idx, _)) = row {
                 let prepared_row = &module.rows.get_index(*idx).unwrap().1;
                 let query_row_struct = if prepared_row.is_named {
-                    prepared_row.path(ctx)
+                    if ctx.settings.gen_cow && prepared_row.is_cow() {
+                        format!("{}<'static>", prepared_row.path(ctx))
+                    } else {
+                        prepared_row.path(ctx)
+                    }
                 } else {
                     prepared_row.fields[0].own_struct(ctx)
                 };
                 let name = &module.rows.get_index(*idx).unwrap().1.name;
                 let nb_params = param_field.len();
                 code!(w =>
-                    impl <'a, C: GenericClient,$($traits_idx: $traits,)> $client::Params<'a, $param_path<$lifetime $($traits_idx,)>, ${name}Query<'a, C, $query_row_struct, $nb_params>, C> for ${struct_name}Stmt {
+                    impl <'a, C: $generic_client,$($traits_idx: $traits,)> $client::Params<'a, $param_path<$lifetime $($traits_idx,)>, ${name}Query<'a, C, $query_row_struct, $nb_params>, C> for ${struct_name}Stmt {
                         fn params(&'a mut self, client: &'a $client_mut C, params: &'a $param_path<$lifetime $($traits_idx,)>) -> ${name}Query<'a, C, $query_row_struct, $nb_params> {
                             self.bind(client, $(&params.$params_name,))
                         }
@@ -624,8 +1472,8 @@ fn gen_query_fn<W: Write>(w: &mut W, module: &PreparedModule, query: &PreparedQu
                     ("", "Result", "", "self", "")
                 };
                 code!(w =>
-                    impl <'a, C: GenericClient $send_sync, $($traits_idx: $traits,)> $client::Params<'a, $param_path<$lifetime $($traits_idx,)>, $pre_ty<u64, $backend::Error>$post_ty_lf, C> for ${struct_name}Stmt {
-                        fn params(&'a mut self, client: &'a $client_mut C, params: &'a $param_path<$lifetime $($traits_idx,)>) -> $pre_ty<u64, $backend::Error>$post_ty_lf {
+                    impl <'a, C: $generic_client $send_sync, $($traits_idx: $traits,)> $client::Params<'a, $param_path<$lifetime $($traits_idx,)>, $pre_ty<u64, $err_ty>$post_ty_lf, C> for ${struct_name}Stmt {
+                        fn params(&'a mut self, client: &'a $client_mut C, params: &'a $param_path<$lifetime $($traits_idx,)>) -> $pre_ty<u64, $err_ty>$post_ty_lf {
                             $pre.bind(client, $(&params.$params_name,))$post
                         }
                     }
@@ -646,16 +1494,25 @@ fn gen_custom_type(w: &mut impl Write, schema: &str, prepared: &PreparedType, ct
         name,
     } = prepared;
     let copy = if *is_copy { "Copy," } else { "" };
-    let ser_str = if ctx.gen_derive {
-        "serde::Serialize,"
-    } else {
-        ""
-    };
+    let ser_str = ctx.ser_str();
+    let ser_attr = ctx.ser_attr();
     match content {
         PreparedContent::Enum(variants) => {
             let variants_ident = variants.iter().map(|v| &v.rs);
+            // Variants are declared in the database order, which derived `Ord` follows
+            let ord_str = if ctx.settings.enum_ord {
+                "PartialOrd, Ord,"
+            } else {
+                ""
+            };
+            let hash_str = if ctx.settings.derive_hash {
+                "Hash,"
+            } else {
+                ""
+            };
             code!(w =>
-                #[derive($ser_str Debug, Clone, Copy, PartialEq, Eq)]
+                $ser_attr
+                #[derive($ser_str Debug, Clone, Copy, PartialEq, Eq, $ord_str $hash_str)]
                 #[allow(non_camel_case_types)]
                 pub enum $struct_name {
                     $($variants_ident,)
@@ -667,13 +1524,16 @@ fn gen_custom_type(w: &mut impl Write, schema: &str, prepared: &PreparedType, ct
             let fields_original_name = fields.iter().map(|p| &p.ident.db);
             let fields_name = fields.iter().map(|p| &p.ident.rs);
             {
-                let fields_ty = fields.iter().map(|p| p.own_struct(ctx));
+                let fields_ty = fields.iter().map(|p| qualify_prelude(&p.own_struct(ctx)));
+                let fields_serde = fields.iter().map(|p| p.serde_attrs(ctx));
                 code!(w =>
+                    $ser_attr
                     #[derive($ser_str Debug,postgres_types::FromSql,$copy Clone, PartialEq)]
                     #[postgres(name = "$name")]
                     pub struct $struct_name {
                         $(
                             #[postgres(name = "$fields_original_name")]
+                            $fields_serde
                             pub $fields_name: $fields_ty,
                         )
                     }
@@ -683,13 +1543,25 @@ fn gen_custom_type(w: &mut impl Write, schema: &str, prepared: &PreparedType, ct
                 struct_tosql(w, struct_name, fields, name, false, *is_params, ctx);
             } else {
                 let fields_owning = fields.iter().map(|p| p.owning_assign());
-                let fields_brw = fields.iter().map(|p| p.brw_ty(true, ctx));
+                let fields_brw = fields.iter().map(|p| qualify_prelude(&p.brw_ty(true, ctx)));
+                let brw_ser = brw_ser(fields, ctx);
+                let is_brw_ser = brw_ser.is_some();
+                let fields_serde = fields.iter().map(|p| {
+                    if is_brw_ser {
+                        p.serde_attrs(ctx)
+                    } else {
+                        String::new()
+                    }
+                });
+                let (brw_ser_str, brw_ser_attr) = brw_ser.unwrap_or_default();
                 code!(w =>
-                    #[derive(Debug)]
+                    $brw_ser_attr
+                    #[derive($brw_ser_str Debug)]
                     pub struct ${struct_name}Borrowed<'a> {
-                        $(pub $fields_name: $fields_brw,)
+                        $($fields_serde pub $fields_name: $fields_brw,)
                     }
-                    impl<'a> From<${struct_name}Borrowed<'a>> for $struct_name {
+                    impl<'a> std::convert::From<${struct_name}Borrowed<'a>> for $struct_name {
+                        #[inline]
                         fn from(
                             ${struct_name}Borrowed {
                             $($fields_name,)
@@ -701,9 +1573,9 @@ fn gen_custom_type(w: &mut impl Write, schema: &str, prepared: &PreparedType, ct
                         }
                     }
                 );
-                composite_fromsql(w, struct_name, fields, name, schema);
+                composite_fromsql(w, struct_name, fields, name, schema, ctx);
                 if !is_params {
-                    let fields_ty = fields.iter().map(|p| p.param_ty(ctx));
+                    let fields_ty = fields.iter().map(|p| qualify_prelude(&p.param_ty(ctx)));
                     let derive = if *is_copy { ",Copy,Clone" } else { "" };
                     code!(w =>
                         #[derive(Debug $derive)]
@@ -718,7 +1590,8 @@ fn gen_custom_type(w: &mut impl Write, schema: &str, prepared: &PreparedType, ct
     }
 }
 
-fn gen_type_modules<W: Write>(
+/// Generates a module per schema, holding its custom types
+fn gen_type_schemas<W: Write>(
     w: &mut W,
     prepared: &IndexMap<String, Vec<PreparedType>>,
     ctx: &GenCtx,
@@ -737,92 +1610,315 @@ fn gen_type_modules<W: Write>(
             });
         }
     });
+    code!(w => $($!modules));
+}
+
+fn gen_type_modules<W: Write>(
+    w: &mut W,
+    prepared: &IndexMap<String, Vec<PreparedType>>,
+    ctx: &GenCtx,
+) {
+    let schemas = |w: &mut W| gen_type_schemas(w, prepared, ctx);
     code!(w =>
         #[allow(clippy::all, clippy::pedantic)]
         #[allow(unused_variables)]
         #[allow(unused_imports)]
         #[allow(dead_code)]
         pub mod types {
-            $($!modules)
+            $!schemas
         }
     );
 }
 
-pub(crate) fn generate(preparation: Preparation, settings: CodegenSettings) -> String {
-    let mut buff = "// This file was generated with `cornucopia`. Do not modify.\n\n".to_string();
-    let w = &mut buff;
-    // Generate database type
-    gen_type_modules(
-        w,
-        &preparation.types,
-        &GenCtx::new(1, settings.gen_async, settings.derive_ser),
-    );
-    // Generate queries
-    let query_modules = preparation.modules.iter().map(|module| {
-        move |w: &mut String| {
-            let name = &module.info.name;
-            let ctx = GenCtx::new(2, settings.gen_async, settings.derive_ser);
-            let params_string = module
-                .params
-                .values()
-                .map(|params| |w: &mut String| gen_params_struct(w, params,  &ctx));
-            let rows_struct_string = module
-                .rows
+/// Names of the types a query module exposes
+fn module_types(module: &PreparedModule, settings: CodegenSettings) -> Vec<String> {
+    let mut types: Vec<String> = module.enums.iter().map(|e| e.name.value.clone()).collect();
+    for params in module.params.values().filter(|p| p.is_named) {
+        types.push(params.name.value.clone());
+    }
+    for row in module.rows.values().filter(|r| r.is_named) {
+        for item in row.groups.iter().map(|it| &it.item).chain([row]) {
+            types.push(item.name.value.clone());
+            if !item.is_copy && !settings.gen_cow {
+                types.push(format!("{}Borrowed", item.name.value));
+            }
+        }
+        if settings.column_enums {
+            types.push(format!("{}Column", row.name.value));
+        }
+    }
+    types
+}
+
+/// Generates a module re-exporting the types and query functions of every query module.
+/// Names defined by several modules are left out and stay accessible through their module.
+fn gen_prelude(w: &mut impl Write, modules: &[PreparedModule], settings: CodegenSettings) {
+    let items: Vec<(&PreparedModule, Vec<String>, Vec<String>)> = modules
+        .iter()
+        .map(|module| {
+            let fns = module
+                .queries
                 .values()
-                .map(|row| |w: &mut String| gen_row_structs(w, row,  &ctx));
-
-            let sync_specific = |w: &mut String| {
-                let gen_specific = |depth: u8, is_async: bool| {
-                    move |w: &mut String| {
-                        let ctx = GenCtx::new(depth, is_async, settings.derive_ser);
-                        let import = if is_async {
-                            "use futures::{StreamExt, TryStreamExt};use futures; use cornucopia_async::GenericClient;"
-                        } else {
-                            "use postgres::{fallible_iterator::FallibleIterator,GenericClient};"
+                .map(|q| q.ident.rs.clone())
+                .collect();
+            (module, module_types(module, settings), fns)
+        })
+        .collect();
+    let mut counts: IndexMap<&str, usize> = IndexMap::new();
+    for (_, types, fns) in &items {
+        for name in types.iter().chain(fns) {
+            *counts.entry(name).or_default() += 1;
+        }
+    }
+    let unique = |names: &[String]| -> Vec<String> {
+        names
+            .iter()
+            .filter(|name| counts[name.as_str()] == 1)
+            .cloned()
+            .collect()
+    };
+    let both = settings.gen_async && settings.gen_sync;
+    let mut reexports = String::new();
+    let mut sync_reexports = String::new();
+    let mut async_reexports = String::new();
+    for (module, types, fns) in &items {
+        let name = &module.info.name;
+        let attributes = module.attributes.iter();
+        let mut types = unique(types);
+        let fns = unique(fns);
+        if !both {
+            types.extend(fns.iter().cloned());
+        } else if !fns.is_empty() {
+            for (w, submodule) in [
+                (&mut sync_reexports, "sync"),
+                (&mut async_reexports, "async_"),
+            ] {
+                let attributes = module.attributes.iter();
+                let fns = fns.iter();
+                code!(w =>
+                    $(#[$attributes])
+                    pub use super::super::queries::$name::$submodule::{$($fns,)};
+                );
+            }
+        }
+        if !types.is_empty() {
+            let types = types.iter();
+            code!(reexports =>
+                $(#[$attributes])
+                pub use super::queries::$name::{$($types,)};
+            );
+        }
+    }
+    if both {
+        code!(reexports =>
+            pub mod sync {
+                $sync_reexports
+            }
+            pub mod async_ {
+                $async_reexports
+            }
+        );
+    }
+    code!(w =>
+        #[allow(clippy::all, clippy::pedantic)]
+        #[allow(unused_imports)]
+        pub mod prelude {
+            $reexports
+        }
+    );
+}
+
+fn gen_foreign_keys<W: Write>(w: &mut W, prepared: &IndexMap<String, Vec<PreparedForeignKey>>) {
+    let modules = prepared.iter().map(|(schema, foreign_keys)| {
+        move |w: &mut W| {
+            let lazy = |w: &mut W| {
+                for foreign_key in foreign_keys {
+                    let const_name = foreign_key.const_name();
+                    let PreparedForeignKey {
+                        name,
+                        table,
+                        columns,
+                        foreign_schema,
+                        foreign_table,
+                        foreign_columns,
+                    } = foreign_key;
+                    code!(w =>
+                        pub const $const_name: super::ForeignKey = super::ForeignKey {
+                            schema: "$schema",
+                            name: "$name",
+                            table: "$table",
+                            columns: &[$("$columns",)],
+                            foreign_schema: "$foreign_schema",
+                            foreign_table: "$foreign_table",
+                            foreign_columns: &[$("$foreign_columns",)],
                         };
-                        let rows_query_string = module
-                            .rows
-                            .values()
-                            .map(|row| |w: &mut String| gen_row_query(w, row, &ctx));
-                        let queries_string = module.queries.values().map(|query| {
-                            |w: &mut String| gen_query_fn(w, module, query, &ctx)
-                        });
-                        code!(w =>
-                            $import
-                            $($!rows_query_string)
-                            $($!queries_string)
-                        )
-                    }
-                };
+                    );
+                }
+            };
 
-                if settings.gen_async != settings.gen_sync {
-                    if settings.gen_async {
-                        let gen =  gen_specific(2, true);
-                        code!(w => $!gen)
-                    } else {
-                        let gen =  gen_specific(2, false);
-                        code!(w => $!gen)
+            code!(w =>
+            pub mod $schema {
+                $!lazy
+            });
+        }
+    });
+    code!(w =>
+        #[allow(clippy::all, clippy::pedantic)]
+        #[allow(dead_code)]
+        pub mod foreign_keys {
+            /// A foreign key constraint of the database schema
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct ForeignKey {
+                pub schema: &'static str,
+                pub name: &'static str,
+                pub table: &'static str,
+                pub columns: &'static [&'static str],
+                pub foreign_schema: &'static str,
+                pub foreign_table: &'static str,
+                pub foreign_columns: &'static [&'static str],
+            }
+            $($!modules)
+        }
+    );
+}
+
+/// Context of the custom types, generated in the `types` module
+fn types_ctx(settings: CodegenSettings) -> GenCtx {
+    GenCtx::new(
+        1,
+        CodegenSettings {
+            gen_query_errors: false,
+            ..settings
+        },
+    )
+}
+
+/// Generates the content of a query module
+fn gen_query_module(w: &mut String, module: &PreparedModule, settings: CodegenSettings) {
+    let ctx = GenCtx::new(
+        2,
+        CodegenSettings {
+            gen_query_errors: false,
+            ..settings
+        },
+    );
+    let enums_string = module
+        .enums
+        .iter()
+        .map(|int_enum| |w: &mut String| gen_int_enum(w, int_enum, &ctx));
+    let params_string = module
+        .params
+        .values()
+        .map(|params| |w: &mut String| gen_params_struct(w, params, &ctx));
+    let rows_struct_string = module
+        .rows
+        .values()
+        .map(|row| |w: &mut String| gen_row_structs(w, row, &ctx));
+
+    let sync_specific = |w: &mut String| {
+        let gen_specific = |depth: u8, is_async: bool| {
+            move |w: &mut String| {
+                let ctx = GenCtx::new(
+                    depth,
+                    CodegenSettings {
+                        gen_async: is_async,
+                        ..settings
+                    },
+                );
+                // Only import what the generated queries actually use
+                let import = match (is_async, module.queries.is_empty(), module.rows.is_empty()) {
+                    _ if settings.qualified_paths => "",
+                    (_, true, _) => "",
+                    (true, false, true) => "use cornucopia_async::GenericClient;",
+                    (true, false, false) => "use futures::{StreamExt, TryStreamExt}; use cornucopia_async::GenericClient;",
+                    (false, false, true) => "use postgres::GenericClient;",
+                    (false, false, false) => "use postgres::{fallible_iterator::FallibleIterator,GenericClient};",
+                };
+                let query_error = |w: &mut String| gen_query_error(w, &ctx);
+                let rows_query_string = module
+                    .rows
+                    .values()
+                    .map(|row| |w: &mut String| gen_row_query(w, row, &ctx));
+                let queries_string = module
+                    .queries
+                    .values()
+                    .map(|query| |w: &mut String| gen_query_fn(w, module, query, &ctx));
+                let caches_string = module
+                    .queries
+                    .values()
+                    .map(|query| |w: &mut String| gen_query_cache(w, module, query, &ctx));
+                let statements = |w: &mut String| {
+                    if settings.statements_struct {
+                        gen_statements(w, module, &ctx)
                     }
-                } else {
-                    let sync = gen_specific(3, false);
-                    let async_ = gen_specific(3, true);
-                    code!(w =>
-                        pub mod sync {
-                            $!sync
-                        }
-                        pub mod async_ {
-                            $!async_
-                        }
-                    )
+                };
+                code!(w =>
+                    $import
+                    $!query_error
+                    $($!rows_query_string)
+                    $($!queries_string)
+                    $($!caches_string)
+                    $!statements
+                )
+            }
+        };
 
+        if settings.gen_async != settings.gen_sync {
+            if settings.gen_async {
+                let gen = gen_specific(2, true);
+                code!(w => $!gen)
+            } else {
+                let gen = gen_specific(2, false);
+                code!(w => $!gen)
+            }
+        } else {
+            let sync = gen_specific(3, false);
+            let async_ = gen_specific(3, true);
+            code!(w =>
+                pub mod sync {
+                    $!sync
                 }
-            };
+                pub mod async_ {
+                    $!async_
+                }
+            )
+        }
+    };
+    code!(w =>
+        $($!enums_string)
+        $($!params_string)
+        $($!rows_struct_string)
+        $!sync_specific
+    );
+}
+
+/// Generates the metadata of the whole preparation, following its modules
+fn gen_metadata(w: &mut String, preparation: &Preparation, settings: CodegenSettings) {
+    // Generate a prelude re-exporting the queries
+    if settings.gen_prelude {
+        gen_prelude(w, &preparation.modules, settings);
+    }
+    // Generate foreign keys metadata
+    if settings.gen_foreign_keys {
+        gen_foreign_keys(w, &preparation.foreign_keys);
+    }
+}
 
+pub(crate) fn generate(preparation: &Preparation, settings: CodegenSettings) -> String {
+    let mut buff = HEADER.to_string();
+    let w = &mut buff;
+    // Generate database type
+    gen_type_modules(w, &preparation.types, &types_ctx(settings));
+    // Generate queries
+    let query_modules = preparation.modules.iter().map(|module| {
+        move |w: &mut String| {
+            let name = &module.info.name;
+            let attributes = module.attributes.iter();
+            let content = |w: &mut String| gen_query_module(w, module, settings);
             code!(w =>
+                $(#[$attributes])
                 pub mod $name {
-                    $($!params_string)
-                    $($!rows_struct_string)
-                    $!sync_specific
+                    $!content
                 }
             );
         }
@@ -836,5 +1932,53 @@ pub(crate) fn generate(preparation: Preparation, settings: CodegenSettings) -> S
             $($!query_modules)
         }
     );
+    gen_metadata(w, preparation, settings);
     buff
 }
+
+/// Same as [`generate`], but splits the code in one file per module, mirroring the module tree:
+/// `mod.rs`, `types.rs`, `queries/mod.rs` and a `queries/{module}.rs` per query module.
+/// Files are returned with their path relative to the generated directory.
+pub(crate) fn generate_split(
+    preparation: &Preparation,
+    settings: CodegenSettings,
+) -> Vec<(PathBuf, String)> {
+    let mut files = Vec::new();
+    // Generate the root module, declaring the others
+    let mut root = HEADER.to_string();
+    let w = &mut root;
+    code!(w =>
+        #[allow(clippy::all, clippy::pedantic)]
+        #[allow(unused_variables)]
+        #[allow(unused_imports)]
+        #[allow(dead_code)]
+        pub mod types;
+        #[allow(clippy::all, clippy::pedantic)]
+        #[allow(unused_variables)]
+        #[allow(unused_imports)]
+        #[allow(dead_code)]
+        pub mod queries;
+    );
+    gen_metadata(&mut root, preparation, settings);
+    files.push((PathBuf::from("mod.rs"), root));
+    // Generate database type
+    let mut types = HEADER.to_string();
+    gen_type_schemas(&mut types, &preparation.types, &types_ctx(settings));
+    files.push((PathBuf::from("types.rs"), types));
+    // Generate queries
+    let mut queries = HEADER.to_string();
+    for module in &preparation.modules {
+        let name = &module.info.name;
+        let attributes = module.attributes.iter();
+        let w = &mut queries;
+        code!(w =>
+            $(#[$attributes])
+            pub mod $name;
+        );
+        let mut content = HEADER.to_string();
+        gen_query_module(&mut content, module, settings);
+        files.push((Path::new("queries").join(format!("{name}.rs")), content));
+    }
+    files.push((Path::new("queries").join("mod.rs"), queries));
+    files
+}
@@ -2,9 +2,49 @@ use postgres::{Client, Config, NoTls};
 
 use self::error::Error;
 
+/// `application_name` of the sessions preparing queries, shown in `pg_stat_activity`
+pub const APPLICATION_NAME: &str = "cornucopia-codegen";
+
+/// Parses a URL, naming the session `application_name`. Without one, the URL's own
+/// `application_name` is kept and defaults to [`APPLICATION_NAME`].
+fn url_config(url: &str, application_name: Option<&str>) -> Result<Config, Error> {
+    let mut config: Config = url.parse()?;
+    match application_name {
+        Some(name) => {
+            config.application_name(name);
+        }
+        None if config.get_application_name().is_none() => {
+            config.application_name(APPLICATION_NAME);
+        }
+        None => {}
+    }
+    Ok(config)
+}
+
 /// Creates a non-TLS connection from a URL.
-pub(crate) fn from_url(url: &str) -> Result<Client, Error> {
-    Ok(Client::connect(url, NoTls)?)
+#[cfg(not(feature = "native-tls"))]
+pub(crate) fn from_url(url: &str, application_name: Option<&str>) -> Result<Client, Error> {
+    Ok(url_config(url, application_name)?.connect(NoTls)?)
+}
+
+/// Creates a connection from a URL, negotiating TLS according to its `sslmode`.
+#[cfg(feature = "native-tls")]
+pub(crate) fn from_url(url: &str, application_name: Option<&str>) -> Result<Client, Error> {
+    let connector = native_tls::TlsConnector::new()?;
+    Ok(url_config(url, application_name)?
+        .connect(postgres_native_tls::MakeTlsConnector::new(connector))?)
+}
+
+/// Sets the `search_path` of the session, so that unqualified names in queries prepared
+/// with `client` resolve against the given schemas (e.g. `"tenant, public"`).
+pub fn set_search_path(client: &mut Client, search_path: &str) -> Result<(), Error> {
+    client
+        .execute(
+            "SELECT set_config('search_path', $1, false)",
+            &[&search_path],
+        )
+        .map_err(Error::SearchPath)?;
+    Ok(())
 }
 
 /// Create a non-TLS connection to the container managed by Cornucopia.
@@ -15,13 +55,43 @@ pub fn cornucopia_conn() -> Result<Client, Error> {
         .host("127.0.0.1")
         .port(5435)
         .dbname("postgres")
+        .application_name(APPLICATION_NAME)
         .connect(NoTls)?)
 }
 
+/// Create a non-TLS async connection to the container managed by Cornucopia.
+///
+/// The connection is driven by a task spawned on the current tokio runtime.
+#[cfg(feature = "tokio")]
+pub async fn cornucopia_conn_async() -> Result<tokio_postgres::Client, Error> {
+    let (client, connection) = tokio_postgres::Config::new()
+        .user("postgres")
+        .password("postgres")
+        .host("127.0.0.1")
+        .port(5435)
+        .dbname("postgres")
+        .application_name(APPLICATION_NAME)
+        .connect(tokio_postgres::NoTls)
+        .await?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            log::error!("connection to the database failed: {err}");
+        }
+    });
+    Ok(client)
+}
+
 pub(crate) mod error {
     use miette::Diagnostic;
 
     #[derive(Debug, thiserror::Error, Diagnostic)]
-    #[error("Couldn't establish a connection with the database.")]
-    pub struct Error(#[from] pub postgres::Error);
+    pub enum Error {
+        #[error("Couldn't establish a connection with the database.")]
+        Postgres(#[from] postgres::Error),
+        #[error("Couldn't set the search_path of the session.")]
+        SearchPath(#[source] postgres::Error),
+        #[cfg(feature = "native-tls")]
+        #[error("Couldn't initialize the TLS connector: {0}")]
+        Tls(#[from] native_tls::Error),
+    }
 }
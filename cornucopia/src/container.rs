@@ -1,10 +1,13 @@
-use std::process::{Command, Stdio};
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
 
 use self::error::Error;
 
 /// Starts Cornucopia's database container and wait until it reports healthy.
 pub fn setup(podman: bool) -> Result<(), Error> {
-    spawn_container(podman)?;
+    spawn_container(podman, None)?;
     healthcheck(podman, 120, 50)?;
     Ok(())
 }
@@ -16,30 +19,95 @@ pub fn cleanup(podman: bool) -> Result<(), Error> {
     Ok(())
 }
 
-/// Starts Cornucopia's database container.
-fn spawn_container(podman: bool) -> Result<(), Error> {
-    cmd(
-        podman,
-        &[
-            "run",
-            "-d",
-            "--name",
-            "cornucopia_postgres",
-            "-p",
-            "5435:5432",
-            "-e",
-            "POSTGRES_PASSWORD=postgres",
-            "postgres",
-        ],
-        "spawn container",
-    )
+/// Handle on Cornucopia's database container, which is stopped and removed when dropped.
+///
+/// This ensures the container does not outlive an early return or a panic. Dropping the
+/// handle ignores cleanup errors, use [`Container::cleanup`] to observe them.
+pub struct Container {
+    podman: bool,
+    running: bool,
+}
+
+impl Container {
+    /// Starts Cornucopia's database container and wait until it reports healthy.
+    pub fn start(podman: bool) -> Result<Self, Error> {
+        Self::spawn(podman, None)
+    }
+
+    /// Same as [`Container::start`], but the SQL file at `init_script` is mounted in the
+    /// container's `/docker-entrypoint-initdb.d/` and runs before the database reports healthy,
+    /// e.g. to seed reference data.
+    pub fn start_with_init_script(podman: bool, init_script: &Path) -> Result<Self, Error> {
+        Self::spawn(podman, Some(init_script))
+    }
+
+    fn spawn(podman: bool, init_script: Option<&Path>) -> Result<Self, Error> {
+        spawn_container(podman, init_script)?;
+        // From now on, the container is removed if anything goes wrong
+        let container = Self {
+            podman,
+            running: true,
+        };
+        healthcheck(podman, 120, 50)?;
+        Ok(container)
+    }
+
+    /// Stop and remove the container and its volume.
+    pub fn cleanup(mut self) -> Result<(), Error> {
+        self.running = false;
+        cleanup(self.podman)
+    }
+}
+
+impl Drop for Container {
+    fn drop(&mut self) {
+        if self.running {
+            cleanup(self.podman).ok();
+        }
+    }
+}
+
+/// Starts Cornucopia's database container, mounting the optional `init_script`.
+fn spawn_container(podman: bool, init_script: Option<&Path>) -> Result<(), Error> {
+    // Bind mounts require an absolute path
+    let mount = init_script
+        .map(|path| -> Result<_, Error> {
+            Ok(format!(
+                "{}:/docker-entrypoint-initdb.d/init.sql:ro",
+                path.canonicalize()?.display()
+            ))
+        })
+        .transpose()?;
+    let mut args = vec![
+        "run",
+        "-d",
+        "--name",
+        "cornucopia_postgres",
+        "-p",
+        "5435:5432",
+        "-e",
+        "POSTGRES_PASSWORD=postgres",
+    ];
+    if let Some(mount) = &mount {
+        args.extend(["-v", mount.as_str()]);
+    }
+    args.push("postgres");
+    cmd(podman, &args, "spawn container")
 }
 
 /// Checks if Cornucopia's container reports healthy
 fn is_postgres_healthy(podman: bool) -> Result<bool, Error> {
+    // Init scripts run on a server only listening on its socket, check through TCP
+    // to wait for the final server
     Ok(cmd(
         podman,
-        &["exec", "cornucopia_postgres", "pg_isready"],
+        &[
+            "exec",
+            "cornucopia_postgres",
+            "pg_isready",
+            "-h",
+            "localhost",
+        ],
         "check container health",
     )
     .is_ok())
@@ -82,7 +150,7 @@ fn remove_container(podman: bool) -> Result<(), Error> {
     )
 }
 
-fn cmd(podman: bool, args: &[&'static str], action: &'static str) -> Result<(), Error> {
+fn cmd(podman: bool, args: &[&str], action: &'static str) -> Result<(), Error> {
     let command = if podman { "podman" } else { "docker" };
     let output = Command::new(command)
         .args(args)
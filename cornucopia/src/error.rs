@@ -22,11 +22,38 @@ pub enum Error {
     PrepareQueries(#[from] crate::prepare_queries::error::Error),
     /// An error while reading PostgreSQL schema files.
     LoadSchema(#[from] crate::load_schema::error::Error),
+    /// An error while reading or writing an offline cache.
+    Offline(#[from] crate::catalog::error::Error),
     /// An error while trying to write the generated code to its destination file.
     WriteCodeGenFile(#[from] WriteOutputError),
+    /// An error while running a formatter on the generated code.
+    Format(#[from] FormatError),
+    /// An error while scaffolding a new project.
+    Init(#[from] InitError),
+    /// A destination file that differs from the generated code.
+    Stale(#[from] StaleOutputError),
+    /// A file in the destination directory that is not generated.
+    Unexpected(#[from] UnexpectedOutputError),
+    /// An error while reading a destination file to verify it.
+    ReadCodeGenFile(#[from] ReadOutputError),
+    /// Several independent errors, reported together.
+    Multiple(#[from] MultipleErrors),
 }
 
 impl Error {
+    /// Reports `errors` as a single error, wrapping them only if there are several.
+    ///
+    /// # Panics
+    /// Panics if `errors` is empty.
+    pub(crate) fn from_many(mut errors: Vec<Error>) -> Self {
+        assert!(!errors.is_empty());
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Error::Multiple(errors.into())
+        }
+    }
+
     #[must_use]
     pub fn report(self) -> String {
         let mut buff = String::new();
@@ -44,3 +71,66 @@ pub struct WriteOutputError {
     pub(crate) file_path: PathBuf,
     pub(crate) err: std::io::Error,
 }
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("Could not read destination file `{file_path}`: ({err})")]
+pub struct ReadOutputError {
+    pub(crate) file_path: PathBuf,
+    pub(crate) err: std::io::Error,
+}
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("`{file_path}` is not part of the generated code")]
+#[diagnostic(help("remove the file if its module was deleted"))]
+pub struct UnexpectedOutputError {
+    pub(crate) file_path: PathBuf,
+}
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error(
+    "`{file_path}` is outdated at line {line}{module}\n  expected: {expected}\n  found:    {found}"
+)]
+#[diagnostic(help("regenerate the file"))]
+pub struct StaleOutputError {
+    pub(crate) file_path: PathBuf,
+    pub(crate) line: usize,
+    /// Module enclosing the diverging line, formatted as ` (in module \`path\`)`
+    pub(crate) module: String,
+    pub(crate) expected: String,
+    pub(crate) found: String,
+}
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("Could not create `{file_path}`: ({err})")]
+pub struct InitError {
+    pub(crate) file_path: PathBuf,
+    pub(crate) err: std::io::Error,
+}
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("Could not format the generated code with `{command}`: {msg}")]
+pub struct FormatError {
+    pub(crate) command: String,
+    pub(crate) msg: String,
+}
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("Found {} error(s) in your queries", .errors.len())]
+pub struct MultipleErrors {
+    #[related]
+    pub(crate) errors: Vec<Error>,
+}
+
+impl MultipleErrors {
+    /// The errors, in the order they were found
+    #[must_use]
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+}
+
+impl From<Vec<Error>> for MultipleErrors {
+    fn from(errors: Vec<Error>) -> Self {
+        Self { errors }
+    }
+}
@@ -1,3 +1,4 @@
+mod catalog;
 mod cli;
 mod codegen;
 mod error;
@@ -5,6 +6,8 @@ mod load_schema;
 mod parser;
 mod prepare_queries;
 mod read_queries;
+mod scaffold;
+mod schema_catalog;
 mod type_registrar;
 mod utils;
 mod validation;
@@ -14,48 +17,224 @@ pub mod conn;
 /// High-level interfaces to work with Cornucopia's container manager.
 pub mod container;
 
-use std::path::Path;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
 
 use postgres::Client;
 
-use codegen::generate as generate_internal;
-use error::WriteOutputError;
-use parser::parse_query_module;
-use prepare_queries::prepare;
+use catalog::{schema_hash, Catalog, Offline, OfflineCache, Recorder};
+use codegen::{generate as generate_internal, generate_split};
+use error::{
+    FormatError, ReadOutputError, StaleOutputError, UnexpectedOutputError, WriteOutputError,
+};
+use parser::{parse_query_module, Module};
+use prepare_queries::{prepare, Preparation};
 use read_queries::read_query_modules;
+use utils::glob_match;
 
 #[doc(hidden)]
 pub use cli::run;
 
-pub use error::Error;
-pub use load_schema::load_schema;
+pub use error::{Error, MultipleErrors};
+pub use load_schema::{load_schema, reset_database};
+pub use scaffold::init_project;
 
 /// Struct containing the settings for code generation.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct CodegenSettings {
+    /// Generate asynchronous code.
+    ///
+    /// When combined with `gen_sync`, the queries are prepared once and both variants are
+    /// emitted in the same output, under `sync` and `async` submodules.
     pub gen_async: bool,
+    /// Generate synchronous code.
     pub gen_sync: bool,
     pub derive_ser: bool,
+    /// Put the serde derives of `derive_ser` behind this cargo feature of the consuming crate,
+    /// using `#[cfg_attr(feature = "...", derive(serde::Serialize))]`, so that serde stays optional.
+    pub serde_feature: Option<&'static str>,
+    /// Derive sqlx's `FromRow` for owned row structs, matching fields to columns by name.
+    ///
+    /// Every field type must also be decodable by sqlx, custom types are not.
+    pub derive_sqlx: bool,
+    /// Derive `Eq` and `Hash` for row structs whose fields are all hashable, so that rows can
+    /// be used as set elements or map keys. Floats and JSON values are not hashable.
+    pub derive_hash: bool,
+    pub gen_cow: bool,
+    pub gen_foreign_keys: bool,
+    /// Generate a `prelude` module re-exporting the query functions and types of every module.
+    ///
+    /// Names defined by several modules are left out of the prelude.
+    pub gen_prelude: bool,
+    pub gen_query_errors: bool,
+    /// Map `numeric` to `f64` instead of `rust_decimal::Decimal`.
+    ///
+    /// Values are approximated, only use this when exactness does not matter. Params also accept
+    /// the client's `TextParam`, to bind exact values from their text representation.
+    pub numeric_as_f64: bool,
+    /// Map `real` and `double precision` to the client's `OrderedF32` and `OrderedF64` instead of `f32` and `f64`.
+    ///
+    /// Generated structs derive `PartialEq`, under which a `NaN` float field makes a value differ from
+    /// itself. The wrappers compare floats by their total order instead, so they can be used as map keys.
+    pub ordered_floats: bool,
+    /// Derive `PartialOrd` and `Ord` for enums, ordering variants as declared in the database.
+    pub enum_ord: bool,
+    /// Generate an enum listing the columns of each row, to reference them by name in dynamic queries.
+    pub column_enums: bool,
+    /// Also recognize yesql/aiosql `-- name: <ident>` query annotations, easing migrations.
+    ///
+    /// `^` and `$` name suffixes mark queries returning a single row, other suffixes are ignored.
+    /// Queries must still end with a `;`.
+    pub yesql: bool,
+    /// Generate a `bind_dyn` method on statements, binding a slice of `&dyn ToSql` checked for arity at runtime.
+    pub dyn_params: bool,
+    /// Reference external items of the query modules by their full path instead of importing them,
+    /// so that no `use` statement can conflict with the surrounding code.
+    pub qualified_paths: bool,
+    /// Generate a `Statements` struct in each query module, holding the statement of every query
+    /// so they can be prepared together with `prepare_all` and reused for the connection's lifetime.
+    pub statements_struct: bool,
+    /// Return a named `*Stream` type from the `iter` method of async queries instead of an `impl Stream`,
+    /// so that it can be stored in a struct field or returned from a non-generic function.
+    pub named_streams: bool,
+    /// Read the columns of a row by name instead of by index, so that a row whose columns come in
+    /// another order than at generation time is still extracted correctly. This is slower.
+    pub columns_by_name: bool,
+    /// Map `text` and `varchar` columns to `Vec<u8>` instead of `String`, for databases whose
+    /// encoding allows values that are not valid UTF-8. Parameters are still bound as strings.
+    pub text_as_bytes: bool,
+    /// Map `timestamp` and `timestamptz` to a `Timestamp` enum that can represent `infinity` and
+    /// `-infinity`, which the `time` types fail to decode.
+    pub infinite_timestamps: bool,
+    /// Serialize `bigint` and `numeric` fields as strings with `derive_ser`, as JavaScript numbers
+    /// cannot represent all of their values. Requires the client's `with-serde_json-1` feature.
+    pub bigint_as_string: bool,
+    /// Crate providing the types of `timestamp`, `timestamptz`, `date` and `time` columns.
+    pub time_backend: TimeBackend,
+    /// Abort the preparation of a query after this many milliseconds
+    pub statement_timeout: Option<u32>,
+}
+
+/// Crate whose types represent temporal columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeBackend {
+    /// `chrono`'s `NaiveDateTime`, `DateTime<Utc>`, `NaiveDate` and `NaiveTime`.
+    ///
+    /// Requires the client's `with-chrono-0_4` feature.
+    Chrono,
+    /// `time`'s `PrimitiveDateTime`, `OffsetDateTime`, `Date` and `Time`.
+    #[default]
+    Time,
+    /// Temporal columns are rejected as unsupported types, temporal params are bound from their
+    /// text representation.
+    None,
+}
+
+impl std::str::FromStr for TimeBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chrono" => Ok(Self::Chrono),
+            "time" => Ok(Self::Time),
+            "none" => Ok(Self::None),
+            _ => Err(format!(
+                "unknown time backend \"{s}\", expected `chrono`, `time` or `none`"
+            )),
+        }
+    }
+}
+
+/// Driver targeted by one of the outputs of [`generate_live_backends`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Asynchronous queries using `tokio-postgres`.
+    Async,
+    /// Synchronous queries using `postgres`.
+    Sync,
+    /// Asynchronous queries whose rows also derive `sqlx::FromRow`, to be used with `sqlx` too.
+    Sqlx,
+}
+
+impl Backend {
+    /// Overrides the settings choosing the generated variant with the ones of this backend
+    fn settings(self, settings: CodegenSettings) -> CodegenSettings {
+        CodegenSettings {
+            gen_async: self != Backend::Sync,
+            gen_sync: self == Backend::Sync,
+            derive_sqlx: self == Backend::Sqlx || settings.derive_sqlx,
+            ..settings
+        }
+    }
 }
 
 /// Generates Rust queries from PostgreSQL queries located at `queries_path`,
 /// using a live database managed by you. If some `destination` is given,
 /// the generated code will be written at that path. Code generation settings are
 /// set using the `settings` parameter.
+///
+/// Queries are prepared in the session of `client`: unqualified names resolve against
+/// its `search_path`, which can be set with [`conn::set_search_path`]. Custom types are
+/// generated in the module of the schema they were found in.
+///
+/// All failing queries are prepared before returning, several errors are reported
+/// together as [`Error::Multiple`].
 pub fn generate_live<P: AsRef<Path>>(
     client: &mut Client,
     queries_path: P,
     destination: Option<P>,
     settings: CodegenSettings,
+) -> Result<String, Error> {
+    generate_live_multi(client, &[queries_path], destination, settings)
+}
+
+/// Same as [`generate_live`], but merges the queries located in all of `queries_paths`
+/// into a single generated module tree. Query files sharing the same name in different
+/// directories are reported as an error.
+pub fn generate_live_multi<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    destination: Option<P>,
+    settings: CodegenSettings,
 ) -> Result<String, Error> {
     // Read
-    let modules = read_query_modules(queries_path.as_ref())?
-        .into_iter()
-        .map(parse_query_module)
-        .collect::<Result<_, parser::error::Error>>()?;
+    let modules = read_modules(queries_paths, settings)?;
+    generate_live_modules(client, modules, destination, settings)
+}
+
+/// Same as [`generate_live_multi`], but only prepares and generates the queries matching the
+/// `filter` glob, where `*` matches any sequence of characters and `?` a single one.
+///
+/// The filter is matched against the module name, the query name and the `module::query` path:
+/// `users` keeps a whole module while `*::insert_*` keeps the matching queries of every module.
+/// Modules left without queries are not generated. This speeds up the regeneration of the
+/// queries being edited in large projects.
+pub fn generate_live_filtered<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    filter: &str,
+    destination: Option<P>,
+    settings: CodegenSettings,
+) -> Result<String, Error> {
+    // Read
+    let mut modules = read_modules(queries_paths, settings)?;
+    filter_modules(&mut modules, filter);
+    generate_live_modules(client, modules, destination, settings)
+}
+
+fn generate_live_modules<P: AsRef<Path>>(
+    client: &mut Client,
+    modules: Vec<Module>,
+    destination: Option<P>,
+    settings: CodegenSettings,
+) -> Result<String, Error> {
     // Generate
-    let prepared_modules = prepare(client, modules)?;
-    let generated_code = generate_internal(prepared_modules, settings);
+    let prepared_modules = prepare_modules(client, modules, settings)?;
+    report_warnings(&prepared_modules);
+    let generated_code = generate_internal(&prepared_modules, settings);
     // Write
     if let Some(d) = destination {
         write_generated_code(d.as_ref(), &generated_code)?;
@@ -64,6 +243,109 @@ pub fn generate_live<P: AsRef<Path>>(
     Ok(generated_code)
 }
 
+/// Same as [`generate_live_multi`], but splits the generated code in one file per module instead
+/// of a single file, which keeps large projects fast to analyze. The files mirror the generated
+/// module tree: `mod.rs` declares `types.rs` and `queries/mod.rs`, which declares a
+/// `queries/{module}.rs` per query file.
+///
+/// If some `destination` directory is given, the files are written inside it. The files are
+/// returned with their path relative to that directory.
+pub fn generate_live_split<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    destination: Option<P>,
+    settings: CodegenSettings,
+) -> Result<Vec<(PathBuf, String)>, Error> {
+    // Read
+    let modules = read_modules(queries_paths, settings)?;
+    generate_live_modules_split(client, modules, destination, settings)
+}
+
+fn generate_live_modules_split<P: AsRef<Path>>(
+    client: &mut Client,
+    modules: Vec<Module>,
+    destination: Option<P>,
+    settings: CodegenSettings,
+) -> Result<Vec<(PathBuf, String)>, Error> {
+    // Generate
+    let prepared_modules = prepare_modules(client, modules, settings)?;
+    report_warnings(&prepared_modules);
+    let generated_files = generate_split(&prepared_modules, settings);
+    // Write
+    if let Some(d) = destination {
+        write_generated_files(d.as_ref(), &generated_files)?;
+    };
+
+    Ok(generated_files)
+}
+
+/// Same as [`generate_live_multi`], but generates the code of every backend of `outputs`,
+/// writing it at its destination if some is given. The queries are only prepared once, the
+/// `gen_async` and `gen_sync` settings are overridden by each backend.
+///
+/// The generated code is returned in the same order as `outputs`.
+pub fn generate_live_backends<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    outputs: &[(Backend, Option<P>)],
+    settings: CodegenSettings,
+) -> Result<Vec<String>, Error> {
+    // Read
+    let modules = read_modules(queries_paths, settings)?;
+    // Generate
+    let prepared_modules = prepare_modules(client, modules, settings)?;
+    report_warnings(&prepared_modules);
+    let mut generated = Vec::with_capacity(outputs.len());
+    for (backend, destination) in outputs {
+        let generated_code = generate_internal(&prepared_modules, backend.settings(settings));
+        // Write
+        if let Some(d) = destination {
+            write_generated_code(d.as_ref(), &generated_code)?;
+        };
+        generated.push(generated_code);
+    }
+
+    Ok(generated)
+}
+
+/// Summary of a query discovered by cornucopia, as it will be generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryInfo {
+    /// Name of the module, taken from the query file name
+    pub module: String,
+    /// Name of the query
+    pub name: String,
+    /// Number of distinct bind parameters
+    pub param_count: usize,
+    /// Whether the query returns rows or only an affected row count
+    pub returns_rows: bool,
+}
+
+/// Lists the queries located at `queries_path`, prepared against a live database
+/// managed by you, without generating any code.
+pub fn list_queries<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_path: P,
+    settings: CodegenSettings,
+) -> Result<Vec<QueryInfo>, Error> {
+    let modules = read_modules(&[queries_path], settings)?;
+    let preparation = prepare_modules(client, modules, settings)?;
+    Ok(preparation
+        .modules
+        .iter()
+        .flat_map(|module| {
+            module.queries.values().map(|query| QueryInfo {
+                module: module.info.name.clone(),
+                name: query.ident.db.clone(),
+                param_count: query.param.as_ref().map_or(0, |(idx, _)| {
+                    module.params.get_index(*idx).unwrap().1.fields.len()
+                }),
+                returns_rows: query.row.is_some(),
+            })
+        })
+        .collect())
+}
+
 /// Generates Rust queries from PostgreSQL queries located at `queries_path`, using
 /// a container managed by cornucopia. The database schema is created using `schema_files`.
 /// If some `destination` is given, the generated code will be written at that path.
@@ -77,18 +359,26 @@ pub fn generate_managed<P: AsRef<Path>>(
     destination: Option<P>,
     podman: bool,
     settings: CodegenSettings,
+) -> Result<String, Error> {
+    generate_managed_multi(&[queries_path], schema_files, destination, podman, settings)
+}
+
+/// Same as [`generate_managed`], but merges the queries located in all of `queries_paths`
+/// into a single generated module tree. Query files sharing the same name in different
+/// directories are reported as an error.
+pub fn generate_managed_multi<P: AsRef<Path>>(
+    queries_paths: &[P],
+    schema_files: &[P],
+    destination: Option<P>,
+    podman: bool,
+    settings: CodegenSettings,
 ) -> Result<String, Error> {
     // Read
-    let modules = read_query_modules(queries_path.as_ref())?
-        .into_iter()
-        .map(parse_query_module)
-        .collect::<Result<_, parser::error::Error>>()?;
-    container::setup(podman)?;
-    let mut client = conn::cornucopia_conn()?;
-    load_schema(&mut client, schema_files)?;
-    let prepared_modules = prepare(&mut client, modules)?;
-    let generated_code = generate_internal(prepared_modules, settings);
-    container::cleanup(podman)?;
+    let modules = read_modules(queries_paths, settings)?;
+    // Generate
+    let prepared_modules = prepare_managed(modules, schema_files, None, podman, settings)?;
+    report_warnings(&prepared_modules);
+    let generated_code = generate_internal(&prepared_modules, settings);
 
     if let Some(destination) = destination {
         write_generated_code(destination.as_ref(), &generated_code)?;
@@ -97,7 +387,293 @@ pub fn generate_managed<P: AsRef<Path>>(
     Ok(generated_code)
 }
 
+/// Same as [`generate_managed_multi`], but splits the generated code in one file per module
+/// like [`generate_live_split`].
+pub fn generate_managed_split<P: AsRef<Path>>(
+    queries_paths: &[P],
+    schema_files: &[P],
+    destination: Option<P>,
+    podman: bool,
+    settings: CodegenSettings,
+) -> Result<Vec<(PathBuf, String)>, Error> {
+    // Read
+    let modules = read_modules(queries_paths, settings)?;
+    // Generate
+    let prepared_modules = prepare_managed(modules, schema_files, None, podman, settings)?;
+    report_warnings(&prepared_modules);
+    let generated_files = generate_split(&prepared_modules, settings);
+
+    if let Some(destination) = destination {
+        write_generated_files(destination.as_ref(), &generated_files)?;
+    };
+
+    Ok(generated_files)
+}
+
+/// Prepares the queries located in all of `queries_paths` against a live database managed by
+/// you, saving the types of their params and columns to the offline cache at `cache`.
+/// [`generate_offline`] then generates the same code from this cache, without any database.
+///
+/// The database must hold the schema of `schema_files`, e.g. a `pg_dump --schema-only` output
+/// loaded with [`load_schema`]. The cache is only valid for the exact content of these files.
+pub fn save_offline_cache<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    schema_files: &[P],
+    cache: P,
+    settings: CodegenSettings,
+) -> Result<(), Error> {
+    // Read
+    let modules = read_modules(queries_paths, settings)?;
+    let prepared_modules =
+        prepare_recorded(client, modules, schema_files, cache.as_ref(), settings)?;
+    report_warnings(&prepared_modules);
+    Ok(())
+}
+
+/// Same as [`generate_live_multi`], but prepares the queries without any database, e.g. to
+/// generate code in CI.
+///
+/// Simple queries on tables and views are prepared from the schema declared in `schema_files`,
+/// e.g. a `pg_dump --schema-only` output. The others must be in the offline cache at `cache`
+/// saved by [`save_offline_cache`], if some is given. `schema_files` must then have the content
+/// the cache was saved against, otherwise the cache is reported as stale.
+pub fn generate_offline<P: AsRef<Path>>(
+    queries_paths: &[P],
+    schema_files: &[P],
+    cache: Option<P>,
+    destination: Option<P>,
+    settings: CodegenSettings,
+) -> Result<String, Error> {
+    // Read
+    let modules = read_modules(queries_paths, settings)?;
+    // Generate
+    let prepared_modules = prepare_offline(
+        modules,
+        schema_files,
+        cache.as_ref().map(AsRef::as_ref),
+        settings,
+    )?;
+    report_warnings(&prepared_modules);
+    let generated_code = generate_internal(&prepared_modules, settings);
+    // Write
+    if let Some(d) = destination {
+        write_generated_code(d.as_ref(), &generated_code)?;
+    };
+
+    Ok(generated_code)
+}
+
+/// Checks that the queries located in all of `queries_paths` can be prepared against a live
+/// database managed by you, without generating any code. Every error is returned, including
+/// parsing errors of distinct query files.
+pub fn check_live<P: AsRef<Path>>(
+    client: &mut Client,
+    queries_paths: &[P],
+    settings: CodegenSettings,
+) -> Result<(), Vec<Error>> {
+    let mut errors = Vec::new();
+    let mut modules = Vec::new();
+    for info in read_query_modules(queries_paths).map_err(|e| vec![e.into()])? {
+        match parse_query_module(info, settings.yesql) {
+            Ok(module) => modules.push(module),
+            Err(e) => errors.push(e.into()),
+        }
+    }
+    match prepare(client, modules, settings) {
+        Ok(preparation) => report_warnings(&preparation),
+        Err(prepare_errors) => errors.extend(prepare_errors.into_iter().map(Error::from)),
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Same as [`check_live`], using a container managed by cornucopia. The database schema
+/// is created using `schema_files`.
+pub fn check_managed<P: AsRef<Path>>(
+    queries_paths: &[P],
+    schema_files: &[P],
+    podman: bool,
+    settings: CodegenSettings,
+) -> Result<(), Vec<Error>> {
+    let container = container::Container::start(podman).map_err(|e| vec![e.into()])?;
+    let mut client = conn::cornucopia_conn().map_err(|e| vec![e.into()])?;
+    load_schema(&mut client, schema_files).map_err(|e| vec![e.into()])?;
+    check_live(&mut client, queries_paths, settings)?;
+    container.cleanup().map_err(|e| vec![e.into()])
+}
+
+/// Reads and parses the query modules of all `queries_paths`, reporting every parsing error
+fn read_modules<P: AsRef<Path>>(
+    queries_paths: &[P],
+    settings: CodegenSettings,
+) -> Result<Vec<Module>, Error> {
+    let mut modules = Vec::new();
+    let mut errors = Vec::new();
+    for info in read_query_modules(queries_paths)? {
+        match parse_query_module(info, settings.yesql) {
+            Ok(module) => modules.push(module),
+            Err(e) => errors.push(e.into()),
+        }
+    }
+    if errors.is_empty() {
+        Ok(modules)
+    } else {
+        Err(Error::from_many(errors))
+    }
+}
+
+/// Keeps the queries and views matching the `filter` glob, dropping the modules left empty
+fn filter_modules(modules: &mut Vec<Module>, filter: &str) {
+    modules.retain_mut(|module| {
+        let module_name = &module.info.name;
+        if glob_match(filter, module_name) {
+            return true;
+        }
+        let matches = |name: &str| {
+            glob_match(filter, name) || glob_match(filter, &format!("{module_name}::{name}"))
+        };
+        module.queries.retain(|query| matches(&query.name.value));
+        module.views.retain(|view| matches(&view.name.value));
+        !module.queries.is_empty() || !module.views.is_empty()
+    });
+}
+
+/// Prepares the modules, reporting every error
+fn prepare_modules(
+    catalog: &mut dyn Catalog,
+    modules: Vec<Module>,
+    settings: CodegenSettings,
+) -> Result<Preparation, Error> {
+    prepare(catalog, modules, settings)
+        .map_err(|errors| Error::from_many(errors.into_iter().map(Error::from).collect()))
+}
+
+/// Prepares the modules against a container managed by cornucopia holding `schema_files`,
+/// saving their metadata to the offline cache at `offline_cache` if some is given
+fn prepare_managed<P: AsRef<Path>>(
+    modules: Vec<Module>,
+    schema_files: &[P],
+    offline_cache: Option<&Path>,
+    podman: bool,
+    settings: CodegenSettings,
+) -> Result<Preparation, Error> {
+    // The container is removed on drop if anything fails before the explicit cleanup
+    let container = container::Container::start(podman)?;
+    let mut client = conn::cornucopia_conn()?;
+    load_schema(&mut client, schema_files)?;
+    let preparation = match offline_cache {
+        Some(path) => prepare_recorded(&mut client, modules, schema_files, path, settings)?,
+        None => prepare_modules(&mut client, modules, settings)?,
+    };
+    container.cleanup()?;
+    Ok(preparation)
+}
+
+/// Prepares the modules against the database of `client`, saving their metadata to the offline
+/// cache at `path`
+fn prepare_recorded<P: AsRef<Path>>(
+    client: &mut Client,
+    modules: Vec<Module>,
+    schema_files: &[P],
+    path: &Path,
+    settings: CodegenSettings,
+) -> Result<Preparation, Error> {
+    let schema = schema_hash(schema_files)?;
+    let mut recorder = Recorder::new(client);
+    let preparation = prepare_modules(&mut recorder, modules, settings)?;
+    let cache = recorder
+        .finish(schema)
+        .map_err(prepare_queries::error::Error::ForeignKeys)?;
+    log::info!("saving offline cache at `{}`", path.display());
+    cache.save(path)?;
+    Ok(preparation)
+}
+
+/// Prepares the modules from the schema declared in `schema_files`, or from the offline cache at
+/// `cache` if some is given, which must have been saved against `schema_files`
+fn prepare_offline<P: AsRef<Path>>(
+    modules: Vec<Module>,
+    schema_files: &[P],
+    cache: Option<&Path>,
+    settings: CodegenSettings,
+) -> Result<Preparation, Error> {
+    let cache = match cache {
+        Some(path) => {
+            log::info!("loading offline cache at `{}`", path.display());
+            Some(OfflineCache::load(path, &schema_hash(schema_files)?)?)
+        }
+        None => None,
+    };
+    let schema = schema_catalog::SchemaCatalog::load(schema_files)?;
+    prepare_modules(&mut Offline::new(cache, schema), modules, settings)
+}
+
+/// Generates the code of the prepared modules at `destination`, or in one file per module in
+/// the `destination` directory if `split`
+fn generate_files(
+    preparation: &Preparation,
+    split: bool,
+    destination: &Path,
+    settings: CodegenSettings,
+) -> Vec<(PathBuf, String)> {
+    report_warnings(preparation);
+    if split {
+        generate_split(preparation, settings)
+    } else {
+        vec![(
+            destination.to_owned(),
+            generate_internal(preparation, settings),
+        )]
+    }
+}
+
+/// Logs the warnings of the preparation, silenced along with the other logs of `cornucopia`
+fn report_warnings(preparation: &Preparation) {
+    for warning in preparation.modules.iter().flat_map(|it| &it.warnings) {
+        log::warn!("{}", warning.report());
+    }
+}
+
+/// Pipes `generated_code` through the formatter `command` (e.g. `rustfmt --edition 2021`),
+/// returning its output. Arguments are split on whitespace, without shell quoting.
+///
+/// The code generated by Cornucopia is not formatted, pass no destination to the `generate_*`
+/// functions to post-process it with this or any other formatter before writing it.
+pub fn format_generated_code(generated_code: &str, command: &str) -> Result<String, Error> {
+    let err = |msg: String| FormatError {
+        command: command.to_string(),
+        msg,
+    };
+    let mut args = command.split_whitespace();
+    let program = args
+        .next()
+        .ok_or_else(|| err("empty command".to_string()))?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| err(e.to_string()))?;
+    // Write from another thread, a formatter can start writing before reading all its input
+    let mut stdin = child.stdin.take().unwrap();
+    let input = generated_code.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(|e| err(e.to_string()))?;
+    let written = writer.join().unwrap();
+    if !output.status.success() {
+        return Err(err(String::from_utf8_lossy(&output.stderr).trim().to_string()).into());
+    }
+    written.map_err(|e| err(e.to_string()))?;
+    String::from_utf8(output.stdout).map_err(|e| err(e.to_string()).into())
+}
+
 fn write_generated_code(destination: &Path, generated_code: &str) -> Result<(), Error> {
+    log::info!("writing generated code to `{}`", destination.display());
     Ok(
         std::fs::write(destination, generated_code).map_err(|err| WriteOutputError {
             err,
@@ -105,3 +681,172 @@ fn write_generated_code(destination: &Path, generated_code: &str) -> Result<(),
         })?,
     )
 }
+
+/// Writes split generated code in the `destination` directory, creating it if needed
+fn write_generated_files(destination: &Path, files: &[(PathBuf, String)]) -> Result<(), Error> {
+    for (path, generated_code) in files {
+        let path = destination.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| WriteOutputError {
+                err,
+                file_path: parent.to_owned(),
+            })?;
+        }
+        write_generated_code(&path, generated_code)?;
+    }
+    Ok(())
+}
+
+/// Checks split generated code in the `destination` directory like [`verify_generated_code`],
+/// also reporting the Rust files of the directory that are not generated anymore.
+pub fn verify_generated_files(
+    destination: &Path,
+    files: &[(PathBuf, String)],
+) -> Result<(), Error> {
+    let mut errors: Vec<Error> = files
+        .iter()
+        .filter_map(|(path, generated_code)| {
+            verify_generated_code(&destination.join(path), generated_code).err()
+        })
+        .collect();
+    let mut dirs = vec![destination.to_owned()];
+    while let Some(dir) = dirs.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            // Missing generated files are already reported
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                errors.push(
+                    ReadOutputError {
+                        file_path: dir,
+                        err,
+                    }
+                    .into(),
+                );
+                continue;
+            }
+        };
+        for entry in entries {
+            let path = match entry {
+                Ok(entry) => entry.path(),
+                Err(err) => {
+                    errors.push(
+                        ReadOutputError {
+                            file_path: dir.clone(),
+                            err,
+                        }
+                        .into(),
+                    );
+                    continue;
+                }
+            };
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension() == Some("rs".as_ref())
+                && !files.iter().any(|(file, _)| destination.join(file) == path)
+            {
+                errors.push(UnexpectedOutputError { file_path: path }.into());
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::from_many(errors))
+    }
+}
+
+/// Checks that the file at `destination` matches `generated_code` byte for byte, without
+/// writing it. This lets CI fail when the committed generated code is stale.
+///
+/// The error gives the first diverging line and the generated module enclosing it. A missing
+/// file is reported as empty, other IO errors are returned as is.
+pub fn verify_generated_code(destination: &Path, generated_code: &str) -> Result<(), Error> {
+    log::info!("verifying generated code at `{}`", destination.display());
+    let current = match std::fs::read_to_string(destination) {
+        Ok(current) => current,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => {
+            return Err(ReadOutputError {
+                file_path: destination.to_owned(),
+                err,
+            }
+            .into())
+        }
+    };
+    if current == generated_code {
+        return Ok(());
+    }
+    let mut expected_lines = generated_code.lines();
+    let mut found_lines = current.lines();
+    // Modules opened before the diverging line, with the brace depth they were opened at
+    let mut modules: Vec<(&str, usize)> = Vec::new();
+    let mut depth = 0;
+    // Braces in string literals, like those of SQL arrays, do not open blocks
+    let mut in_string = false;
+    let mut line = 1;
+    let (expected, found) = loop {
+        match (expected_lines.next(), found_lines.next()) {
+            (Some(expected), Some(found)) if expected == found => {
+                let trimmed = expected.trim_start();
+                let name = trimmed
+                    .strip_prefix("pub mod ")
+                    .or_else(|| trimmed.strip_prefix("mod "))
+                    .and_then(|it| it.split(|c: char| c.is_whitespace() || c == '{').next());
+                if let Some(name) = name.filter(|_| expected.contains('{')) {
+                    modules.push((name, depth));
+                }
+                let mut chars = expected.chars();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' if in_string => {
+                            chars.next();
+                        }
+                        '"' => in_string = !in_string,
+                        '{' if !in_string => depth += 1,
+                        '}' if !in_string => {
+                            depth = depth.saturating_sub(1);
+                            modules.retain(|(_, opened)| *opened < depth);
+                        }
+                        _ => {}
+                    }
+                }
+                line += 1;
+            }
+            // Same lines, the files only differ by their line endings or final newline
+            (None, None) => {
+                let line_endings = generated_code.contains("\r\n") != current.contains("\r\n");
+                let describe = |code: &str| match (line_endings, code.contains("\r\n")) {
+                    (true, true) => "<\\r\\n line endings>",
+                    (true, false) => "<\\n line endings>",
+                    (false, _) if code.ends_with('\n') => "<trailing newline>",
+                    (false, _) => "<no trailing newline>",
+                };
+                break (
+                    describe(generated_code).to_string(),
+                    describe(&current).to_string(),
+                );
+            }
+            (expected, found) => {
+                break (
+                    expected.unwrap_or("<end of file>").trim().to_string(),
+                    found.unwrap_or("<end of file>").trim().to_string(),
+                )
+            }
+        }
+    };
+    let module = if modules.is_empty() {
+        String::new()
+    } else {
+        let path: Vec<_> = modules.iter().map(|(name, _)| *name).collect();
+        format!(" (in module `{}`)", path.join("::"))
+    };
+    Err(StaleOutputError {
+        file_path: destination.to_owned(),
+        line,
+        module,
+        expected,
+        found,
+    }
+    .into())
+}
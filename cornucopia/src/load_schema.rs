@@ -13,6 +13,7 @@ use self::error::Error;
 pub fn load_schema<P: AsRef<Path>>(client: &mut Client, paths: &[P]) -> Result<(), Error> {
     for path in paths {
         let path = path.as_ref();
+        log::info!("loading schema `{}`", path.display());
         let sql = std::fs::read_to_string(path).map_err(|err| Error::Io {
             path: path.to_string_lossy().to_string(),
             err,
@@ -40,6 +41,27 @@ pub fn load_schema<P: AsRef<Path>>(client: &mut Client, paths: &[P]) -> Result<(
     Ok(())
 }
 
+/// Resets a throwaway database by dropping and recreating its `public` schema.
+///
+/// Everything in the `public` schema is destroyed, so this is only meant for
+/// development and test databases. As a safeguard, `dbname` must match the
+/// name of the database `client` is connected to.
+pub fn reset_database(client: &mut Client, dbname: &str) -> Result<(), Error> {
+    let current: String = client
+        .query_one("SELECT current_database()", &[])
+        .map_err(Error::Reset)?
+        .get(0);
+    if current != dbname {
+        return Err(Error::ResetMismatch {
+            expected: dbname.to_string(),
+            actual: current,
+        });
+    }
+    client
+        .batch_execute("DROP SCHEMA public CASCADE;CREATE SCHEMA public;")
+        .map_err(Error::Reset)
+}
+
 pub(crate) mod error {
     use miette::{Diagnostic, NamedSource, SourceSpan};
     use thiserror::Error as ThisError;
@@ -58,5 +80,9 @@ pub(crate) mod error {
             #[label("error occurs near this location")]
             err_span: Option<SourceSpan>,
         },
+        #[error("Could not reset database: {0:#}")]
+        Reset(postgres::Error),
+        #[error("Refusing to reset database `{actual}`, expected `{expected}`")]
+        ResetMismatch { expected: String, actual: String },
     }
 }
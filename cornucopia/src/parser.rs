@@ -93,14 +93,54 @@ fn space() -> impl Parser<char, (), Error = Simple<char>> {
         .ignored()
 }
 
-fn blank() -> impl Parser<char, (), Error = Simple<char>> {
-    // We want to escape valid SQL comment beginning with -- while not escaping our syntax --: or --!
+/// Whether a comment, without its leading `--`, is a yesql query annotation
+fn is_yesql_name(comment: &str) -> bool {
+    comment.trim_start().starts_with("name:")
+}
+
+/// `:one` suffix of a query annotation, not followed by more identifier characters
+fn single_row() -> impl Parser<char, (), Error = Simple<char>> {
+    just(":one")
+        .then(
+            filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+                .not()
+                .rewind(),
+        )
+        .ignored()
+}
+
+/// Keywords starting a `--#` annotation, other `--#` lines are plain comments
+const ANNOTATION_KEYWORDS: &[&str] = &[
+    "row", "params", "column", "module", "default", "cache", "group", "explain", "execute", "setup",
+];
+
+/// Whether a comment, without its leading `--`, is a `--#` annotation
+fn is_annotation(comment: &str) -> bool {
+    let keyword = comment
+        .strip_prefix('#')
+        .and_then(|rest| rest.split_whitespace().next());
+    matches!(keyword, Some(keyword) if ANNOTATION_KEYWORDS.contains(&keyword))
+}
+
+fn blank(yesql: bool) -> impl Parser<char, (), Error = Simple<char>> {
+    // We want to escape valid SQL comment beginning with -- while not escaping our syntax --:, --!
+    // or the --# annotations
     let comment = just("--")
         .then(none_of(":!").rewind())
-        .then(none_of('\n').repeated());
+        .ignore_then(none_of('\n').repeated().collect::<String>())
+        // In yesql mode, `-- name:` starts a query
+        .try_map(move |comment, span| {
+            if is_annotation(&comment) {
+                Err(Simple::custom(span, "misplaced `--#` annotation"))
+            } else if yesql && is_yesql_name(&comment) {
+                Err(Simple::custom(span, "query annotation"))
+            } else {
+                Ok(())
+            }
+        });
     filter(|c: &char| c.is_whitespace())
         .ignored()
-        .or(comment.ignored())
+        .or(comment)
         .repeated()
         .ignored()
 }
@@ -108,20 +148,54 @@ fn blank() -> impl Parser<char, (), Error = Simple<char>> {
 #[derive(Debug, Clone)]
 pub struct NullableIdent {
     pub name: Span<String>,
-    pub nullable: bool,
+    /// `Some(true)` if marked `?`, `Some(false)` if marked `!`, `None` to use the module's default
+    pub nullable: Option<bool>,
     pub inner_nullable: bool,
+    /// Int enum declared with `--: enum` that the integer value is mapped to, given with
+    /// `name: Enum`
+    pub int_enum: Option<Span<String>>,
+    /// Postgres type of a param given with `name::type`, overriding the inferred one, e.g.
+    /// `id::int8`
+    pub pg_ty: Option<Span<String>>,
+}
+
+/// Postgres type name of a `::type` annotation, e.g. `int4`, `public.mood` or `text[]`
+fn pg_type() -> impl Parser<char, Span<String>, Error = Simple<char>> {
+    just("::").ignore_then(
+        filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+            .repeated()
+            .at_least(1)
+            .collect::<String>()
+            .then(just("[]").or_not())
+            .map_with_span(|(name, array), span: Range<usize>| Span {
+                value: format!("{name}{}", array.unwrap_or_default()),
+                span: span.into(),
+            }),
+    )
 }
 
 fn parse_nullable_ident() -> impl Parser<char, Vec<NullableIdent>, Error = Simple<char>> {
     space()
         .ignore_then(ident())
-        .then(just('?').or_not())
+        .then(pg_type().or_not())
+        .then(just('?').to(true).or(just('!').to(false)).or_not())
         .then(just("[?]").or_not())
-        .map(|((name, null), inner_null)| NullableIdent {
-            name,
-            nullable: null.is_some(),
-            inner_nullable: inner_null.is_some(),
-        })
+        .then(
+            space()
+                .ignore_then(just(':'))
+                .ignore_then(space())
+                .ignore_then(ident())
+                .or_not(),
+        )
+        .map(
+            |((((name, pg_ty), null), inner_null), int_enum)| NullableIdent {
+                name,
+                nullable: null,
+                inner_nullable: inner_null.is_some(),
+                int_enum,
+                pg_ty,
+            },
+        )
         .then_ignore(space())
         .separated_by(just(','))
         .allow_trailing()
@@ -145,14 +219,292 @@ impl TypeAnnotation {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct EnumAnnotation {
+    pub name: Span<String>,
+    pub variants: Vec<(Span<String>, i32)>,
+}
+
+impl EnumAnnotation {
+    fn discriminant() -> impl Parser<char, i32, Error = Simple<char>> {
+        just('-')
+            .or_not()
+            .chain::<char, _, _>(text::digits(10))
+            .collect::<String>()
+            .try_map(|value, span| {
+                value.parse().map_err(|_| {
+                    Simple::custom(span, format!("`{value}` is not a valid i32 discriminant"))
+                })
+            })
+    }
+
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        just("--:")
+            .ignore_then(space())
+            .ignore_then(just("enum"))
+            .ignore_then(
+                filter(|c: &char| c.is_whitespace() && *c != '\n')
+                    .repeated()
+                    .at_least(1),
+            )
+            .ignore_then(ident())
+            .then_ignore(space())
+            .then(
+                space()
+                    .ignore_then(ident())
+                    .then_ignore(space())
+                    .then_ignore(just('='))
+                    .then_ignore(space())
+                    .then(Self::discriminant())
+                    .then_ignore(space())
+                    .separated_by(just(','))
+                    .allow_trailing()
+                    .at_least(1)
+                    .delimited_by(just('('), just(')')),
+            )
+            .map(|(name, variants)| Self { name, variants })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ViewAnnotation {
+    pub name: Span<String>,
+    pub fields: Vec<NullableIdent>,
+}
+
+impl ViewAnnotation {
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        just("--:")
+            .ignore_then(space())
+            .ignore_then(just("view"))
+            .ignore_then(
+                filter(|c: &char| c.is_whitespace() && *c != '\n')
+                    .repeated()
+                    .at_least(1),
+            )
+            .ignore_then(ident())
+            .then_ignore(space())
+            .then(parse_nullable_ident().or_not())
+            .map(|(name, fields)| Self {
+                name,
+                fields: fields.unwrap_or_default(),
+            })
+    }
+}
+
+/// Generated struct an attribute is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttributeTarget {
+    Row,
+    Params,
+}
+
+/// A raw Rust attribute attached to a query's row or params struct
+#[derive(Debug, Clone)]
+pub(crate) struct QueryAttribute {
+    pub(crate) target: Span<AttributeTarget>,
+    pub(crate) value: Span<String>,
+}
+
+impl QueryAttribute {
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        just("--#")
+            .ignore_then(space())
+            .ignore_then(
+                just("row")
+                    .to(AttributeTarget::Row)
+                    .or(just("params").to(AttributeTarget::Params))
+                    .map_with_span(|value, span: Range<usize>| Span {
+                        value,
+                        span: span.into(),
+                    }),
+            )
+            .then_ignore(space())
+            .then(
+                none_of('\n')
+                    .repeated()
+                    .at_least(1)
+                    .collect::<String>()
+                    .map_with_span(|value: String, span: Range<usize>| Span {
+                        value: value.trim_end().to_string(),
+                        span: span.into(),
+                    }),
+            )
+            .map(|(target, value)| Self { target, value })
+    }
+}
+
+/// A row column converted to a custom Rust type, e.g. `--# column status: crate::Status = crate::Status::parse`
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnMapping {
+    pub(crate) column: Span<String>,
+    pub(crate) ty: Span<String>,
+    pub(crate) map: Span<String>,
+}
+
+impl ColumnMapping {
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        let rust_path = |stop: &'static str| {
+            none_of(stop)
+                .repeated()
+                .at_least(1)
+                .collect::<String>()
+                .map_with_span(|value: String, span: Range<usize>| Span {
+                    value: value.trim_end().to_string(),
+                    span: span.into(),
+                })
+        };
+        just("--#")
+            .ignore_then(space())
+            .ignore_then(just("column"))
+            .ignore_then(space())
+            .ignore_then(ident())
+            .then_ignore(space())
+            .then_ignore(just(':'))
+            .then_ignore(space())
+            .then(rust_path("=\n"))
+            .then_ignore(just('='))
+            .then_ignore(space())
+            .then(rust_path("\n"))
+            .map(|((column, ty), map)| Self { column, ty, map })
+    }
+}
+
+/// A raw Rust attribute attached to the generated module, e.g. `--# module cfg(feature = "x")`
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleAttribute {
+    pub(crate) value: Span<String>,
+}
+
+impl ModuleAttribute {
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        just("--#")
+            .ignore_then(space())
+            .ignore_then(just("module"))
+            .ignore_then(
+                filter(|c: &char| c.is_whitespace() && *c != '\n')
+                    .repeated()
+                    .at_least(1),
+            )
+            .ignore_then(
+                none_of('\n')
+                    .repeated()
+                    .at_least(1)
+                    .collect::<String>()
+                    .map_with_span(|value: String, span: Range<usize>| Span {
+                        value: value.trim_end().to_string(),
+                        span: span.into(),
+                    }),
+            )
+            .map(|value| Self { value })
+    }
+}
+
+/// Default nullability of the columns of the module's queries, e.g. `--# default nullable`
+fn nullable_default() -> impl Parser<char, Span<bool>, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("default"))
+        .ignore_then(space())
+        .ignore_then(just("nullable").to(true).or(just("non_null").to(false)))
+        .map_with_span(|value, span: Range<usize>| Span {
+            value,
+            span: span.into(),
+        })
+        .then_ignore(space())
+}
+
+/// Key column of a query cached in memory, e.g. `--# cache id`
+fn cache_key() -> impl Parser<char, Span<String>, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("cache"))
+        .ignore_then(space())
+        .ignore_then(ident())
+        .then_ignore(space())
+}
+
+/// Row columns nested under a field of the same name as their prefix, e.g. `--# group author`
+fn group() -> impl Parser<char, Span<String>, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("group"))
+        .ignore_then(space())
+        .ignore_then(ident())
+        .then_ignore(space())
+}
+
+/// Request of a companion query returning the plan of the query, e.g. `--# explain`
+fn explain() -> impl Parser<char, SourceSpan, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("explain"))
+        .map_with_span(|_, span: Range<usize>| span.into())
+        .then_ignore(space())
+}
+
+/// Forces a query returning rows to be executed, discarding them, e.g. `--# execute`
+fn execute() -> impl Parser<char, SourceSpan, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("execute"))
+        .map_with_span(|_, span: Range<usize>| span.into())
+        .then_ignore(space())
+}
+
+/// SQL run in the session before preparing a query, e.g. `--# setup CREATE TEMP TABLE tmp (id INT);`
+fn setup() -> impl Parser<char, Span<String>, Error = Simple<char>> {
+    just("--#")
+        .ignore_then(space())
+        .ignore_then(just("setup"))
+        .ignore_then(
+            filter(|c: &char| c.is_whitespace() && *c != '\n')
+                .repeated()
+                .at_least(1),
+        )
+        .ignore_then(
+            none_of('\n')
+                .repeated()
+                .at_least(1)
+                .collect::<String>()
+                .map_with_span(|value: String, span: Range<usize>| Span {
+                    value: value.trim_end().to_string(),
+                    span: span.into(),
+                }),
+        )
+}
+
+/// Annotation lines following a query declaration
+enum QueryLine {
+    Attribute(QueryAttribute),
+    Mapping(ColumnMapping),
+    Cache(Span<String>),
+    Group(Span<String>),
+    Explain(SourceSpan),
+    Execute(SourceSpan),
+    Setup(Span<String>),
+}
+
 #[derive(Debug)]
 pub(crate) struct Query {
     pub(crate) name: Span<String>,
     pub(crate) param: QueryDataStruct,
     pub(crate) row: QueryDataStruct,
+    pub(crate) attributes: Vec<QueryAttribute>,
+    pub(crate) mappings: Vec<ColumnMapping>,
+    pub(crate) cache: Option<Span<String>>,
+    /// Prefixes of the `--# group` annotations, nesting row columns under a field
+    pub(crate) groups: Vec<Span<String>>,
     pub(crate) sql_span: SourceSpan,
     pub(crate) sql_str: String,
     pub(crate) bind_params: Vec<Span<String>>,
+    pub(crate) single_row: bool,
+    /// Span of the `--# explain` annotation, if any
+    pub(crate) explain: Option<SourceSpan>,
+    /// Span of the `--# execute` annotation, if any
+    pub(crate) execute: Option<SourceSpan>,
+    /// Lines of the `--# setup` annotations, run in the session before preparing the query
+    pub(crate) setup: Vec<Span<String>>,
 }
 
 impl Query {
@@ -210,37 +562,61 @@ impl Query {
             .allow_trailing()
     }
 
-    /// Parse sql query, normalizing named parameters
+    /// Parse sql query and its named parameters
     fn parse_sql_query(
     ) -> impl Parser<char, (String, SourceSpan, Vec<Span<String>>), Error = Simple<char>> {
         none_of(";")
             .repeated()
             .then_ignore(just(';'))
             .collect::<String>()
-            .map_with_span(|mut sql_str, span: Range<usize>| {
+            .map_with_span(|sql_str, span: Range<usize>| {
                 let bind_params: Vec<_> = Self::parse_bind().parse(sql_str.clone()).unwrap();
-                // Remove duplicate
-                let dedup_params: Vec<_> = bind_params
-                    .iter()
-                    .enumerate()
-                    .rev()
-                    .filter_map(|(i, u)| (!bind_params[..i].contains(u)).then(|| u.clone()))
-                    .rev()
-                    .collect();
-
-                for bind_param in bind_params.iter().rev() {
-                    let index = dedup_params.iter().position(|bp| bp == bind_param).unwrap();
-                    let start = bind_param.span.offset() - 1;
-                    let end = start + bind_param.span.len();
-                    sql_str.replace_range(start..=end, &format!("${}", index + 1));
-                }
-
-                (sql_str, span.into(), dedup_params)
+                (sql_str, span.into(), bind_params)
             })
     }
 
+    /// Replace named parameters by positional ones, casting the first occurrence of
+    /// the params whose type is annotated
+    fn normalize_sql(
+        mut sql_str: String,
+        bind_params: Vec<Span<String>>,
+        param: &QueryDataStruct,
+    ) -> (String, Vec<Span<String>>) {
+        // Remove duplicate
+        let dedup_params: Vec<_> = bind_params
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, u)| (!bind_params[..i].contains(u)).then(|| u.clone()))
+            .rev()
+            .collect();
+        let pg_ty = |name: &str| {
+            param
+                .idents
+                .iter()
+                .flatten()
+                .find(|it| it.name.value == name)
+                .and_then(|it| it.pg_ty.as_ref())
+        };
+
+        for bind_param in bind_params.iter().rev() {
+            let index = dedup_params.iter().position(|bp| bp == bind_param).unwrap();
+            let start = bind_param.span.offset() - 1;
+            let end = start + bind_param.span.len();
+            let is_first = dedup_params[index].span.offset() == bind_param.span.offset();
+            let replacement = match pg_ty(&bind_param.value) {
+                Some(ty) if is_first => format!("(${}::{})", index + 1, ty.value),
+                _ => format!("${}", index + 1),
+            };
+            sql_str.replace_range(start..=end, &replacement);
+        }
+
+        (sql_str, dedup_params)
+    }
+
+    #[allow(clippy::type_complexity)]
     fn parse_query_annotation(
-    ) -> impl Parser<char, (Span<String>, QueryDataStruct, QueryDataStruct), Error = Simple<char>>
+    ) -> impl Parser<char, (Span<String>, QueryDataStruct, QueryDataStruct, bool), Error = Simple<char>>
     {
         just("--!")
             .ignore_then(space())
@@ -249,33 +625,155 @@ impl Query {
             .then(QueryDataStruct::parser())
             .then_ignore(space())
             .then(
-                just(':')
-                    .ignore_then(space())
-                    .ignore_then(QueryDataStruct::parser())
+                // `:one` marks a query expected to return a single row. It is tried first so
+                // that it is not read as a row struct named `one`
+                single_row()
+                    .to((QueryDataStruct::default(), true))
+                    .or(just(':')
+                        .ignore_then(space())
+                        .ignore_then(QueryDataStruct::parser())
+                        .then_ignore(space())
+                        .then(single_row().or_not())
+                        .map(|(row, one)| (row, one.is_some())))
                     .or_not(),
             )
-            .map(|((name, param), row)| (name, param, row.unwrap_or_default()))
+            .map(|((name, param), row)| {
+                let (row, single_row) = row.unwrap_or_default();
+                (name, param, row, single_row)
+            })
     }
 
-    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+    /// Parse a yesql/aiosql `-- name: <ident>` annotation, `^` and `$` suffixes marking
+    /// a query returning a single row
+    #[allow(clippy::type_complexity)]
+    fn parse_yesql_annotation(
+    ) -> impl Parser<char, (Span<String>, QueryDataStruct, QueryDataStruct, bool), Error = Simple<char>>
+    {
+        let name = filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+            .repeated()
+            .at_least(1)
+            .collect::<String>()
+            .map_with_span(|value, span: Range<usize>| Span {
+                // yesql names are often kebab-case
+                value: value.replace('-', "_"),
+                span: span.into(),
+            });
+        let suffix = choice((
+            just("<!"),
+            just("*!"),
+            just("^"),
+            just("$"),
+            just("!"),
+            just("#"),
+        ));
+        just("--")
+            .ignore_then(space())
+            .ignore_then(just("name:"))
+            .ignore_then(space())
+            .ignore_then(name)
+            .then(suffix.or_not())
+            .map(|(name, suffix)| {
+                let single_row = matches!(suffix, Some("^" | "$"));
+                (
+                    name,
+                    QueryDataStruct::default(),
+                    QueryDataStruct::default(),
+                    single_row,
+                )
+            })
+    }
+
+    fn parser(yesql: bool) -> impl Parser<char, Self, Error = Simple<char>> {
         Self::parse_query_annotation()
+            .or(Self::parse_yesql_annotation().try_map(move |it, span| {
+                if yesql {
+                    Ok(it)
+                } else {
+                    Err(Simple::custom(span, "yesql annotations are not enabled"))
+                }
+            }))
             .then_ignore(space())
             .then_ignore(ln())
+            .then(
+                QueryAttribute::parser()
+                    .map(QueryLine::Attribute)
+                    .or(ColumnMapping::parser().map(QueryLine::Mapping))
+                    .or(cache_key().map(QueryLine::Cache))
+                    .or(group().map(QueryLine::Group))
+                    .or(explain().map(QueryLine::Explain))
+                    .or(execute().map(QueryLine::Execute))
+                    .or(setup().map(QueryLine::Setup))
+                    .then_ignore(ln())
+                    .repeated(),
+            )
             .then(Self::parse_sql_query())
             .map(
-                |((name, param, row), (sql_str, sql_span, bind_params))| Self {
-                    name,
-                    param,
-                    row,
-                    sql_span,
-                    sql_str,
-                    bind_params,
+                |(((name, param, row, single_row), lines), (sql_str, sql_span, bind_params))| {
+                    let (sql_str, bind_params) = Self::normalize_sql(sql_str, bind_params, &param);
+                    let mut attributes = Vec::new();
+                    let mut mappings = Vec::new();
+                    let mut cache = None;
+                    let mut groups = Vec::new();
+                    let mut explain = None;
+                    let mut execute = None;
+                    let mut setup = Vec::new();
+                    for line in lines {
+                        match line {
+                            QueryLine::Attribute(it) => attributes.push(it),
+                            QueryLine::Mapping(it) => mappings.push(it),
+                            QueryLine::Cache(it) => cache = Some(it),
+                            QueryLine::Group(it) => groups.push(it),
+                            QueryLine::Explain(it) => explain = Some(it),
+                            QueryLine::Execute(it) => execute = Some(it),
+                            QueryLine::Setup(it) => setup.push(it),
+                        }
+                    }
+                    Self {
+                        name,
+                        param,
+                        row,
+                        attributes,
+                        mappings,
+                        cache,
+                        groups,
+                        sql_span,
+                        sql_str,
+                        bind_params,
+                        single_row,
+                        explain,
+                        execute,
+                        setup,
+                    }
                 },
             )
     }
+
+    /// Companion query `<name>_explain` returning the JSON plan of this query, if requested
+    pub(crate) fn explain_query(&self) -> Option<Query> {
+        let span = self.explain?;
+        Some(Query {
+            name: Span {
+                value: format!("{}_explain", self.name.value),
+                span,
+            },
+            param: self.param.clone(),
+            row: QueryDataStruct::default(),
+            attributes: Vec::new(),
+            mappings: Vec::new(),
+            cache: None,
+            groups: Vec::new(),
+            sql_span: self.sql_span,
+            sql_str: format!("EXPLAIN (FORMAT JSON) {}", self.sql_str.trim_start()),
+            bind_params: self.bind_params.clone(),
+            single_row: false,
+            explain: None,
+            execute: None,
+            setup: self.setup.clone(),
+        })
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct QueryDataStruct {
     pub span: SourceSpan,
     pub name: Option<Span<String>>,
@@ -355,22 +853,43 @@ impl QueryDataStruct {
 
 #[derive(Debug)]
 enum Statement {
+    Enum(EnumAnnotation),
+    View(ViewAnnotation),
     Type(TypeAnnotation),
-    Query(Query),
+    Attribute(ModuleAttribute),
+    NullableDefault(Span<bool>),
+    Query(Box<Query>),
 }
 
 #[derive(Debug)]
 pub(crate) struct Module {
     pub(crate) info: ModuleInfo,
     pub(crate) types: Vec<TypeAnnotation>,
+    pub(crate) enums: Vec<EnumAnnotation>,
+    pub(crate) views: Vec<ViewAnnotation>,
+    pub(crate) attributes: Vec<ModuleAttribute>,
+    /// Declarations of the default nullability of query columns, at most one is valid
+    pub(crate) nullable_defaults: Vec<Span<bool>>,
     pub(crate) queries: Vec<Query>,
 }
 
-pub(crate) fn parse_query_module(info: ModuleInfo) -> Result<Module, Error> {
-    match TypeAnnotation::parser()
-        .map(Statement::Type)
-        .or(Query::parser().map(Statement::Query))
-        .separated_by(blank())
+impl Module {
+    /// Whether the columns of this module's queries are nullable unless marked otherwise
+    pub(crate) fn nullable_default(&self) -> bool {
+        self.nullable_defaults.iter().any(|it| it.value)
+    }
+}
+
+/// Parses a query module, also recognizing yesql/aiosql `-- name:` query annotations if `yesql`
+pub(crate) fn parse_query_module(info: ModuleInfo, yesql: bool) -> Result<Module, Error> {
+    match EnumAnnotation::parser()
+        .map(Statement::Enum)
+        .or(ViewAnnotation::parser().map(Statement::View))
+        .or(TypeAnnotation::parser().map(Statement::Type))
+        .or(ModuleAttribute::parser().map(Statement::Attribute))
+        .or(nullable_default().map(Statement::NullableDefault))
+        .or(Query::parser(yesql).map(|it| Statement::Query(Box::new(it))))
+        .separated_by(blank(yesql))
         .allow_leading()
         .allow_trailing()
         .then_ignore(end())
@@ -378,24 +897,48 @@ pub(crate) fn parse_query_module(info: ModuleInfo) -> Result<Module, Error> {
     {
         Ok(statements) => {
             let mut types = Vec::new();
+            let mut enums = Vec::new();
+            let mut views = Vec::new();
+            let mut attributes = Vec::new();
+            let mut nullable_defaults = Vec::new();
             let mut queries = Vec::new();
             for item in statements {
                 match item {
+                    Statement::Enum(it) => enums.push(it),
+                    Statement::View(it) => views.push(it),
                     Statement::Type(it) => types.push(it),
-                    Statement::Query(it) => queries.push(it),
+                    Statement::Attribute(it) => attributes.push(it),
+                    Statement::NullableDefault(it) => nullable_defaults.push(it),
+                    Statement::Query(it) => queries.push(*it),
                 }
             }
             Ok(Module {
                 info,
                 types,
+                enums,
+                views,
+                attributes,
+                nullable_defaults,
                 queries,
             })
         }
-        Err(e) => Err(Error {
-            src: (&info).into(),
-            err_span: e[0].span().into(),
-            help: e[0].to_string().replace('\n', "\\n"),
-        }),
+        Err(e) => {
+            let yesql_names = info
+                .content
+                .lines()
+                .any(|line| line.starts_with("--") && is_yesql_name(&line[2..]));
+            let help = if !yesql && yesql_names {
+                "`-- name:` query annotations are only recognized with the yesql setting"
+                    .to_string()
+            } else {
+                e[0].to_string().replace('\n', "\\n")
+            };
+            Err(Error {
+                src: (&info).into(),
+                err_span: e[0].span().into(),
+                help,
+            })
+        }
     }
 }
 
@@ -1,18 +1,23 @@
-use std::rc::Rc;
+use std::{rc::Rc, time::Instant};
 
 use heck::ToUpperCamelCase;
 use indexmap::{map::Entry, IndexMap};
-use postgres::Client;
+use miette::SourceSpan;
 use postgres_types::{Kind, Type};
 
 use crate::{
+    catalog::{Catalog, CatalogError, Column},
     codegen::GenCtx,
-    parser::{Module, NullableIdent, Query, Span, TypeAnnotation},
+    parser::{
+        AttributeTarget, EnumAnnotation, Module, NullableIdent, Query, QueryAttribute,
+        QueryDataStruct, Span, TypeAnnotation, ViewAnnotation,
+    },
     read_queries::ModuleInfo,
     type_registrar::CornucopiaType,
     type_registrar::TypeRegistrar,
     utils::KEYWORD,
-    validation,
+    validation::{self, error::Warning},
+    CodegenSettings,
 };
 
 use self::error::Error;
@@ -25,6 +30,8 @@ pub(crate) struct PreparedQuery {
     pub(crate) param: Option<(usize, Vec<usize>)>,
     pub(crate) row: Option<(usize, Vec<usize>)>,
     pub(crate) sql: String,
+    /// Key column of the in-memory cache generated for this query
+    pub(crate) cache: Option<String>,
 }
 
 /// A normalized ident replacing all non-alphanumeric characters with an underscore (`_`)
@@ -76,11 +83,14 @@ impl PreparedField {
         db_ident: String,
         ty: Rc<CornucopiaType>,
         nullity: Option<&NullableIdent>,
+        nullable_default: bool,
     ) -> Self {
         Self {
             ident: Ident::new(db_ident),
             ty,
-            is_nullable: nullity.map_or(false, |it| it.nullable),
+            is_nullable: nullity
+                .and_then(|it| it.nullable)
+                .unwrap_or(nullable_default),
             is_inner_nullable: nullity.map_or(false, |it| it.inner_nullable),
         }
     }
@@ -88,7 +98,13 @@ impl PreparedField {
 
 impl PreparedField {
     pub fn unwrapped_name(&self) -> String {
-        self.own_struct(&GenCtx::new(0, false, false))
+        let field = PreparedField {
+            ty: Rc::new(self.ty.with_numeric_name()),
+            ..self.clone()
+        };
+        let own = field.own_struct(&GenCtx::new(0, CodegenSettings::default()));
+        // Client wrapper types are named after themselves
+        own.trim_start_matches("cornucopia_sync::")
             .replace(['<', '>', '_'], "")
             .to_upper_camel_case()
     }
@@ -99,8 +115,33 @@ pub(crate) struct PreparedItem {
     pub(crate) name: Span<String>,
     pub(crate) fields: Vec<PreparedField>,
     pub(crate) is_copy: bool,
+    /// All fields can be hashed
+    pub(crate) is_hash: bool,
     pub(crate) is_named: bool,
     pub(crate) is_ref: bool,
+    /// Raw attributes emitted on the generated struct
+    pub(crate) attributes: Vec<String>,
+    /// Where the attributes were first declared, queries sharing this struct must declare the same
+    pub(crate) attributes_pos: Option<SourceSpan>,
+    /// Columns nested under a field of the struct, declared by `--# group`
+    pub(crate) groups: Vec<PreparedGroup>,
+}
+
+/// Row columns sharing a prefix, generated as a struct nested under a field of their row
+#[derive(Debug, Clone)]
+pub(crate) struct PreparedGroup {
+    /// Field of the row holding the group, named after the prefix
+    pub(crate) field: Ident,
+    /// Struct of the group, whose fields are the columns without their prefix
+    pub(crate) item: PreparedItem,
+    /// Indexes of the grouped columns among the row's fields
+    pub(crate) columns: Vec<usize>,
+}
+
+/// A field of a row struct, either a column or a group of columns
+pub(crate) enum RowMember<'a> {
+    Column(&'a PreparedField, usize),
+    Group(&'a PreparedGroup),
 }
 
 impl PreparedItem {
@@ -108,8 +149,12 @@ impl PreparedItem {
         Self {
             name,
             is_copy: fields.iter().all(|f| f.ty.is_copy()),
+            is_hash: fields.iter().all(|f| f.ty.is_hash()),
             is_ref: fields.iter().any(|f| f.ty.is_ref()),
             is_named: !is_implicit || fields.len() > 1,
+            attributes: Vec::new(),
+            attributes_pos: None,
+            groups: Vec::new(),
             fields,
         }
     }
@@ -117,6 +162,40 @@ impl PreparedItem {
     pub fn path(&self, ctx: &GenCtx) -> String {
         ctx.path(ctx.depth - 2, &self.name)
     }
+
+    /// Group nesting the field at this index, if any
+    pub(crate) fn group_of(&self, idx: usize) -> Option<&PreparedGroup> {
+        self.groups.iter().find(|it| it.columns.contains(&idx))
+    }
+
+    /// Fields of the generated struct, a group taking the place of its first column
+    pub(crate) fn members(&self) -> Vec<RowMember<'_>> {
+        let mut members = Vec::new();
+        for (idx, field) in self.fields.iter().enumerate() {
+            match self.group_of(idx) {
+                Some(group) if group.columns[0] == idx => members.push(RowMember::Group(group)),
+                Some(_) => {}
+                None => members.push(RowMember::Column(field, idx)),
+            }
+        }
+        members
+    }
+
+    /// Expression accessing the field at this index from a `row` variable
+    pub(crate) fn field_access(&self, idx: usize) -> String {
+        match self.group_of(idx) {
+            Some(group) => {
+                let pos = group.columns.iter().position(|it| *it == idx).unwrap();
+                format!("row.{}.{}", group.field.rs, group.item.fields[pos].ident.rs)
+            }
+            None => format!("row.{}", self.fields[idx].ident.rs),
+        }
+    }
+
+    /// Does this item contain fields that can borrow using `Cow`
+    pub fn is_cow(&self) -> bool {
+        self.fields.iter().any(|f| f.ty.cow_ty().is_some())
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -134,20 +213,54 @@ pub(crate) enum PreparedContent {
     Composite(Vec<PreparedField>),
 }
 
+/// An enum mapped from an integer column, declared in a query module.
+#[derive(Debug, Clone)]
+pub(crate) struct PreparedIntEnum {
+    pub(crate) name: Span<String>,
+    pub(crate) variants: Vec<(Ident, i32)>,
+    /// Type of the columns it is mapped from, `smallint` or `integer`
+    pub(crate) pg_ty: Option<Type>,
+}
+
 /// A struct containing the module name and the list of all
 /// the queries it contains.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub(crate) struct PreparedModule {
     pub(crate) info: ModuleInfo,
+    pub(crate) enums: Vec<PreparedIntEnum>,
     pub(crate) queries: IndexMap<Span<String>, PreparedQuery>,
     pub(crate) params: IndexMap<Span<String>, PreparedItem>,
     pub(crate) rows: IndexMap<Span<String>, PreparedItem>,
+    /// Raw attributes emitted on the generated module
+    pub(crate) attributes: Vec<String>,
+    pub(crate) warnings: Vec<Warning>,
 }
 
+/// A foreign key constraint discovered in the database schema.
 #[derive(Debug, Clone)]
+pub(crate) struct PreparedForeignKey {
+    pub(crate) name: String,
+    pub(crate) table: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) foreign_schema: String,
+    pub(crate) foreign_table: String,
+    pub(crate) foreign_columns: Vec<String>,
+}
+
+impl PreparedForeignKey {
+    /// Name of the generated constant describing this foreign key
+    pub(crate) fn const_name(&self) -> String {
+        self.name
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+            .to_uppercase()
+    }
+}
+
+#[derive(Debug)]
 pub(crate) struct Preparation {
     pub(crate) modules: Vec<PreparedModule>,
     pub(crate) types: IndexMap<String, Vec<PreparedType>>,
+    pub(crate) foreign_keys: IndexMap<String, Vec<PreparedForeignKey>>,
 }
 
 impl PreparedModule {
@@ -206,12 +319,111 @@ impl PreparedModule {
         Self::add(&self.info, &mut self.params, name, fields, is_implicit)
     }
 
+    /// Attach the raw attributes of a query to the structs they target
+    fn add_attributes(
+        &mut self,
+        query_name: &Span<String>,
+        attributes: &[QueryAttribute],
+        param_idx: Option<&(usize, Vec<usize>)>,
+        row_idx: Option<&(usize, Vec<usize>)>,
+    ) -> Result<(), Error> {
+        for (target, map, idx) in [
+            (AttributeTarget::Row, &mut self.rows, row_idx),
+            (AttributeTarget::Params, &mut self.params, param_idx),
+        ] {
+            let declared: Vec<_> = attributes
+                .iter()
+                .filter(|it| it.target.value == target)
+                .collect();
+            if declared.is_empty() {
+                continue;
+            }
+            let item = idx
+                .map(|(idx, _)| &mut map[*idx])
+                .filter(|item| item.is_named);
+            for attribute in &declared {
+                validation::attribute_without_struct(
+                    &self.info,
+                    query_name,
+                    attribute,
+                    item.is_some(),
+                )?;
+            }
+            let item = item.unwrap();
+            let mut values = Vec::new();
+            for attribute in &declared {
+                if !values.contains(&attribute.value.value) {
+                    values.push(attribute.value.value.clone());
+                }
+            }
+            let pos = declared[0].target.span;
+            match item.attributes_pos {
+                // Another query sharing this struct already declared its attributes
+                Some(prev) => validation::conflicting_attributes(
+                    &self.info,
+                    &item.name.value,
+                    (prev, &item.attributes),
+                    (pos, &values),
+                )?,
+                None => {
+                    item.attributes = values;
+                    item.attributes_pos = Some(pos);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Nest the row columns prefixed by the group name under a field of the row struct
+    fn add_group(
+        &mut self,
+        query_name: &Span<String>,
+        group: &Span<String>,
+        row_idx: Option<&(usize, Vec<usize>)>,
+    ) -> Result<(), Error> {
+        let item = row_idx
+            .map(|(idx, _)| &mut self.rows[*idx])
+            .filter(|item| item.is_named);
+        validation::group_without_struct(&self.info, query_name, group, item.is_some())?;
+        let item = item.unwrap();
+        if item.groups.iter().any(|it| it.field.db == group.value) {
+            return Ok(());
+        }
+        let prefix = format!("{}_", group.value);
+        let columns: Vec<_> = (0..item.fields.len())
+            .filter(|i| item.group_of(*i).is_none())
+            .filter(|i| {
+                let db = &item.fields[*i].ident.db;
+                db.len() > prefix.len() && db.starts_with(&prefix)
+            })
+            .collect();
+        validation::group_columns(&self.info, group, &item.fields, &columns)?;
+        let fields = columns
+            .iter()
+            .map(|i| {
+                let field = &item.fields[*i];
+                PreparedField {
+                    ident: Ident::new(field.ident.db[prefix.len()..].to_string()),
+                    ..field.clone()
+                }
+            })
+            .collect();
+        let name = group.map(|it| format!("{}{}", item.name.value, it.to_upper_camel_case()));
+        item.groups.push(PreparedGroup {
+            field: Ident::new(group.value.clone()),
+            item: PreparedItem::new(name, fields, false),
+            columns,
+        });
+        Ok(())
+    }
+
     fn add_query(
         &mut self,
         name: Span<String>,
         param_idx: Option<(usize, Vec<usize>)>,
         row_idx: Option<(usize, Vec<usize>)>,
         sql: String,
+        cache: Option<String>,
     ) {
         self.queries.insert(
             name.clone(),
@@ -220,17 +432,55 @@ impl PreparedModule {
                 row: row_idx,
                 sql,
                 param: param_idx,
+                cache,
             },
         );
     }
 }
 
-/// Prepares all modules
-pub(crate) fn prepare(client: &mut Client, modules: Vec<Module>) -> Result<Preparation, Error> {
-    let mut registrar = TypeRegistrar::default();
+/// Prepares all modules, reporting the errors of every query instead of stopping at the first one
+pub(crate) fn prepare(
+    catalog: &mut dyn Catalog,
+    modules: Vec<Module>,
+    settings: CodegenSettings,
+) -> Result<Preparation, Vec<Error>> {
+    if let Some(timeout) = settings.statement_timeout {
+        catalog
+            .batch_execute(&format!("SET statement_timeout = {timeout}"))
+            .map_err(|e| vec![Error::StatementTimeoutSetup(e)])?;
+    }
+    let mut errors = Vec::new();
+    let preparation = prepare_modules(catalog, modules, settings, &mut errors);
+    if settings.statement_timeout.is_some() {
+        if let Err(e) = catalog.batch_execute("RESET statement_timeout") {
+            errors.push(Error::StatementTimeoutSetup(e));
+        }
+    }
+    if errors.is_empty() {
+        Ok(preparation)
+    } else {
+        Err(errors)
+    }
+}
+
+fn prepare_modules(
+    catalog: &mut dyn Catalog,
+    modules: Vec<Module>,
+    settings: CodegenSettings,
+    errors: &mut Vec<Error>,
+) -> Preparation {
+    let mut registrar = TypeRegistrar {
+        numeric_as_f64: settings.numeric_as_f64,
+        ordered_floats: settings.ordered_floats,
+        text_as_bytes: settings.text_as_bytes,
+        infinite_timestamps: settings.infinite_timestamps,
+        time_backend: settings.time_backend,
+        ..Default::default()
+    };
     let mut tmp = Preparation {
         modules: Vec::new(),
         types: IndexMap::new(),
+        foreign_keys: IndexMap::new(),
     };
     let declared: Vec<_> = modules
         .iter()
@@ -239,8 +489,9 @@ pub(crate) fn prepare(client: &mut Client, modules: Vec<Module>) -> Result<Prepa
         .collect();
 
     for module in modules {
-        tmp.modules
-            .push(prepare_module(client, module, &mut registrar)?);
+        if let Some(module) = prepare_module(catalog, module, &mut registrar, errors) {
+            tmp.modules.push(module);
+        }
     }
 
     // Prepare types grouped by schema
@@ -256,11 +507,48 @@ pub(crate) fn prepare(client: &mut Client, modules: Vec<Module>) -> Result<Prepa
             }
         }
     }
-    Ok(tmp)
+    // Types are registered in the order queries use them, sort them so the generated
+    // code only changes when the types themselves do
+    tmp.types.sort_keys();
+    for types in tmp.types.values_mut() {
+        types.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    if settings.gen_foreign_keys {
+        match prepare_foreign_keys(catalog) {
+            Ok(foreign_keys) => tmp.foreign_keys = foreign_keys,
+            Err(e) => errors.push(e),
+        }
+    }
+    tmp
 }
 
-fn normalize_rust_name(name: &str) -> String {
-    name.replace(':', "_")
+/// Reads all foreign key constraints, grouped by schema
+fn prepare_foreign_keys(
+    catalog: &mut dyn Catalog,
+) -> Result<IndexMap<String, Vec<PreparedForeignKey>>, Error> {
+    let columns = catalog.foreign_keys().map_err(Error::ForeignKeys)?;
+
+    let mut foreign_keys: IndexMap<String, Vec<PreparedForeignKey>> = IndexMap::new();
+    for column in columns {
+        let keys = foreign_keys.entry(column.schema).or_default();
+        // Multi-column constraints span several consecutive rows
+        match keys.last_mut() {
+            Some(last) if last.name == column.constraint && last.table == column.table => {
+                last.columns.push(column.column);
+                last.foreign_columns.push(column.foreign_column);
+            }
+            _ => keys.push(PreparedForeignKey {
+                name: column.constraint,
+                table: column.table,
+                columns: vec![column.column],
+                foreign_schema: column.foreign_schema,
+                foreign_table: column.foreign_table,
+                foreign_columns: vec![column.foreign_column],
+            }),
+        }
+    }
+    Ok(foreign_keys)
 }
 
 /// Prepares database custom types
@@ -297,6 +585,7 @@ fn prepare_type(
                             field.name().to_string(),
                             registrar.ref_of(field.type_()),
                             nullity,
+                            false,
                         )
                     })
                     .collect(),
@@ -315,65 +604,252 @@ fn prepare_type(
     }
 }
 
+/// Resolves a field mapped to an int-coded enum declared in this module
+fn int_enum(
+    info: &ModuleInfo,
+    enums: &[EnumAnnotation],
+    prepared: &mut [PreparedIntEnum],
+    enum_name: &Span<String>,
+    ty: &Type,
+) -> Result<Rc<CornucopiaType>, Error> {
+    validation::reference_unknown_enum(info, enum_name, enums)?;
+    let prepared = prepared
+        .iter_mut()
+        .find(|it| it.name.value == enum_name.value)
+        .unwrap();
+    validation::int_enum_column_type(info, enum_name, prepared, ty)?;
+    prepared.pg_ty = Some(ty.clone());
+    Ok(Rc::new(CornucopiaType::IntEnum {
+        pg_ty: ty.clone(),
+        enum_name: enum_name.value.clone(),
+    }))
+}
+
 /// Prepares all queries in this module
+///
+/// Errors are pushed to `errors` so that the remaining queries are still prepared. Returns `None`
+/// if the module itself is invalid.
 fn prepare_module(
-    client: &mut Client,
-    module: Module,
+    catalog: &mut dyn Catalog,
+    mut module: Module,
     registrar: &mut TypeRegistrar,
-) -> Result<PreparedModule, Error> {
-    validation::validate_module(&module)?;
+    errors: &mut Vec<Error>,
+) -> Option<PreparedModule> {
+    log::info!("preparing module `{}`", module.info.name);
+    // Queries annotated with `--# explain` are followed by their plan query
+    module.queries = module
+        .queries
+        .into_iter()
+        .flat_map(|query| {
+            let explain = query.explain_query();
+            std::iter::once(query).chain(explain)
+        })
+        .collect();
+    if let Err(e) = validation::validate_module(&module) {
+        errors.push(e.into());
+        return None;
+    }
 
+    let scope = ModuleScope {
+        info: &module.info,
+        types: &module.types,
+        enums: &module.enums,
+        nullable_default: module.nullable_default(),
+    };
     let mut tmp_prepared_module = PreparedModule {
         info: module.info.clone(),
+        enums: module
+            .enums
+            .iter()
+            .map(|it| PreparedIntEnum {
+                name: it.name.clone(),
+                variants: it
+                    .variants
+                    .iter()
+                    .map(|(name, value)| (Ident::new(name.value.clone()), *value))
+                    .collect(),
+                pg_ty: None,
+            })
+            .collect(),
         queries: IndexMap::new(),
         params: IndexMap::new(),
         rows: IndexMap::new(),
+        attributes: module
+            .attributes
+            .iter()
+            .map(|it| it.value.value.clone())
+            .collect(),
+        warnings: Vec::new(),
     };
 
-    for query in module.queries {
-        prepare_query(
-            client,
-            &mut tmp_prepared_module,
-            registrar,
-            &module.types,
-            query,
-            &module.info,
-        )?;
+    for view in &module.views {
+        let result = view_query(catalog, view, &module.info).and_then(|query| {
+            prepare_query(catalog, &mut tmp_prepared_module, registrar, &scope, query)
+        });
+        if let Err(e) = result {
+            errors.push(e);
+        }
     }
 
-    validation::validate_preparation(&tmp_prepared_module)?;
+    for mut query in module.queries {
+        let setup = std::mem::take(&mut query.setup);
+        if let Err(e) = with_setup(catalog, &setup, &module.info, |catalog| {
+            prepare_query(catalog, &mut tmp_prepared_module, registrar, &scope, query)
+        }) {
+            errors.push(e);
+        }
+    }
+
+    if let Err(e) = validation::validate_preparation(&tmp_prepared_module) {
+        errors.push(e.into());
+    }
+
+    Some(tmp_prepared_module)
+}
+
+/// Builds a query selecting all the columns of a view, as listed in the catalog
+fn view_query(
+    catalog: &mut dyn Catalog,
+    view: &ViewAnnotation,
+    module_info: &ModuleInfo,
+) -> Result<Query, Error> {
+    let name = &view.name;
+    let quoted = format!("\"{}\"", name.value.replace('"', "\"\""));
+    let columns = catalog
+        .view_columns(&quoted)
+        .map_err(|e| Error::new_lookup_err(e, module_info, name))?;
+    validation::unknown_view(module_info, name, &columns)?;
+
+    let columns = columns
+        .iter()
+        .map(|it| format!("\"{}\"", it.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(Query {
+        name: name.clone(),
+        param: QueryDataStruct::default(),
+        row: QueryDataStruct {
+            span: name.span,
+            name: Some(name.map(|it| it.to_upper_camel_case())),
+            idents: Some(view.fields.clone()),
+        },
+        attributes: Vec::new(),
+        mappings: Vec::new(),
+        cache: None,
+        groups: Vec::new(),
+        sql_span: name.span,
+        sql_str: format!("SELECT {columns} FROM {quoted}"),
+        bind_params: Vec::new(),
+        single_row: false,
+        explain: None,
+        execute: None,
+        setup: Vec::new(),
+    })
+}
+
+/// Lists the columns having a single column unique index in the tables read by this query
+fn unique_columns(
+    catalog: &mut dyn Catalog,
+    sql: &str,
+    module_info: &ModuleInfo,
+    name: &Span<String>,
+) -> Result<Vec<String>, Error> {
+    let tables = validation::referenced_tables(sql);
+    catalog
+        .unique_columns(&tables)
+        .map_err(|e| Error::new_lookup_err(e, module_info, name))
+}
+
+/// Runs `prepare` after the `--# setup` annotations of a query, in a transaction rolled back
+/// afterward so that the session state they create does not leak to the following queries
+fn with_setup<T>(
+    catalog: &mut dyn Catalog,
+    setup: &[Span<String>],
+    module_info: &ModuleInfo,
+    prepare: impl FnOnce(&mut dyn Catalog) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let (first, last) = match (setup.first(), setup.last()) {
+        (Some(first), Some(last)) => (first.span, last.span),
+        _ => return prepare(catalog),
+    };
+    let span = (first.offset(), last.offset() + last.len() - first.offset()).into();
+    let setup_err = |err: CatalogError| Error::Setup {
+        msg: match &err {
+            CatalogError::Db(err) => err
+                .as_db_error()
+                .map_or_else(|| format!("{err:#}"), |e| e.message().to_string()),
+            err => err.to_string(),
+        },
+        src: module_info.into(),
+        err_span: span,
+    };
+    let sql = setup
+        .iter()
+        .map(|it| it.value.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    log::trace!("setting up session: {sql}");
+    let result = catalog
+        .batch_execute(&format!("BEGIN;\n{sql}"))
+        .map_err(setup_err)
+        .and_then(|()| prepare(catalog));
+    let rollback = catalog.batch_execute("ROLLBACK").map_err(setup_err);
+    let value = result?;
+    rollback?;
+    Ok(value)
+}
 
-    Ok(tmp_prepared_module)
+/// Module level declarations shared by the preparation of each of its queries
+struct ModuleScope<'a> {
+    info: &'a ModuleInfo,
+    types: &'a [TypeAnnotation],
+    enums: &'a [EnumAnnotation],
+    /// Row columns are nullable by default
+    nullable_default: bool,
 }
 
-/// Prepares a query
+/// Prepares a query in the scope of its module
 fn prepare_query(
-    client: &mut Client,
+    catalog: &mut dyn Catalog,
     module: &mut PreparedModule,
     registrar: &mut TypeRegistrar,
-    types: &[TypeAnnotation],
+    &ModuleScope {
+        info: module_info,
+        types,
+        enums,
+        nullable_default,
+    }: &ModuleScope,
     Query {
         name,
         param,
         bind_params,
         row,
+        attributes,
+        mappings,
+        cache,
+        groups,
         sql_str,
         sql_span,
+        single_row,
+        execute,
+        ..
     }: Query,
-    module_info: &ModuleInfo,
 ) -> Result<(), Error> {
     // Prepare the statement
-    let stmt = client
-        .prepare(&sql_str)
-        .map_err(|e| Error::new_db_err(&e, module_info, &sql_span, &name))?;
+    log::trace!("preparing query `{}`: {sql_str}", name.value);
+    let start = Instant::now();
+    let stmt = catalog.prepare(&sql_str).map_err(|e| match e {
+        CatalogError::Db(e) => Error::new_db_err(&e, module_info, &sql_span, &name),
+        e => Error::new_lookup_err(e, module_info, &name),
+    })?;
+    log::debug!("prepared query `{}` in {:?}", name.value, start.elapsed());
 
     let (nullable_params_fields, params_name) = param.name_and_fields(types, &name, Some("Params"));
     let (nullable_row_fields, row_name) = row.name_and_fields(types, &name, None);
     let params_fields = {
-        let stmt_params = stmt.params();
         let params = bind_params
             .iter()
-            .zip(stmt_params)
+            .zip(&stmt.params)
             .map(|(a, b)| (a.clone(), b.clone()))
             .collect::<Vec<(Span<String>, Type)>>();
         // Check for param declaration on simple query
@@ -390,19 +866,31 @@ fn prepare_query(
                 .iter()
                 .find(|x| x.name.value == col_name.value);
             // Register type
+            let ty = match nullity.and_then(|it| it.int_enum.as_ref()) {
+                Some(enum_name) => {
+                    int_enum(module_info, enums, &mut module.enums, enum_name, &col_ty)?
+                }
+                None => registrar.register_param(&col_name.value, &col_ty, &name, module_info)?,
+            };
             param_fields.push(PreparedField::new(
                 col_name.value.clone(),
-                registrar
-                    .register(&col_name.value, &col_ty, &name, module_info)?
-                    .clone(),
+                ty,
                 nullity,
+                false,
             ));
         }
         param_fields
     };
 
     let row_fields = {
-        let stmt_cols = stmt.columns();
+        // Queries annotated with `--# execute` discard their rows
+        let stmt_cols: &[Column] = match execute {
+            Some(execute) => {
+                validation::row_on_forced_execute(&module.info, &name, &execute, &row, &cache)?;
+                &[]
+            }
+            None => &stmt.columns,
+        };
         // Check for row declaration on execute
         validation::row_on_execute(&module.info, &name, &sql_span, &row, stmt_cols)?;
         // Check for duplicate names
@@ -412,6 +900,10 @@ fn prepare_query(
             validation::nullable_column_name(&module.info, nullable_col, stmt_cols)
                 .map_err(Error::from)?;
         }
+        for mapping in &mappings {
+            validation::mapped_column_name(&module.info, mapping, stmt_cols)
+                .map_err(Error::from)?;
+        }
 
         let mut row_fields = Vec::new();
         for (col_name, col_ty) in stmt_cols.iter().map(|c| (c.name().to_owned(), c.type_())) {
@@ -419,18 +911,71 @@ fn prepare_query(
                 .iter()
                 .find(|x| x.name.value == col_name);
             // Register type
-            let ty = registrar
-                .register(&col_name, col_ty, &name, module_info)?
-                .clone();
-            row_fields.push(PreparedField::new(
-                normalize_rust_name(&col_name),
-                ty,
-                nullity,
-            ));
+            let ty = match nullity.and_then(|it| it.int_enum.as_ref()) {
+                Some(enum_name) => {
+                    int_enum(module_info, enums, &mut module.enums, enum_name, col_ty)?
+                }
+                None => registrar
+                    .register(&col_name, col_ty, &name, module_info)?
+                    .clone(),
+            };
+            // Convert the extracted value to a custom Rust type
+            let ty = match mappings.iter().find(|it| it.column.value == col_name) {
+                Some(mapping) => Rc::new(CornucopiaType::Mapped {
+                    inner: ty,
+                    rust_ty: mapping.ty.value.clone(),
+                    map: mapping.map.value.clone(),
+                }),
+                None => ty,
+            };
+            row_fields.push(PreparedField::new(col_name, ty, nullity, nullable_default));
         }
         row_fields
     };
 
+    if let Some(key) = &cache {
+        let unique_columns = unique_columns(catalog, &sql_str, module_info, &name)?;
+        validation::cache_key(
+            module_info,
+            &name,
+            key,
+            &sql_str,
+            !params_fields.is_empty(),
+            &stmt.columns,
+            &unique_columns,
+        )
+        .map_err(Error::from)?;
+        validation::cache_key_hash(module_info, key, &row_fields).map_err(Error::from)?;
+    }
+
+    if !nullable_default {
+        let unannotated_columns: Vec<_> = row_fields
+            .iter()
+            .map(|field| field.ident.db.clone())
+            .filter(|col| {
+                !nullable_row_fields
+                    .iter()
+                    .any(|it| it.name.value == *col && it.nullable.is_some())
+            })
+            .collect();
+        module.warnings.extend(validation::set_operation_nullity(
+            module_info,
+            &name,
+            &sql_str,
+            &unannotated_columns,
+        ));
+    }
+
+    if single_row {
+        let unique_columns = unique_columns(catalog, &sql_str, module_info, &name)?;
+        module.warnings.extend(validation::single_row_query(
+            module_info,
+            &name,
+            &sql_str,
+            &unique_columns,
+        ));
+    }
+
     let row_idx = if row_fields.is_empty() {
         None
     } else {
@@ -441,18 +986,30 @@ fn prepare_query(
     } else {
         Some(module.add_param(params_name, params_fields, param.is_implicit())?)
     };
-    module.add_query(name.clone(), param_idx, row_idx, sql_str);
+    module.add_attributes(&name, &attributes, param_idx.as_ref(), row_idx.as_ref())?;
+    for group in &groups {
+        module.add_group(&name, group, row_idx.as_ref())?;
+    }
+    module.add_query(
+        name.clone(),
+        param_idx,
+        row_idx,
+        sql_str,
+        cache.map(|it| it.value),
+    );
 
     Ok(())
 }
 
 pub(crate) mod error {
     use miette::{Diagnostic, NamedSource, SourceSpan};
+    use postgres::error::SqlState;
     use thiserror::Error as ThisError;
 
     use crate::{
-        parser::Span, read_queries::ModuleInfo, type_registrar::error::Error as PostgresTypeError,
-        utils::db_err, validation::error::Error as ValidationError,
+        catalog::CatalogError, parser::Span, read_queries::ModuleInfo,
+        type_registrar::error::Error as PostgresTypeError, utils::db_err,
+        validation::error::Error as ValidationError,
     };
 
     #[derive(Debug, ThisError, Diagnostic)]
@@ -467,6 +1024,26 @@ pub(crate) mod error {
             #[label("error occurs near this location")]
             err_span: Option<SourceSpan>,
         },
+        #[error("Couldn't prepare query: statement timeout exceeded")]
+        #[diagnostic(help("increase the statement timeout or simplify this query"))]
+        StatementTimeout {
+            #[source_code]
+            src: NamedSource,
+            #[label("query prepared here")]
+            err_span: SourceSpan,
+        },
+        #[error("Couldn't set up the session before preparing the query: {msg}")]
+        Setup {
+            msg: String,
+            #[source_code]
+            src: NamedSource,
+            #[label("setup declared here")]
+            err_span: SourceSpan,
+        },
+        #[error("Couldn't set statement timeout: {0}")]
+        StatementTimeoutSetup(CatalogError),
+        #[error("Couldn't read foreign keys: {0}")]
+        ForeignKeys(CatalogError),
         #[error(transparent)]
         #[diagnostic(transparent)]
         PostgresType(#[from] PostgresTypeError),
@@ -476,12 +1053,47 @@ pub(crate) mod error {
     }
 
     impl Error {
+        /// Reports a failed catalog lookup made for the query or view `name`
+        pub(crate) fn new_lookup_err(
+            err: CatalogError,
+            module_info: &ModuleInfo,
+            name: &Span<String>,
+        ) -> Self {
+            Self::Db {
+                msg: err.to_string(),
+                help: err.help(),
+                src: module_info.into(),
+                err_span: Some(name.span),
+            }
+        }
+
         pub(crate) fn new_db_err(
             err: &postgres::Error,
             module_info: &ModuleInfo,
             query_span: &SourceSpan,
             query_name: &Span<String>,
         ) -> Self {
+            if err.code() == Some(&SqlState::QUERY_CANCELED) {
+                return Self::StatementTimeout {
+                    src: module_info.into(),
+                    err_span: query_name.span,
+                };
+            }
+            if err.code() == Some(&SqlState::GENERATED_ALWAYS) {
+                // Postgres only explains the constraint, point to the usual fixes
+                let db_err = err.as_db_error();
+                let help = if db_err.and_then(|e| e.hint()).is_some() {
+                    "identity columns `GENERATED ALWAYS` are assigned by the database, leave them out of the query or use `OVERRIDING SYSTEM VALUE`"
+                } else {
+                    "generated columns are computed by the database, leave them out of the query or set them to `DEFAULT`"
+                };
+                return Self::Db {
+                    msg: db_err.map_or_else(|| format!("{err:#}"), |e| e.message().to_string()),
+                    help: Some(help.to_string()),
+                    src: module_info.into(),
+                    err_span: Some(query_name.span),
+                };
+            }
             let msg = format!("{err:#}");
             if let Some((position, msg, help)) = db_err(err) {
                 Self::Db {
@@ -26,18 +26,41 @@ impl From<&ModuleInfo> for NamedSource {
     }
 }
 
-/// Reads queries in the directory. Only .sql files are considered.
+/// Reads queries in the directories. Only .sql files are considered.
 ///
 /// # Error
-/// Returns an error if `dir_path` does not point to a valid directory or if a query file cannot be parsed.
-pub(crate) fn read_query_modules(dir_path: &Path) -> Result<Vec<ModuleInfo>, Error> {
+/// Returns an error if a path does not point to a valid directory, if a query file cannot be read or
+/// if two directories contain a module with the same name.
+pub(crate) fn read_query_modules<P: AsRef<Path>>(
+    dir_paths: &[P],
+) -> Result<Vec<ModuleInfo>, Error> {
+    let mut modules_info: Vec<ModuleInfo> = Vec::new();
+    for dir_path in dir_paths {
+        for module in read_query_dir(dir_path.as_ref())? {
+            if let Some(prev) = modules_info.iter().find(|m| m.name == module.name) {
+                return Err(Error::ModuleCollision {
+                    name: module.name,
+                    first: prev.path.clone(),
+                    second: module.path,
+                });
+            }
+            modules_info.push(module);
+        }
+    }
+    // Sort module for consistent codegen
+    modules_info.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(modules_info)
+}
+
+/// Reads the .sql files of a single directory
+fn read_query_dir(dir_path: &Path) -> Result<Vec<ModuleInfo>, Error> {
     let mut modules_info = Vec::new();
-    for entry_result in std::fs::read_dir(dir_path).map_err(|err| Error {
+    for entry_result in std::fs::read_dir(dir_path).map_err(|err| Error::Io {
         err,
         path: dir_path.to_owned(),
     })? {
         // Directory entry
-        let entry = entry_result.map_err(|err| Error {
+        let entry = entry_result.map_err(|err| Error::Io {
             err,
             path: dir_path.to_owned(),
         })?;
@@ -56,7 +79,7 @@ pub(crate) fn read_query_modules(dir_path: &Path) -> Result<Vec<ModuleInfo>, Err
                 .expect("file name is valid utf8")
                 .to_string();
 
-            let file_contents = std::fs::read_to_string(&path_buf).map_err(|err| Error {
+            let file_contents = std::fs::read_to_string(&path_buf).map_err(|err| Error::Io {
                 err,
                 path: dir_path.to_owned(),
             })?;
@@ -68,8 +91,6 @@ pub(crate) fn read_query_modules(dir_path: &Path) -> Result<Vec<ModuleInfo>, Err
             });
         }
     }
-    // Sort module for consistent codegen
-    modules_info.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(modules_info)
 }
 
@@ -80,9 +101,17 @@ pub(crate) mod error {
     use thiserror::Error as ThisError;
 
     #[derive(Debug, ThisError, Diagnostic)]
-    #[error("[{path}] : {err:#}")]
-    pub struct Error {
-        pub(crate) err: std::io::Error,
-        pub(crate) path: PathBuf,
+    pub enum Error {
+        #[error("[{path}] : {err:#}")]
+        Io { err: std::io::Error, path: PathBuf },
+        #[error("module `{name}` is defined twice, in `{first}` and `{second}`")]
+        #[diagnostic(help(
+            "rename one of the query files, modules of all query directories are merged"
+        ))]
+        ModuleCollision {
+            name: String,
+            first: PathBuf,
+            second: PathBuf,
+        },
     }
 }
@@ -0,0 +1,51 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+use crate::error::{Error, InitError};
+
+/// Starter migration created by [`init_project`]
+const MIGRATION: &str = "CREATE TABLE author (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+";
+
+/// Example query file created by [`init_project`]
+const QUERIES: &str = "--! insert_author
+INSERT INTO author (name) VALUES (:name);
+
+--! authors
+SELECT id, name FROM author ORDER BY id;
+
+--! author_by_id
+SELECT id, name FROM author WHERE id = :id;
+";
+
+/// Creates a `migrations` directory holding a starter migration and a `queries` directory
+/// holding an example query file in `path`. Existing files are never overwritten.
+pub fn init_project(path: &Path) -> Result<(), Error> {
+    let files = [
+        (path.join("migrations").join("0001_init.sql"), MIGRATION),
+        (path.join("queries").join("author.sql"), QUERIES),
+    ];
+    for (file_path, content) in files {
+        let err = |err| InitError {
+            file_path: file_path.clone(),
+            err,
+        };
+        if let Some(dir) = file_path.parent() {
+            fs::create_dir_all(dir).map_err(err)?;
+        }
+        log::info!("creating `{}`", file_path.display());
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&file_path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(err)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,1559 @@
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use postgres_types::{Kind, Type};
+
+use crate::catalog::{
+    error::Error, Catalog, CatalogError, Column, ForeignKeyColumn, StatementTypes,
+};
+
+/// In-memory catalog built from schema files, e.g. a `pg_dump --schema-only` output, to prepare
+/// queries without any database.
+///
+/// Only tables, views, enums and their constraints are read from the schema, other statements
+/// are skipped. Statement types are inferred for plain `SELECT`, `INSERT`, `UPDATE` and `DELETE`
+/// statements over tables and views with built-in scalar or enum columns. Params are typed from
+/// casts and from comparisons with a column, anything else is reported as unsupported.
+#[derive(Default)]
+pub(crate) struct SchemaCatalog {
+    relations: Vec<Relation>,
+    enums: Vec<EnumType>,
+    primary_keys: Vec<(Name, Vec<String>)>,
+    /// Columns having a single column unique constraint or index
+    unique: Vec<(Name, String)>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+/// Schema qualified name, in the `public` schema unless specified
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Name {
+    schema: String,
+    name: String,
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.schema, self.name)
+    }
+}
+
+struct Relation {
+    name: Name,
+    is_view: bool,
+    /// Columns with their type, or why a view could not be read
+    columns: Result<Vec<(String, ColumnType)>, String>,
+}
+
+/// Type of a column, or the name of a type that is not supported
+type ColumnType = Result<Type, String>;
+
+struct EnumType {
+    name: Name,
+    oid: u32,
+    variants: Vec<String>,
+}
+
+struct ForeignKey {
+    table: Name,
+    constraint: String,
+    columns: Vec<String>,
+    foreign_table: Name,
+    /// The primary key of the foreign table if unspecified
+    foreign_columns: Option<Vec<String>>,
+}
+
+/// A view declaration, read once the whole schema is declared
+struct View {
+    name: Name,
+    /// Names of the columns, overriding those of the query
+    aliases: Vec<String>,
+    query: Vec<Token>,
+}
+
+/// A relation read by a statement, under its alias
+struct Source {
+    alias: String,
+    columns: Vec<(String, ColumnType)>,
+}
+
+/// Output columns of a statement with the relations it reads, or why it is not supported
+type Analysis = Result<(Vec<(String, Type)>, Vec<Source>), String>;
+
+/// Built-in scalar types by their SQL names, with their array type
+const BUILTIN_TYPES: &[(&str, Type, Type)] = &[
+    ("smallint", Type::INT2, Type::INT2_ARRAY),
+    ("int2", Type::INT2, Type::INT2_ARRAY),
+    ("smallserial", Type::INT2, Type::INT2_ARRAY),
+    ("serial2", Type::INT2, Type::INT2_ARRAY),
+    ("integer", Type::INT4, Type::INT4_ARRAY),
+    ("int", Type::INT4, Type::INT4_ARRAY),
+    ("int4", Type::INT4, Type::INT4_ARRAY),
+    ("serial", Type::INT4, Type::INT4_ARRAY),
+    ("serial4", Type::INT4, Type::INT4_ARRAY),
+    ("bigint", Type::INT8, Type::INT8_ARRAY),
+    ("int8", Type::INT8, Type::INT8_ARRAY),
+    ("bigserial", Type::INT8, Type::INT8_ARRAY),
+    ("serial8", Type::INT8, Type::INT8_ARRAY),
+    ("real", Type::FLOAT4, Type::FLOAT4_ARRAY),
+    ("float4", Type::FLOAT4, Type::FLOAT4_ARRAY),
+    ("double precision", Type::FLOAT8, Type::FLOAT8_ARRAY),
+    ("float8", Type::FLOAT8, Type::FLOAT8_ARRAY),
+    ("numeric", Type::NUMERIC, Type::NUMERIC_ARRAY),
+    ("decimal", Type::NUMERIC, Type::NUMERIC_ARRAY),
+    ("text", Type::TEXT, Type::TEXT_ARRAY),
+    ("character varying", Type::VARCHAR, Type::VARCHAR_ARRAY),
+    ("varchar", Type::VARCHAR, Type::VARCHAR_ARRAY),
+    ("character", Type::BPCHAR, Type::BPCHAR_ARRAY),
+    ("char", Type::BPCHAR, Type::BPCHAR_ARRAY),
+    ("bpchar", Type::BPCHAR, Type::BPCHAR_ARRAY),
+    ("boolean", Type::BOOL, Type::BOOL_ARRAY),
+    ("bool", Type::BOOL, Type::BOOL_ARRAY),
+    ("bytea", Type::BYTEA, Type::BYTEA_ARRAY),
+    ("uuid", Type::UUID, Type::UUID_ARRAY),
+    ("json", Type::JSON, Type::JSON_ARRAY),
+    ("jsonb", Type::JSONB, Type::JSONB_ARRAY),
+    ("date", Type::DATE, Type::DATE_ARRAY),
+    ("time", Type::TIME, Type::TIME_ARRAY),
+    ("time without time zone", Type::TIME, Type::TIME_ARRAY),
+    ("timetz", Type::TIMETZ, Type::TIMETZ_ARRAY),
+    ("time with time zone", Type::TIMETZ, Type::TIMETZ_ARRAY),
+    ("timestamp", Type::TIMESTAMP, Type::TIMESTAMP_ARRAY),
+    (
+        "timestamp without time zone",
+        Type::TIMESTAMP,
+        Type::TIMESTAMP_ARRAY,
+    ),
+    ("timestamptz", Type::TIMESTAMPTZ, Type::TIMESTAMPTZ_ARRAY),
+    (
+        "timestamp with time zone",
+        Type::TIMESTAMPTZ,
+        Type::TIMESTAMPTZ_ARRAY,
+    ),
+    ("interval", Type::INTERVAL, Type::INTERVAL_ARRAY),
+    ("inet", Type::INET, Type::INET_ARRAY),
+    ("cidr", Type::CIDR, Type::CIDR_ARRAY),
+    ("macaddr", Type::MACADDR, Type::MACADDR_ARRAY),
+    ("oid", Type::OID, Type::OID_ARRAY),
+];
+
+/// Words continuing a multi-word type name like `double precision`
+const TYPE_WORDS: &[&str] = &["varying", "precision", "with", "without", "time", "zone"];
+
+/// Keywords ending the list of relations of a `FROM` clause
+const FROM_END: &[&str] = &[
+    "where",
+    "group",
+    "having",
+    "window",
+    "order",
+    "limit",
+    "offset",
+    "fetch",
+    "for",
+    "returning",
+];
+
+/// Keywords that cannot be an alias following a relation
+const NOT_ALIAS: &[&str] = &[
+    "on",
+    "using",
+    "join",
+    "inner",
+    "left",
+    "right",
+    "full",
+    "cross",
+    "natural",
+    "set",
+    "where",
+    "group",
+    "having",
+    "window",
+    "order",
+    "limit",
+    "offset",
+    "fetch",
+    "for",
+    "returning",
+];
+
+/// Comparison operators typing a param like the column it is compared to
+const COMPARISONS: &[&str] = &["=", "<>", "!=", "<", ">", "<=", ">="];
+
+impl SchemaCatalog {
+    /// Reads the tables, views, enums and constraints declared in `schema_files`
+    pub(crate) fn load<P: AsRef<Path>>(schema_files: &[P]) -> Result<Self, Error> {
+        let mut catalog = Self::default();
+        let mut views = Vec::new();
+        for path in schema_files {
+            let path = path.as_ref();
+            let content = std::fs::read_to_string(path).map_err(|err| Error::Io {
+                path: path.display().to_string(),
+                err,
+            })?;
+            for statement in split_statements(&tokenize(&content)) {
+                if let Some(view) = catalog.declare(statement) {
+                    views.push(view);
+                }
+            }
+        }
+        // Views are read once every type they may use is declared
+        for view in views {
+            let columns = catalog.select(&view.query).map(|(columns, _)| {
+                columns
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (name, ty))| (view.aliases.get(i).cloned().unwrap_or(name), Ok(ty)))
+                    .collect()
+            });
+            if let Err(err) = &columns {
+                log::debug!("cannot read the view `{}`: {err}", view.name);
+            }
+            catalog.relations.push(Relation {
+                name: view.name,
+                is_view: true,
+                columns,
+            });
+        }
+        Ok(catalog)
+    }
+
+    /// Records the objects declared by `statement`, returning the views to read afterward
+    fn declare(&mut self, statement: &[Token]) -> Option<View> {
+        let mut cursor = Cursor::new(statement);
+        if cursor.keyword("create") {
+            cursor.keywords(&["or", "replace"]);
+            if cursor.keyword("type") {
+                self.create_enum(&mut cursor);
+            } else if cursor.keyword("unique") {
+                self.create_unique_index(&mut cursor);
+            } else {
+                cursor.keyword("materialized");
+                cursor.skip_keywords(&["global", "local", "temporary", "temp", "unlogged"]);
+                cursor.keyword("recursive");
+                if cursor.keyword("table") {
+                    self.create_table(&mut cursor);
+                } else if cursor.keyword("view") {
+                    return view(&mut cursor);
+                }
+            }
+        } else if cursor.keyword("alter") {
+            if cursor.keyword("table") {
+                self.alter_table(&mut cursor);
+            } else if cursor.keyword("type") {
+                self.alter_enum(&mut cursor);
+            }
+        }
+        None
+    }
+
+    /// `CREATE TABLE name (column type constraints, table constraint, ...)`
+    fn create_table(&mut self, cursor: &mut Cursor) {
+        cursor.keywords(&["if", "not", "exists"]);
+        let name = match cursor.qualified_name() {
+            Some(name) => name,
+            None => return,
+        };
+        let elements = match cursor.group() {
+            Some(elements) => elements,
+            None => return,
+        };
+        let mut columns = Vec::new();
+        for element in split_top_level(elements, ",") {
+            let mut element = Cursor::new(element);
+            if !self.table_constraint(&name, &mut element) {
+                if let Some(column) = self.column_definition(&name, &mut element) {
+                    columns.push(column);
+                }
+            }
+        }
+        self.relations.retain(|it| it.name != name);
+        self.relations.push(Relation {
+            name,
+            is_view: false,
+            columns: Ok(columns),
+        });
+    }
+
+    /// `name type [CONSTRAINT name] [PRIMARY KEY | UNIQUE | REFERENCES table (column) | ...]`
+    fn column_definition(
+        &mut self,
+        table: &Name,
+        cursor: &mut Cursor,
+    ) -> Option<(String, ColumnType)> {
+        let name = cursor.name()?;
+        let ty = cursor.type_name().map(|ty| self.resolve_type(&ty));
+        let ty = ty.unwrap_or_else(|| Err("unknown".to_string()));
+        let mut constraint = None;
+        while !cursor.at_end() {
+            if cursor.keyword("constraint") {
+                constraint = cursor.name();
+            } else if cursor.keywords(&["primary", "key"]) {
+                self.primary_keys.push((table.clone(), vec![name.clone()]));
+                self.unique.push((table.clone(), name.clone()));
+            } else if cursor.keyword("unique") {
+                self.unique.push((table.clone(), name.clone()));
+            } else if cursor.keyword("references") {
+                if let Some(foreign_table) = cursor.qualified_name() {
+                    let foreign_columns = cursor.group().map(names);
+                    self.foreign_keys.push(ForeignKey {
+                        table: table.clone(),
+                        constraint: constraint
+                            .take()
+                            .unwrap_or_else(|| format!("{}_{name}_fkey", table.name)),
+                        columns: vec![name.clone()],
+                        foreign_table,
+                        foreign_columns,
+                    });
+                }
+            } else {
+                cursor.next();
+            }
+        }
+        Some((name, ty))
+    }
+
+    /// `[CONSTRAINT name] PRIMARY KEY (columns) | UNIQUE (columns) | FOREIGN KEY ...`, returning
+    /// whether this is a table constraint rather than a column
+    fn table_constraint(&mut self, table: &Name, cursor: &mut Cursor) -> bool {
+        let constraint = if cursor.keyword("constraint") {
+            cursor.name()
+        } else {
+            None
+        };
+        if cursor.keywords(&["primary", "key"]) {
+            let columns = cursor.group().map(names).unwrap_or_default();
+            if let [column] = columns.as_slice() {
+                self.unique.push((table.clone(), column.clone()));
+            }
+            self.primary_keys.push((table.clone(), columns));
+        } else if cursor.keyword("unique") {
+            if let Some([column]) = cursor.group().map(names).as_deref() {
+                self.unique.push((table.clone(), column.clone()));
+            }
+        } else if cursor.keywords(&["foreign", "key"]) {
+            let columns = cursor.group().map(names).unwrap_or_default();
+            if cursor.keyword("references") {
+                if let Some(foreign_table) = cursor.qualified_name() {
+                    let foreign_columns = cursor.group().map(names);
+                    self.foreign_keys.push(ForeignKey {
+                        table: table.clone(),
+                        constraint: constraint.unwrap_or_else(|| {
+                            format!("{}_{}_fkey", table.name, columns.join("_"))
+                        }),
+                        columns,
+                        foreign_table,
+                        foreign_columns,
+                    });
+                }
+            }
+        } else {
+            return constraint.is_some()
+                || cursor.peek_keyword("check")
+                || cursor.peek_keyword("exclude")
+                || cursor.peek_keyword("like");
+        }
+        true
+    }
+
+    /// `ALTER TABLE name ADD [COLUMN] column, ADD [CONSTRAINT name] ..., DROP [COLUMN] name`
+    fn alter_table(&mut self, cursor: &mut Cursor) {
+        cursor.keywords(&["if", "exists"]);
+        cursor.keyword("only");
+        let name = match cursor.qualified_name() {
+            Some(name) => name,
+            None => return,
+        };
+        for action in split_top_level(cursor.rest(), ",") {
+            let mut action = Cursor::new(action);
+            if action.keyword("add") {
+                if self.table_constraint(&name, &mut action) {
+                    continue;
+                }
+                action.keyword("column");
+                action.keywords(&["if", "not", "exists"]);
+                if let Some(column) = self.column_definition(&name, &mut action) {
+                    if let Some(Ok(columns)) = self.relation_mut(&name).map(|it| &mut it.columns) {
+                        columns.push(column);
+                    }
+                }
+            } else if action.keyword("drop") {
+                action.keyword("column");
+                action.keywords(&["if", "exists"]);
+                if let Some(column) = action.name() {
+                    if let Some(Ok(columns)) = self.relation_mut(&name).map(|it| &mut it.columns) {
+                        columns.retain(|(name, _)| *name != column);
+                    }
+                }
+            }
+        }
+    }
+
+    /// `CREATE TYPE name AS ENUM ('variant', ...)`, other types are not supported
+    fn create_enum(&mut self, cursor: &mut Cursor) {
+        let name = match cursor.qualified_name() {
+            Some(name) => name,
+            None => return,
+        };
+        if !cursor.keywords(&["as", "enum"]) {
+            return;
+        }
+        let variants = cursor
+            .group()
+            .map(|it| {
+                it.iter()
+                    .filter_map(|token| match token {
+                        Token::Str(variant) => Some(variant.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Fake oids, unique among the enums and their array types
+        let oid = 0x8000_0000 + 2 * self.enums.len() as u32;
+        self.enums.push(EnumType {
+            name,
+            oid,
+            variants,
+        });
+    }
+
+    /// `ALTER TYPE name ADD VALUE [IF NOT EXISTS] 'variant' [BEFORE | AFTER 'variant']`
+    fn alter_enum(&mut self, cursor: &mut Cursor) {
+        let name = match cursor.qualified_name() {
+            Some(name) => name,
+            None => return,
+        };
+        if !cursor.keywords(&["add", "value"]) {
+            return;
+        }
+        cursor.keywords(&["if", "not", "exists"]);
+        let variant = match cursor.next() {
+            Some(Token::Str(variant)) => variant.clone(),
+            _ => return,
+        };
+        let position = if cursor.keyword("before") {
+            Some(0)
+        } else if cursor.keyword("after") {
+            Some(1)
+        } else {
+            None
+        };
+        let neighbor = match cursor.next() {
+            Some(Token::Str(neighbor)) => Some(neighbor.clone()),
+            _ => None,
+        };
+        if let Some(it) = self.enums.iter_mut().find(|it| it.name == name) {
+            if it.variants.contains(&variant) {
+                return;
+            }
+            let index = neighbor
+                .and_then(|neighbor| it.variants.iter().position(|it| *it == neighbor))
+                .zip(position)
+                .map_or(it.variants.len(), |(index, offset)| index + offset);
+            it.variants.insert(index, variant);
+        }
+    }
+
+    /// `CREATE UNIQUE INDEX [name] ON table (column)`
+    fn create_unique_index(&mut self, cursor: &mut Cursor) {
+        if !cursor.keyword("index") {
+            return;
+        }
+        cursor.keyword("concurrently");
+        cursor.keywords(&["if", "not", "exists"]);
+        if !cursor.peek_keyword("on") {
+            cursor.name();
+        }
+        if !cursor.keyword("on") {
+            return;
+        }
+        cursor.keyword("only");
+        let table = match cursor.qualified_name() {
+            Some(table) => table,
+            None => return,
+        };
+        if cursor.keyword("using") {
+            cursor.name();
+        }
+        if let Some([Token::Ident(column) | Token::Quoted(column)]) = cursor.group() {
+            self.unique.push((table, column.clone()));
+        }
+    }
+
+    fn relation(&self, name: &Name) -> Option<&Relation> {
+        self.relations.iter().find(|it| it.name == *name)
+    }
+
+    fn relation_mut(&mut self, name: &Name) -> Option<&mut Relation> {
+        self.relations.iter_mut().find(|it| it.name == *name)
+    }
+
+    /// Resolves a type declared in the schema, arrays are supported down to a single dimension
+    fn resolve_type(&self, ty: &TypeName) -> ColumnType {
+        let builtin = match &ty.schema {
+            None => true,
+            Some(schema) => schema == "pg_catalog",
+        };
+        let builtin = BUILTIN_TYPES
+            .iter()
+            .find(|(name, _, _)| builtin && *name == ty.name)
+            .map(|(_, scalar, array)| if ty.array { array } else { scalar }.clone());
+        if let Some(builtin) = builtin {
+            return Ok(builtin);
+        }
+        let name = Name {
+            schema: ty.schema.clone().unwrap_or_else(|| "public".to_string()),
+            name: ty.name.clone(),
+        };
+        match self.enums.iter().find(|it| it.name == name) {
+            Some(it) => {
+                let scalar = Type::new(
+                    it.name.name.clone(),
+                    it.oid,
+                    Kind::Enum(it.variants.clone()),
+                    it.name.schema.clone(),
+                );
+                Ok(if ty.array {
+                    Type::new(
+                        format!("_{}", it.name.name),
+                        it.oid + 1,
+                        Kind::Array(scalar),
+                        it.name.schema.clone(),
+                    )
+                } else {
+                    scalar
+                })
+            }
+            None => Err(ty.to_string()),
+        }
+    }
+
+    /// Array type of `ty`, if it is a built-in scalar or an enum
+    fn array_of(&self, ty: &Type) -> Option<Type> {
+        if let Some((_, _, array)) = BUILTIN_TYPES.iter().find(|(_, scalar, _)| scalar == ty) {
+            return Some(array.clone());
+        }
+        match ty.kind() {
+            Kind::Enum(_) => self
+                .resolve_type(&TypeName {
+                    schema: Some(ty.schema().to_string()),
+                    name: ty.name().to_string(),
+                    array: true,
+                })
+                .ok(),
+            _ => None,
+        }
+    }
+
+    /// Infers the types of the params and columns of `sql`
+    fn statement(&self, sql: &str) -> Result<StatementTypes, String> {
+        let mut tokens = tokenize(sql);
+        if tokens.last() == Some(&Token::Punct(";".to_string())) {
+            tokens.pop();
+        }
+        let mut cursor = Cursor::new(&tokens);
+        // `EXPLAIN (FORMAT JSON)` plans are a single json column
+        let explain = cursor.keyword("explain");
+        if explain {
+            cursor.group();
+        }
+        let statement = cursor.rest();
+        let mut params = BTreeMap::new();
+        let (columns, sources) = match statement.first() {
+            Some(it) if it.is_keyword("select") => self.select(statement)?,
+            Some(it) if it.is_keyword("insert") => {
+                check_simple(statement)?;
+                self.insert(statement, &mut params)?
+            }
+            Some(it) if it.is_keyword("update") => {
+                check_simple(statement)?;
+                self.update(statement)?
+            }
+            Some(it) if it.is_keyword("delete") => {
+                check_simple(statement)?;
+                self.delete(statement)?
+            }
+            Some(it) if it.is_keyword("with") => {
+                return Err("common table expressions are not supported".to_string())
+            }
+            _ => {
+                return Err(
+                    "only SELECT, INSERT, UPDATE and DELETE statements are supported".to_string(),
+                )
+            }
+        };
+        self.infer_params(statement, &sources, &mut params)?;
+
+        let nb_params = statement
+            .iter()
+            .filter_map(|it| match it {
+                Token::Param(index) => Some(*index),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let params = (1..=nb_params)
+            .map(|index| {
+                params
+                    .remove(&index)
+                    .ok_or_else(|| format!("cannot infer the type of the parameter ${index}"))
+            })
+            .collect::<Result<_, _>>()?;
+        let columns = if explain {
+            vec![Column::new("QUERY PLAN".to_string(), Type::JSON)]
+        } else {
+            columns
+                .into_iter()
+                .map(|(name, ty)| Column::new(name, ty))
+                .collect()
+        };
+        Ok(StatementTypes { params, columns })
+    }
+
+    /// `SELECT [DISTINCT] items [FROM relations] ...`
+    fn select(&self, tokens: &[Token]) -> Analysis {
+        check_simple(tokens)?;
+        let mut cursor = Cursor::new(&tokens[1..]);
+        if cursor.keyword("distinct") {
+            if cursor.keyword("on") {
+                cursor.group();
+            }
+        } else {
+            cursor.keyword("all");
+        }
+        let rest = cursor.rest();
+        let (items, sources) = match find_top_level(rest, &["from"]) {
+            Some(from) => {
+                let relations = &rest[from + 1..];
+                let end = find_top_level(relations, FROM_END).unwrap_or(relations.len());
+                (&rest[..from], self.sources(&relations[..end])?)
+            }
+            None => {
+                let end = find_top_level(rest, FROM_END).unwrap_or(rest.len());
+                (&rest[..end], Vec::new())
+            }
+        };
+        Ok((self.output_columns(items, &sources)?, sources))
+    }
+
+    /// `INSERT INTO table [(columns)] VALUES (values), ... [RETURNING items]`, typing the params
+    /// inserted in a column like it
+    fn insert(&self, tokens: &[Token], params: &mut BTreeMap<usize, Type>) -> Analysis {
+        let mut cursor = Cursor::new(&tokens[1..]);
+        if !cursor.keyword("into") {
+            return Err("unsupported INSERT statement".to_string());
+        }
+        let target = self.target(&mut cursor)?;
+        let columns = match cursor.peek() {
+            Some(Token::Punct(p)) if p == "(" => cursor.group().map(names).unwrap_or_default(),
+            _ => target
+                .columns
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+        };
+        if cursor.keyword("values") {
+            while let Some(values) = cursor.group() {
+                for (column, value) in columns.iter().zip(split_top_level(values, ",")) {
+                    if let [Token::Param(index)] = value {
+                        if !params.contains_key(index) {
+                            let ty = column_type(std::slice::from_ref(&target), None, column)?
+                                .ok_or_else(|| format!("unknown column `{column}`"))?;
+                            params.insert(*index, ty);
+                        }
+                    }
+                }
+                if !cursor.punct(",") {
+                    break;
+                }
+            }
+        } else if !cursor.keywords(&["default", "values"]) {
+            return Err("only INSERT statements with VALUES are supported".to_string());
+        }
+        let sources = vec![target];
+        Ok((self.returning(tokens, &sources)?, sources))
+    }
+
+    /// `UPDATE table SET column = value, ... [FROM relations] [WHERE ...] [RETURNING items]`
+    fn update(&self, tokens: &[Token]) -> Analysis {
+        let mut cursor = Cursor::new(&tokens[1..]);
+        let mut sources = vec![self.target(&mut cursor)?];
+        let end = find_top_level(tokens, &["where", "returning"]).unwrap_or(tokens.len());
+        if let Some(from) = find_top_level(&tokens[..end], &["from"]) {
+            let relations = &tokens[from + 1..];
+            let end = find_top_level(relations, FROM_END).unwrap_or(relations.len());
+            sources.extend(self.sources(&relations[..end])?);
+        }
+        Ok((self.returning(tokens, &sources)?, sources))
+    }
+
+    /// `DELETE FROM table [USING relations] [WHERE ...] [RETURNING items]`
+    fn delete(&self, tokens: &[Token]) -> Analysis {
+        let mut cursor = Cursor::new(&tokens[1..]);
+        if !cursor.keyword("from") {
+            return Err("unsupported DELETE statement".to_string());
+        }
+        let mut sources = vec![self.target(&mut cursor)?];
+        let end = find_top_level(tokens, &["where", "returning"]).unwrap_or(tokens.len());
+        if let Some(using) = find_top_level(&tokens[..end], &["using"]) {
+            let relations = &tokens[using + 1..];
+            let end = find_top_level(relations, FROM_END).unwrap_or(relations.len());
+            sources.extend(self.sources(&relations[..end])?);
+        }
+        Ok((self.returning(tokens, &sources)?, sources))
+    }
+
+    /// Columns of the `RETURNING` clause of `tokens`, if any
+    fn returning(
+        &self,
+        tokens: &[Token],
+        sources: &[Source],
+    ) -> Result<Vec<(String, Type)>, String> {
+        match find_top_level(tokens, &["returning"]) {
+            Some(returning) => self.output_columns(&tokens[returning + 1..], sources),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// The table modified by a statement, `[ONLY] table [[AS] alias]`
+    fn target(&self, cursor: &mut Cursor) -> Result<Source, String> {
+        cursor.keyword("only");
+        let name = cursor
+            .qualified_name()
+            .ok_or_else(|| "expected a table".to_string())?;
+        let source = self.source(&name, cursor.alias())?;
+        Ok(source)
+    }
+
+    fn source(&self, name: &Name, alias: Option<String>) -> Result<Source, String> {
+        let relation = self
+            .relation(name)
+            .ok_or_else(|| format!("unknown relation `{}`", name.name))?;
+        Ok(Source {
+            alias: alias.unwrap_or_else(|| name.name.clone()),
+            columns: relation.columns.clone()?,
+        })
+    }
+
+    /// Relations of a `FROM` clause joined with commas or `JOIN`s, their join conditions are
+    /// skipped
+    fn sources(&self, tokens: &[Token]) -> Result<Vec<Source>, String> {
+        let mut sources = Vec::new();
+        let mut cursor = Cursor::new(tokens);
+        // Depth of the parentheses around the last relation, deeper ones are in its join condition
+        let mut relation_depth = None;
+        let mut depth = 0;
+        while let Some(token) = cursor.peek() {
+            match token {
+                Token::Punct(p) if p == "(" => depth += 1,
+                Token::Punct(p) if p == ")" => depth -= 1,
+                _ => {}
+            }
+            match relation_depth {
+                Some(last_depth) => {
+                    if depth <= last_depth
+                        && (token.is_keyword("join")
+                            || matches!(token, Token::Punct(p) if p == ","))
+                    {
+                        relation_depth = None;
+                    }
+                    cursor.next();
+                }
+                None if matches!(token, Token::Punct(p) if p == "(")
+                    || token.is_keyword("only") =>
+                {
+                    cursor.next();
+                }
+                None => {
+                    let name = cursor
+                        .qualified_name()
+                        .ok_or_else(|| "unsupported FROM clause".to_string())?;
+                    sources.push(self.source(&name, cursor.alias())?);
+                    relation_depth = Some(depth);
+                }
+            }
+        }
+        Ok(sources)
+    }
+
+    /// Names and types of comma separated output items
+    fn output_columns(
+        &self,
+        tokens: &[Token],
+        sources: &[Source],
+    ) -> Result<Vec<(String, Type)>, String> {
+        let mut columns = Vec::new();
+        for item in split_top_level(tokens, ",") {
+            let all = match item {
+                [Token::Punct(p)] if p == "*" => Some(sources.iter().collect::<Vec<_>>()),
+                [Token::Ident(alias) | Token::Quoted(alias), Token::Punct(dot), Token::Punct(p)]
+                    if dot == "." && p == "*" =>
+                {
+                    Some(sources.iter().filter(|it| it.alias == *alias).collect())
+                }
+                _ => None,
+            };
+            if let Some(all) = all {
+                for (name, ty) in all.iter().flat_map(|it| &it.columns) {
+                    columns.push((name.clone(), unsupported_column(name, ty)?));
+                }
+                continue;
+            }
+            let column = match item {
+                [expr @ .., as_, alias] if as_.is_keyword("as") => {
+                    let (_, ty) = self.expression(expr, sources)?;
+                    (token_name(alias)?, ty)
+                }
+                _ => match self.expression(item, sources) {
+                    Ok(column) => column,
+                    // Alias without `AS`
+                    Err(err) => match item {
+                        [expr @ .., alias] if !expr.is_empty() => {
+                            let (_, ty) =
+                                self.expression(expr, sources).map_err(|_| err.clone())?;
+                            (token_name(alias).map_err(|_| err)?, ty)
+                        }
+                        _ => return Err(err),
+                    },
+                },
+            };
+            columns.push(column);
+        }
+        Ok(columns)
+    }
+
+    /// Name and type of an output expression: a column, a cast, `count(...)` or a literal
+    fn expression(&self, tokens: &[Token], sources: &[Source]) -> Result<(String, Type), String> {
+        let unsupported = || format!("cannot infer the type of `{}`", display(tokens));
+        if let Some(cast) = find_top_level(tokens, &["::"]).filter(|it| *it > 0) {
+            // Only the last of chained casts matters
+            let mut cursor = Cursor::new(&tokens[cast + 1..]);
+            let mut ty = cursor.type_name().ok_or_else(unsupported)?;
+            while cursor.punct("::") {
+                ty = cursor.type_name().ok_or_else(unsupported)?;
+            }
+            if !cursor.at_end() {
+                return Err(unsupported());
+            }
+            let ty = self
+                .resolve_type(&ty)
+                .map_err(|ty| format!("unsupported type `{ty}`"))?;
+            let name = match column_ref(&tokens[..cast]) {
+                Some((len, name)) if len == cast => name,
+                _ => ty.name().to_string(),
+            };
+            return Ok((name, ty));
+        }
+        match tokens {
+            [Token::Number(number)] => {
+                let ty = if number.parse::<i32>().is_ok() {
+                    Type::INT4
+                } else if number.parse::<i64>().is_ok() {
+                    Type::INT8
+                } else {
+                    Type::NUMERIC
+                };
+                Ok(("?column?".to_string(), ty))
+            }
+            [Token::Str(_)] => Ok(("?column?".to_string(), Type::TEXT)),
+            [Token::Ident(it)] if it == "true" || it == "false" => {
+                Ok(("bool".to_string(), Type::BOOL))
+            }
+            [count, Token::Punct(open), .., Token::Punct(close)]
+                if count.is_keyword("count") && open == "(" && close == ")" =>
+            {
+                Ok(("count".to_string(), Type::INT8))
+            }
+            _ => match column_ref(tokens) {
+                Some((len, name)) if len == tokens.len() => {
+                    let qualifier = qualifier(tokens);
+                    let ty = column_type(sources, qualifier.as_deref(), &name)?
+                        .ok_or_else(|| format!("unknown column `{name}`"))?;
+                    Ok((name, ty))
+                }
+                _ => Err(unsupported()),
+            },
+        }
+    }
+
+    /// Types params from their casts, `LIMIT` and `OFFSET`, and comparisons with a column
+    fn infer_params(
+        &self,
+        tokens: &[Token],
+        sources: &[Source],
+        params: &mut BTreeMap<usize, Type>,
+    ) -> Result<(), String> {
+        for (i, token) in tokens.iter().enumerate() {
+            if let Token::Param(index) = token {
+                if tokens.get(i + 1) == Some(&Token::Punct("::".to_string())) {
+                    let mut cursor = Cursor::new(&tokens[i + 2..]);
+                    if let Some(ty) = cursor.type_name() {
+                        let ty = self
+                            .resolve_type(&ty)
+                            .map_err(|ty| format!("unsupported type `{ty}`"))?;
+                        params.entry(*index).or_insert(ty);
+                    }
+                } else if i > 0
+                    && (tokens[i - 1].is_keyword("limit") || tokens[i - 1].is_keyword("offset"))
+                {
+                    params.entry(*index).or_insert(Type::INT8);
+                }
+            }
+        }
+        for i in 0..tokens.len() {
+            // `$1 = column`
+            if let [Token::Param(index), Token::Punct(op), rest @ ..] = &tokens[i..] {
+                if COMPARISONS.contains(&op.as_str()) && !params.contains_key(index) {
+                    if let Some((len, name)) = column_ref(rest) {
+                        if let Some(ty) =
+                            column_type(sources, qualifier(&rest[..len]).as_deref(), &name)?
+                        {
+                            params.insert(*index, ty);
+                        }
+                    }
+                }
+                continue;
+            }
+            // Only start at the first part of a qualified column
+            if i > 0 && tokens[i - 1] == Token::Punct(".".to_string()) {
+                continue;
+            }
+            let (len, name) = match column_ref(&tokens[i..]) {
+                Some(column) => column,
+                None => continue,
+            };
+            let qualifier = qualifier(&tokens[i..i + len]);
+            let mut cursor = Cursor::new(&tokens[i + len..]);
+            let mut typed = Vec::new();
+            let mut array = Vec::new();
+            if matches!(
+                cursor.peek(),
+                Some(Token::Punct(op)) if COMPARISONS.contains(&op.as_str())
+            ) {
+                cursor.next();
+                if cursor.keyword("any") || cursor.keyword("some") || cursor.keyword("all") {
+                    if let Some([Token::Param(index)]) = cursor.group() {
+                        array.push(*index);
+                    }
+                } else if let Some(Token::Param(index)) = cursor.next() {
+                    typed.push(*index);
+                }
+            } else {
+                cursor.keyword("not");
+                if cursor.keyword("like") || cursor.keyword("ilike") {
+                    if let Some(Token::Param(index)) = cursor.next() {
+                        typed.push(*index);
+                    }
+                } else if cursor.keyword("in") {
+                    for value in cursor
+                        .group()
+                        .map(|it| split_top_level(it, ","))
+                        .unwrap_or_default()
+                    {
+                        if let [Token::Param(index)] = value {
+                            typed.push(*index);
+                        }
+                    }
+                } else if cursor.keyword("between") {
+                    cursor.keyword("symmetric");
+                    if let Some(Token::Param(index)) = cursor.next() {
+                        typed.push(*index);
+                    }
+                    if cursor.keyword("and") {
+                        if let Some(Token::Param(index)) = cursor.next() {
+                            typed.push(*index);
+                        }
+                    }
+                } else if cursor.keywords(&["is", "distinct", "from"])
+                    || cursor.keywords(&["is", "not", "distinct", "from"])
+                {
+                    if let Some(Token::Param(index)) = cursor.next() {
+                        typed.push(*index);
+                    }
+                }
+            }
+            typed.retain(|it| !params.contains_key(it));
+            array.retain(|it| !params.contains_key(it));
+            if typed.is_empty() && array.is_empty() {
+                continue;
+            }
+            let ty = match column_type(sources, qualifier.as_deref(), &name)? {
+                Some(ty) => ty,
+                None => continue,
+            };
+            for index in typed {
+                params.insert(index, ty.clone());
+            }
+            for index in array {
+                let array = self
+                    .array_of(&ty)
+                    .ok_or_else(|| format!("unsupported array of `{}`", ty.name()))?;
+                params.insert(index, array);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Catalog for SchemaCatalog {
+    fn batch_execute(&mut self, _: &str) -> Result<(), CatalogError> {
+        // There is no session to alter
+        Ok(())
+    }
+
+    fn prepare(&mut self, sql: &str) -> Result<StatementTypes, CatalogError> {
+        self.statement(sql).map_err(CatalogError::Unsupported)
+    }
+
+    fn view_columns(&mut self, name: &str) -> Result<Vec<String>, CatalogError> {
+        let name = match Cursor::new(&tokenize(name)).qualified_name() {
+            Some(name) => name,
+            None => return Ok(Vec::new()),
+        };
+        match self.relation(&name) {
+            Some(relation) if relation.is_view => match &relation.columns {
+                Ok(columns) => Ok(columns.iter().map(|(name, _)| name.clone()).collect()),
+                Err(err) => Err(CatalogError::Unsupported(format!(
+                    "the view `{}` cannot be read: {err}",
+                    name.name
+                ))),
+            },
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    fn unique_columns(&mut self, tables: &[String]) -> Result<Vec<String>, CatalogError> {
+        let tables: Vec<_> = tables
+            .iter()
+            .filter_map(|it| Cursor::new(&tokenize(it)).qualified_name())
+            .collect();
+        Ok(self
+            .unique
+            .iter()
+            .filter(|(table, _)| tables.contains(table))
+            .map(|(_, column)| column.clone())
+            .collect())
+    }
+
+    fn foreign_keys(&mut self) -> Result<Vec<ForeignKeyColumn>, CatalogError> {
+        let mut columns = Vec::new();
+        for fk in &self.foreign_keys {
+            let foreign_columns = match &fk.foreign_columns {
+                Some(columns) => columns.clone(),
+                None => self
+                    .primary_keys
+                    .iter()
+                    .find(|(table, _)| *table == fk.foreign_table)
+                    .map(|(_, columns)| columns.clone())
+                    .unwrap_or_default(),
+            };
+            for (column, foreign_column) in fk.columns.iter().zip(foreign_columns) {
+                columns.push(ForeignKeyColumn {
+                    schema: fk.table.schema.clone(),
+                    constraint: fk.constraint.clone(),
+                    table: fk.table.name.clone(),
+                    column: column.clone(),
+                    foreign_schema: fk.foreign_table.schema.clone(),
+                    foreign_table: fk.foreign_table.name.clone(),
+                    foreign_column,
+                });
+            }
+        }
+        // Stable sort, columns stay in the order of their constraint
+        columns.sort_by(|a, b| {
+            (&a.schema, &a.table, &a.constraint).cmp(&(&b.schema, &b.table, &b.constraint))
+        });
+        Ok(columns)
+    }
+}
+
+/// Rejects the statements that cannot be analyzed without a planner
+fn check_simple(tokens: &[Token]) -> Result<(), String> {
+    if tokens.iter().skip(1).any(|it| it.is_keyword("select")) {
+        return Err("subqueries are not supported".to_string());
+    }
+    if find_top_level(tokens, &["union", "intersect", "except"]).is_some() {
+        return Err("set operations are not supported".to_string());
+    }
+    Ok(())
+}
+
+/// `[OR REPLACE] VIEW name [(columns)] [WITH (options)] AS query [WITH [NO] DATA]`
+fn view(cursor: &mut Cursor) -> Option<View> {
+    cursor.keywords(&["if", "not", "exists"]);
+    let name = cursor.qualified_name()?;
+    let aliases = match cursor.peek() {
+        Some(Token::Punct(p)) if p == "(" => cursor.group().map(names).unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    if cursor.keyword("with") {
+        cursor.group();
+    }
+    if !cursor.keyword("as") {
+        return None;
+    }
+    let mut query = cursor.rest().to_vec();
+    if query.ends_with(&[Token::Ident("data".to_string())]) {
+        let with = query.iter().rposition(|it| it.is_keyword("with"));
+        query.truncate(with.unwrap_or(query.len()));
+    }
+    Some(View {
+        name,
+        aliases,
+        query,
+    })
+}
+
+/// Type of the column `name` of `sources`, or of the relation aliased `qualifier`. Returns `None`
+/// if there is no such column.
+fn column_type(
+    sources: &[Source],
+    qualifier: Option<&str>,
+    name: &str,
+) -> Result<Option<Type>, String> {
+    let mut found = sources
+        .iter()
+        .filter(|it| match qualifier {
+            Some(qualifier) => it.alias == qualifier,
+            None => true,
+        })
+        .flat_map(|it| &it.columns)
+        .filter(|(column, _)| column == name);
+    match (found.next(), found.next()) {
+        (Some((name, ty)), None) => unsupported_column(name, ty).map(Some),
+        (Some(_), Some(_)) => Err(format!("column reference `{name}` is ambiguous")),
+        (None, _) => Ok(None),
+    }
+}
+
+fn unsupported_column(name: &str, ty: &ColumnType) -> Result<Type, String> {
+    ty.clone()
+        .map_err(|ty| format!("the type `{ty}` of the column `{name}` is not supported"))
+}
+
+/// Length and name of a possibly qualified column at the start of `tokens`
+fn column_ref(tokens: &[Token]) -> Option<(usize, String)> {
+    let mut len = 0;
+    loop {
+        let name = match tokens.get(len) {
+            Some(Token::Ident(it) | Token::Quoted(it)) => it.clone(),
+            _ => return None,
+        };
+        len += 1;
+        match tokens.get(len) {
+            Some(Token::Punct(p)) if p == "." && len < 5 => len += 1,
+            // A function call
+            Some(Token::Punct(p)) if p == "(" => return None,
+            _ => return Some((len, name)),
+        }
+    }
+}
+
+/// The relation qualifying a column reference, e.g. `a` in `a.id`
+fn qualifier(column: &[Token]) -> Option<String> {
+    match column {
+        [.., Token::Ident(it) | Token::Quoted(it), Token::Punct(_), Token::Ident(_) | Token::Quoted(_)] => {
+            Some(it.clone())
+        }
+        _ => None,
+    }
+}
+
+fn names(tokens: &[Token]) -> Vec<String> {
+    split_top_level(tokens, ",")
+        .into_iter()
+        .filter_map(|it| match it {
+            [Token::Ident(name) | Token::Quoted(name), ..] => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn token_name(token: &Token) -> Result<String, String> {
+    match token {
+        Token::Ident(name) | Token::Quoted(name) => Ok(name.clone()),
+        token => Err(format!("unexpected `{token}`")),
+    }
+}
+
+fn display(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Index of the first of `keywords` outside of parentheses
+fn find_top_level(tokens: &[Token], keywords: &[&str]) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct(p) if p == "(" => depth += 1,
+            Token::Punct(p) if p == ")" => depth = depth.saturating_sub(1),
+            Token::Punct(p) if depth == 0 && keywords.contains(&p.as_str()) => return Some(i),
+            Token::Ident(it) if depth == 0 && keywords.contains(&it.as_str()) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits `tokens` at each `separator` outside of parentheses
+fn split_top_level<'a>(tokens: &'a [Token], separator: &str) -> Vec<&'a [Token]> {
+    let mut parts = Vec::new();
+    let mut rest = tokens;
+    while let Some(i) = find_top_level(rest, &[separator]) {
+        parts.push(&rest[..i]);
+        rest = &rest[i + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(rest);
+    }
+    parts
+}
+
+fn split_statements(tokens: &[Token]) -> Vec<&[Token]> {
+    split_top_level(tokens, ";")
+}
+
+/// A type as written in SQL
+struct TypeName {
+    schema: Option<String>,
+    /// Lowercase words of a built-in type, e.g. `double precision`, or the name of a custom type
+    name: String,
+    array: bool,
+}
+
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(schema) = &self.schema {
+            write!(f, "{schema}.")?;
+        }
+        write!(f, "{}", self.name)?;
+        if self.array {
+            write!(f, "[]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted identifier or keyword, lowercase
+    Ident(String),
+    Quoted(String),
+    Str(String),
+    Number(String),
+    Param(usize),
+    Punct(String),
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Ident(it) if it == keyword)
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(it) | Token::Number(it) | Token::Punct(it) => write!(f, "{it}"),
+            Token::Quoted(it) => write!(f, "\"{it}\""),
+            Token::Str(it) => write!(f, "'{it}'"),
+            Token::Param(index) => write!(f, "${index}"),
+        }
+    }
+}
+
+/// Splits SQL into tokens, skipping comments and psql meta-commands
+fn tokenize(sql: &str) -> Vec<Token> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let at = |i: usize| chars.get(i).copied().unwrap_or('\0');
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '-' && at(i + 1) == '-' || c == '\\' && (i == 0 || chars[i - 1] == '\n') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && at(i + 1) == '*' {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && at(i + 1) == '/') {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '\'' || (c == 'e' || c == 'E') && at(i + 1) == '\'' {
+            let escapes = c != '\'';
+            i += if escapes { 2 } else { 1 };
+            let mut value = String::new();
+            while i < chars.len() {
+                match chars[i] {
+                    '\\' if escapes => {
+                        value.push(at(i + 1));
+                        i += 2;
+                    }
+                    '\'' if at(i + 1) == '\'' => {
+                        value.push('\'');
+                        i += 2;
+                    }
+                    '\'' => break,
+                    c => {
+                        value.push(c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            tokens.push(Token::Str(value));
+        } else if c == '"' {
+            i += 1;
+            let mut value = String::new();
+            while i < chars.len() {
+                match chars[i] {
+                    '"' if at(i + 1) == '"' => {
+                        value.push('"');
+                        i += 2;
+                    }
+                    '"' => break,
+                    c => {
+                        value.push(c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            tokens.push(Token::Quoted(value));
+        } else if c == '$' && at(i + 1).is_ascii_digit() {
+            let start = i + 1;
+            i += 1;
+            while at(i).is_ascii_digit() {
+                i += 1;
+            }
+            let index: String = chars[start..i].iter().collect();
+            tokens.push(Token::Param(index.parse().unwrap_or(0)));
+        } else if c == '$' {
+            // Dollar quoted string, e.g. a function body
+            let mut end = i + 1;
+            while at(end).is_alphanumeric() || at(end) == '_' {
+                end += 1;
+            }
+            if at(end) != '$' {
+                tokens.push(Token::Punct("$".to_string()));
+                i += 1;
+                continue;
+            }
+            let tag = &chars[i..=end];
+            i = end + 1;
+            let body_start = i;
+            while i < chars.len() && !chars[i..].starts_with(tag) {
+                i += 1;
+            }
+            tokens.push(Token::Str(chars[body_start..i].iter().collect()));
+            i += tag.len();
+        } else if c.is_ascii_digit() || c == '.' && at(i + 1).is_ascii_digit() {
+            let start = i;
+            while at(i).is_ascii_digit() || at(i) == '.' {
+                i += 1;
+            }
+            if (at(i) == 'e' || at(i) == 'E') && (at(i + 1).is_ascii_digit() || at(i + 1) == '-') {
+                i += 2;
+                while at(i).is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while at(i).is_alphanumeric() || at(i) == '_' || at(i) == '$' {
+                i += 1;
+            }
+            let ident: String = chars[start..i].iter().collect();
+            tokens.push(Token::Ident(ident.to_lowercase()));
+        } else if c == ':' && at(i + 1) == ':' {
+            tokens.push(Token::Punct("::".to_string()));
+            i += 2;
+        } else if "+-*/<>=~!@#%^&|`?".contains(c) && c != '*' {
+            let start = i;
+            while "+-*/<>=~!@#%^&|`?".contains(at(i)) && at(i) != '\0' {
+                i += 1;
+            }
+            tokens.push(Token::Punct(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Punct(c.to_string()));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+struct Cursor<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(tokens: &'a [Token]) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn rest(&self) -> &'a [Token] {
+        &self.tokens[self.pos.min(self.tokens.len())..]
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(it) if it.is_keyword(keyword))
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek_keyword(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// Consumes all of `keywords` in order, or none of them
+    fn keywords(&mut self, keywords: &[&str]) -> bool {
+        let found = keywords.iter().enumerate().all(|(i, keyword)| {
+            matches!(self.tokens.get(self.pos + i), Some(it) if it.is_keyword(keyword))
+        });
+        if found {
+            self.pos += keywords.len();
+        }
+        found
+    }
+
+    fn skip_keywords(&mut self, keywords: &[&str]) {
+        while keywords.iter().any(|it| self.keyword(it)) {}
+    }
+
+    fn punct(&mut self, punct: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Punct(p)) if p == punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn name(&mut self) -> Option<String> {
+        match self.peek()? {
+            Token::Ident(name) | Token::Quoted(name) => {
+                self.pos += 1;
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// `[schema.]name`
+    fn qualified_name(&mut self) -> Option<Name> {
+        let first = self.name()?;
+        if self.punct(".") {
+            let name = self.name()?;
+            Some(Name {
+                schema: first,
+                name,
+            })
+        } else {
+            Some(Name {
+                schema: "public".to_string(),
+                name: first,
+            })
+        }
+    }
+
+    /// `[AS] alias`
+    fn alias(&mut self) -> Option<String> {
+        let explicit = self.keyword("as");
+        match self.peek()? {
+            Token::Ident(it) if explicit || !NOT_ALIAS.contains(&it.as_str()) => self.name(),
+            Token::Quoted(_) => self.name(),
+            _ => None,
+        }
+    }
+
+    /// Tokens between parentheses, which are consumed
+    fn group(&mut self) -> Option<&'a [Token]> {
+        if !matches!(self.peek(), Some(Token::Punct(p)) if p == "(") {
+            return None;
+        }
+        let start = self.pos + 1;
+        let mut depth = 0;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Punct(p) if p == "(" => depth += 1,
+                Token::Punct(p) if p == ")" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&self.tokens[start..self.pos - 1]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(&self.tokens[start..])
+    }
+
+    /// `[schema.]name [(modifiers)] [[]]`, with the words of multi-word built-in types
+    fn type_name(&mut self) -> Option<TypeName> {
+        let (schema, mut name) = match (self.peek()?, self.tokens.get(self.pos + 1)) {
+            (Token::Ident(_) | Token::Quoted(_), Some(Token::Punct(p))) if p == "." => {
+                let schema = self.name();
+                self.next();
+                (schema, self.name()?)
+            }
+            _ => (None, self.name()?),
+        };
+        loop {
+            if matches!(self.peek(), Some(Token::Punct(p)) if p == "(") {
+                self.group();
+            } else if let Some(Token::Ident(word)) = self.peek().filter(|_| schema.is_none()) {
+                if !TYPE_WORDS.contains(&word.as_str()) {
+                    break;
+                }
+                name.push(' ');
+                name.push_str(word);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let mut array = false;
+        loop {
+            if self.punct("[") {
+                while !self.punct("]") && !self.at_end() {
+                    self.next();
+                }
+                array = true;
+            } else if self.keyword("array") {
+                array = true;
+            } else {
+                break;
+            }
+        }
+        Some(TypeName {
+            schema,
+            name,
+            array,
+        })
+    }
+}
@@ -9,10 +9,26 @@ use crate::{
     parser::Span,
     read_queries::ModuleInfo,
     utils::SchemaKey,
+    TimeBackend,
 };
 
 use self::error::Error;
 
+/// Representation of a simple type, when it is not its natural Rust equivalent
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub(crate) enum Repr {
+    Native,
+    /// `numeric` approximated by an `f64`, decoded through the client's `NumericF64`
+    NumericF64,
+    /// A float wrapped in the client's totally ordered `OrderedF32` or `OrderedF64`
+    OrderedFloat,
+    /// A timestamp wrapped in the client's `Timestamp`, which can represent infinity
+    InfiniteTimestamp,
+    /// A param without a Rust equivalent, bound from its text representation with the client's
+    /// `TextParam`
+    Text,
+}
+
 /// A struct containing a postgres type and its Rust-equivalent.
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub(crate) enum CornucopiaType {
@@ -20,6 +36,7 @@ pub(crate) enum CornucopiaType {
         pg_ty: Type,
         rust_name: &'static str,
         is_copy: bool,
+        repr: Repr,
     },
     Array {
         inner: Rc<CornucopiaType>,
@@ -28,11 +45,31 @@ pub(crate) enum CornucopiaType {
         pg_ty: Type,
         inner: Rc<CornucopiaType>,
     },
+    Range {
+        pg_ty: Type,
+        inner: Rc<CornucopiaType>,
+    },
+    Multirange {
+        pg_ty: Type,
+        inner: Rc<CornucopiaType>,
+    },
     Custom {
         pg_ty: Type,
         struct_name: String,
         is_copy: bool,
         is_params: bool,
+        /// Its borrowed form can be serialized
+        is_brw_ser: bool,
+    },
+    IntEnum {
+        pg_ty: Type,
+        enum_name: String,
+    },
+    /// A row column converted to `rust_ty` by calling `map` on its borrowed value
+    Mapped {
+        inner: Rc<CornucopiaType>,
+        rust_ty: String,
+        map: String,
     },
 }
 
@@ -40,13 +77,25 @@ impl CornucopiaType {
     /// Is this type need a generic lifetime
     pub fn is_ref(&self) -> bool {
         match self {
+            CornucopiaType::Simple {
+                repr: Repr::Text, ..
+            } => false,
             CornucopiaType::Simple { pg_ty, .. } => match *pg_ty {
-                Type::BYTEA | Type::TEXT | Type::VARCHAR | Type::JSON | Type::JSONB => false,
+                Type::BYTEA
+                | Type::TEXT
+                | Type::VARCHAR
+                | Type::JSON
+                | Type::JSONB
+                | Type::JSONPATH
+                | Type::NUMERIC => false,
+                _ if is_hstore(pg_ty) => false,
                 _ => !self.is_copy(),
             },
-            CornucopiaType::Domain { inner, .. } | CornucopiaType::Array { inner } => {
-                inner.is_ref()
-            }
+            CornucopiaType::Domain { inner, .. }
+            | CornucopiaType::Range { inner, .. }
+            | CornucopiaType::Multirange { inner, .. }
+            | CornucopiaType::Mapped { inner, .. }
+            | CornucopiaType::Array { inner } => inner.is_ref(),
             _ => !self.is_copy(),
         }
     }
@@ -57,21 +106,135 @@ impl CornucopiaType {
             CornucopiaType::Simple { is_copy, .. } | CornucopiaType::Custom { is_copy, .. } => {
                 *is_copy
             }
-            CornucopiaType::Domain { inner, .. } => inner.is_copy(),
-            CornucopiaType::Array { .. } => false,
+            CornucopiaType::Domain { inner, .. } | CornucopiaType::Range { inner, .. } => {
+                inner.is_copy()
+            }
+            CornucopiaType::Array { .. }
+            | CornucopiaType::Multirange { .. }
+            | CornucopiaType::Mapped { .. } => false,
+            CornucopiaType::IntEnum { .. } => true,
         }
     }
 
     /// Can this used in parameters as it is
     pub fn is_params(&self) -> bool {
         match self {
-            CornucopiaType::Simple { .. } => true,
-            CornucopiaType::Array { .. } => false,
-            CornucopiaType::Domain { inner, .. } => inner.is_params(),
+            CornucopiaType::Simple { .. } | CornucopiaType::IntEnum { .. } => true,
+            CornucopiaType::Array { .. } | CornucopiaType::Mapped { .. } => false,
+            CornucopiaType::Domain { inner, .. }
+            | CornucopiaType::Range { inner, .. }
+            | CornucopiaType::Multirange { inner, .. } => inner.is_params(),
             CornucopiaType::Custom { is_params, .. } => *is_params,
         }
     }
 
+    /// Can the borrowed form of this type be serialized, `postgres_types::Json` is not `Serialize`
+    pub fn is_brw_ser(&self) -> bool {
+        match self {
+            CornucopiaType::Simple { pg_ty, .. } => !matches!(*pg_ty, Type::JSON | Type::JSONB),
+            CornucopiaType::Array { inner }
+            | CornucopiaType::Domain { inner, .. }
+            | CornucopiaType::Range { inner, .. }
+            | CornucopiaType::Multirange { inner, .. }
+            | CornucopiaType::Mapped { inner, .. } => inner.is_brw_ser(),
+            CornucopiaType::Custom { is_brw_ser, .. } => *is_brw_ser,
+            CornucopiaType::IntEnum { .. } => true,
+        }
+    }
+
+    /// Can this be hashed, floats, JSON values and infinite timestamps cannot. Generated enums
+    /// derive `Hash` with `derive_hash`, composites never do
+    pub fn is_hash(&self) -> bool {
+        match self {
+            CornucopiaType::Simple { pg_ty, repr, .. } => match *pg_ty {
+                Type::FLOAT4 | Type::FLOAT8 => *repr == Repr::OrderedFloat,
+                Type::NUMERIC => *repr != Repr::NumericF64,
+                Type::JSON | Type::JSONB => false,
+                _ => !is_hstore(pg_ty) && *repr != Repr::InfiniteTimestamp,
+            },
+            CornucopiaType::Array { inner }
+            | CornucopiaType::Domain { inner, .. }
+            | CornucopiaType::Range { inner, .. }
+            | CornucopiaType::Multirange { inner, .. } => inner.is_hash(),
+            CornucopiaType::Custom { pg_ty, .. } => matches!(pg_ty.kind(), Kind::Enum(_)),
+            CornucopiaType::IntEnum { .. } => true,
+            CornucopiaType::Mapped { .. } => false,
+        }
+    }
+
+    /// Is this a number whose values JavaScript cannot all represent
+    pub fn is_big_number(&self) -> bool {
+        match self {
+            CornucopiaType::Simple { pg_ty, repr, .. } => {
+                *pg_ty == Type::INT8 || (*pg_ty == Type::NUMERIC && *repr != Repr::NumericF64)
+            }
+            CornucopiaType::Domain { inner, .. } => inner.is_big_number(),
+            _ => false,
+        }
+    }
+
+    /// This type with `numeric` mapped to `f64` named `numeric`, as it is not extracted like a
+    /// `float8`. Single column rows are named after their type.
+    pub(crate) fn with_numeric_name(&self) -> CornucopiaType {
+        match self {
+            CornucopiaType::Simple {
+                pg_ty,
+                is_copy,
+                repr: Repr::NumericF64,
+                ..
+            } => CornucopiaType::Simple {
+                pg_ty: pg_ty.clone(),
+                rust_name: "numeric",
+                is_copy: *is_copy,
+                repr: Repr::NumericF64,
+            },
+            CornucopiaType::Array { inner } => CornucopiaType::Array {
+                inner: Rc::new(inner.with_numeric_name()),
+            },
+            CornucopiaType::Domain { pg_ty, inner } => CornucopiaType::Domain {
+                pg_ty: pg_ty.clone(),
+                inner: Rc::new(inner.with_numeric_name()),
+            },
+            CornucopiaType::Range { pg_ty, inner } => CornucopiaType::Range {
+                pg_ty: pg_ty.clone(),
+                inner: Rc::new(inner.with_numeric_name()),
+            },
+            CornucopiaType::Multirange { pg_ty, inner } => CornucopiaType::Multirange {
+                pg_ty: pg_ty.clone(),
+                inner: Rc::new(inner.with_numeric_name()),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Can this be bound from its text representation, which array elements cannot be
+    pub fn is_text_bindable(&self) -> bool {
+        match self {
+            CornucopiaType::Simple { repr, .. } => {
+                matches!(repr, Repr::Text | Repr::NumericF64)
+            }
+            CornucopiaType::Domain { inner, .. } => inner.is_text_bindable(),
+            _ => false,
+        }
+    }
+
+    /// Borrowed type usable in a `Cow`, if any
+    pub fn cow_ty(&self) -> Option<&'static str> {
+        match self {
+            CornucopiaType::Simple {
+                pg_ty, rust_name, ..
+            } => match *pg_ty {
+                Type::BYTEA => Some("[u8]"),
+                // `&[u8]` does not accept `text` values
+                Type::TEXT | Type::VARCHAR if *rust_name == "Vec<u8>" => None,
+                Type::TEXT | Type::VARCHAR => Some("str"),
+                _ => None,
+            },
+            CornucopiaType::Domain { inner, .. } => inner.cow_ty(),
+            _ => None,
+        }
+    }
+
     /// Wrap type to escape domains in parameters
     pub(crate) fn sql_wrapped(&self, name: &str, ctx: &GenCtx) -> String {
         let client_name = ctx.client_name();
@@ -119,8 +282,26 @@ impl CornucopiaType {
         match self {
             CornucopiaType::Simple { pg_ty, .. }
             | CornucopiaType::Custom { pg_ty, .. }
-            | CornucopiaType::Domain { pg_ty, .. } => pg_ty,
-            CornucopiaType::Array { inner } => inner.pg_ty(),
+            | CornucopiaType::Domain { pg_ty, .. }
+            | CornucopiaType::Range { pg_ty, .. }
+            | CornucopiaType::Multirange { pg_ty, .. }
+            | CornucopiaType::IntEnum { pg_ty, .. } => pg_ty,
+            CornucopiaType::Array { inner } | CornucopiaType::Mapped { inner, .. } => inner.pg_ty(),
+        }
+    }
+
+    /// PostgreSQL name of this type, qualified by its schema outside of the default ones
+    pub(crate) fn pg_name(&self) -> String {
+        match self {
+            CornucopiaType::Array { inner } => format!("{}[]", inner.pg_name()),
+            CornucopiaType::Mapped { inner, .. } => inner.pg_name(),
+            _ => {
+                let ty = self.pg_ty();
+                match ty.schema() {
+                    "public" | "pg_catalog" => ty.name().to_string(),
+                    schema => format!("{schema}.{}", ty.name()),
+                }
+            }
         }
     }
 
@@ -144,11 +325,23 @@ impl CornucopiaType {
             CornucopiaType::Simple { pg_ty, .. } if matches!(*pg_ty, Type::JSON | Type::JSONB) => {
                 format!("serde_json::from_str({name}.0.get()).unwrap()")
             }
+            CornucopiaType::Simple { pg_ty, .. } if is_hstore(pg_ty) => {
+                format!("{name}.map(|(k, v)| (k.into(), v.map(Into::into))).collect()")
+            }
             CornucopiaType::Array { inner, .. } => {
                 let inner = inner.owning_call("v", is_inner_nullable, false);
                 format!("{name}.map(|v| {inner}).collect()")
             }
             CornucopiaType::Domain { inner, .. } => inner.owning_call(name, is_nullable, false),
+            CornucopiaType::Range { inner, .. } => {
+                let inner = inner.owning_call("v", false, false);
+                format!("{name}.map(|v| {inner})")
+            }
+            CornucopiaType::Multirange { inner, .. } => {
+                let inner = inner.owning_call("v", false, false);
+                format!("{name}.map(|r| r.map(|v| {inner})).collect()")
+            }
+            CornucopiaType::Mapped { map, .. } => format!("{map}({name})"),
             _ => {
                 format!("{name}.into()")
             }
@@ -158,6 +351,18 @@ impl CornucopiaType {
     /// Corresponding owned type
     pub(crate) fn own_ty(&self, is_inner_nullable: bool, ctx: &GenCtx) -> String {
         match self {
+            CornucopiaType::Simple { pg_ty, .. } if *pg_ty == Type::JSONPATH => {
+                format!("{}::JsonPath", ctx.client_name())
+            }
+            CornucopiaType::Simple {
+                rust_name,
+                repr: Repr::OrderedFloat | Repr::InfiniteTimestamp,
+                ..
+            }
+            | CornucopiaType::Simple {
+                rust_name: rust_name @ "RegOid",
+                ..
+            } => format!("{}::{rust_name}", ctx.client_name()),
             CornucopiaType::Simple { rust_name, .. } => (*rust_name).to_string(),
             CornucopiaType::Array { inner, .. } => {
                 let own_inner = inner.own_ty(false, ctx);
@@ -168,9 +373,21 @@ impl CornucopiaType {
                 }
             }
             CornucopiaType::Domain { inner, .. } => inner.own_ty(false, ctx),
+            CornucopiaType::Range { inner, .. } => {
+                format!("{}::Range<{}>", ctx.client_name(), inner.own_ty(false, ctx))
+            }
+            CornucopiaType::Multirange { inner, .. } => {
+                format!(
+                    "Vec<{}::Range<{}>>",
+                    ctx.client_name(),
+                    inner.own_ty(false, ctx)
+                )
+            }
             CornucopiaType::Custom {
                 struct_name, pg_ty, ..
             } => custom_ty_path(pg_ty.schema(), struct_name, ctx),
+            CornucopiaType::IntEnum { enum_name, .. } => int_enum_path(enum_name, ctx),
+            CornucopiaType::Mapped { rust_ty, .. } => rust_ty.clone(),
         }
     }
 
@@ -183,7 +400,16 @@ impl CornucopiaType {
     ) -> String {
         let client_name = ctx.client_name();
         match self {
-            CornucopiaType::Simple { pg_ty, .. } => match *pg_ty {
+            CornucopiaType::Simple { pg_ty, repr, .. } => match *pg_ty {
+                _ if *repr == Repr::Text => {
+                    traits.push(format!("{client_name}::StringSql"));
+                    format!("{client_name}::TextParam<{}>", idx_char(traits.len()))
+                }
+                // Also accepts exact values bound from their text representation
+                Type::NUMERIC if *repr == Repr::NumericF64 => {
+                    traits.push(format!("{client_name}::NumericSql"));
+                    idx_char(traits.len())
+                }
                 Type::BYTEA => {
                     traits.push(format!("{client_name}::BytesSql"));
                     idx_char(traits.len())
@@ -196,10 +422,18 @@ impl CornucopiaType {
                     traits.push(format!("{client_name}::JsonSql"));
                     idx_char(traits.len())
                 }
+                _ if is_hstore(pg_ty) => {
+                    traits.push(format!("{client_name}::HstoreSql"));
+                    idx_char(traits.len())
+                }
                 _ => self.param_ty(is_inner_nullable, ctx),
             },
             CornucopiaType::Array { inner, .. } => {
-                let inner = inner.param_ergo_ty(is_inner_nullable, traits, ctx);
+                let inner = if inner.is_text_bindable() {
+                    inner.param_ty(is_inner_nullable, ctx)
+                } else {
+                    inner.param_ergo_ty(is_inner_nullable, traits, ctx)
+                };
                 let inner = if is_inner_nullable {
                     format!("Option<{inner}>")
                 } else {
@@ -208,18 +442,29 @@ impl CornucopiaType {
                 traits.push(format!("{client_name}::ArraySql<Item = {inner}>"));
                 idx_char(traits.len())
             }
-            CornucopiaType::Domain { inner, .. } => {
+            CornucopiaType::Domain { inner, .. } | CornucopiaType::Mapped { inner, .. } => {
                 inner.param_ergo_ty(is_inner_nullable, traits, ctx)
             }
-            CornucopiaType::Custom { .. } => self.param_ty(is_inner_nullable, ctx),
+            CornucopiaType::Range { .. }
+            | CornucopiaType::Multirange { .. }
+            | CornucopiaType::Custom { .. }
+            | CornucopiaType::IntEnum { .. } => self.param_ty(is_inner_nullable, ctx),
         }
     }
 
     /// Corresponding borrowed parameter type
     pub(crate) fn param_ty(&self, is_inner_nullable: bool, ctx: &GenCtx) -> String {
         match self {
+            CornucopiaType::Simple {
+                repr: Repr::Text, ..
+            } => format!("{}::TextParam<&'a str>", ctx.client_name()),
             CornucopiaType::Simple { pg_ty, .. } => match *pg_ty {
                 Type::JSON | Type::JSONB => "&'a serde_json::value::Value".to_string(),
+                // Parameters are valid UTF-8 whatever the way text columns are read
+                Type::TEXT | Type::VARCHAR => "&'a str".to_string(),
+                _ if is_hstore(pg_ty) => {
+                    "&'a std::collections::HashMap<String, Option<String>>".to_string()
+                }
                 _ => self.brw_ty(is_inner_nullable, true, ctx),
             },
             CornucopiaType::Array { inner, .. } => {
@@ -233,6 +478,20 @@ impl CornucopiaType {
                 format!("&'a [{inner}]")
             }
             CornucopiaType::Domain { inner, .. } => inner.param_ty(false, ctx),
+            CornucopiaType::Range { inner, .. } => {
+                format!(
+                    "{}::Range<{}>",
+                    ctx.client_name(),
+                    inner.param_ty(false, ctx)
+                )
+            }
+            CornucopiaType::Multirange { inner, .. } => {
+                format!(
+                    "{}::Multirange<{}>",
+                    ctx.client_name(),
+                    inner.param_ty(false, ctx)
+                )
+            }
             CornucopiaType::Custom {
                 is_params,
                 is_copy,
@@ -247,6 +506,8 @@ impl CornucopiaType {
                     self.brw_ty(is_inner_nullable, true, ctx)
                 }
             }
+            CornucopiaType::IntEnum { .. } => self.brw_ty(is_inner_nullable, true, ctx),
+            CornucopiaType::Mapped { inner, .. } => inner.param_ty(is_inner_nullable, ctx),
         }
     }
 
@@ -261,13 +522,38 @@ impl CornucopiaType {
         let lifetime = if has_lifetime { "'a" } else { "" };
         match self {
             CornucopiaType::Simple {
-                pg_ty, rust_name, ..
+                pg_ty,
+                rust_name,
+                repr,
+                ..
             } => match *pg_ty {
                 Type::BYTEA => format!("&{lifetime} [u8]"),
+                // `&[u8]` does not accept `text` values, decode them through a wrapper
+                Type::TEXT | Type::VARCHAR if *rust_name == "Vec<u8>" => {
+                    let lifetime = if has_lifetime { lifetime } else { "'_" };
+                    format!("{}::TextBytes<{lifetime}>", ctx.client_name())
+                }
                 Type::TEXT | Type::VARCHAR => format!("&{lifetime} str"),
                 Type::JSON | Type::JSONB => {
                     format!("postgres_types::Json<&{lifetime} serde_json::value::RawValue>")
                 }
+                _ if is_hstore(pg_ty) => {
+                    let lifetime = if has_lifetime { lifetime } else { "'_" };
+                    let client_name = ctx.client_name();
+                    format!("{client_name}::HstoreIterator<{lifetime}>")
+                }
+                Type::JSONPATH => format!("{}::JsonPath", ctx.client_name()),
+                // `f64` does not accept `numeric` values, decode them through a wrapper
+                Type::NUMERIC if *repr == Repr::NumericF64 => {
+                    format!("{}::NumericF64", ctx.client_name())
+                }
+                Type::FLOAT4 | Type::FLOAT8 if *repr == Repr::OrderedFloat => {
+                    format!("{}::{rust_name}", ctx.client_name())
+                }
+                _ if *rust_name == "RegOid" => format!("{}::RegOid", ctx.client_name()),
+                Type::TIMESTAMP | Type::TIMESTAMPTZ if *repr == Repr::InfiniteTimestamp => {
+                    format!("{}::{rust_name}", ctx.client_name())
+                }
                 _ => (*rust_name).to_string(),
             },
             CornucopiaType::Array { inner, .. } => {
@@ -283,6 +569,17 @@ impl CornucopiaType {
                 format!("{client_name}::ArrayIterator<{lifetime}, {inner}>")
             }
             CornucopiaType::Domain { inner, .. } => inner.brw_ty(false, has_lifetime, ctx),
+            CornucopiaType::Range { inner, .. } => format!(
+                "{}::Range<{}>",
+                ctx.client_name(),
+                inner.brw_ty(false, has_lifetime, ctx)
+            ),
+            CornucopiaType::Multirange { inner, .. } => {
+                let inner = inner.brw_ty(false, has_lifetime, ctx);
+                let lifetime = if has_lifetime { lifetime } else { "'_" };
+                let client_name = ctx.client_name();
+                format!("{client_name}::MultirangeIterator<{lifetime}, {inner}>")
+            }
             CornucopiaType::Custom {
                 is_copy,
                 pg_ty,
@@ -296,6 +593,11 @@ impl CornucopiaType {
                     format!("{}Borrowed<{lifetime}>", path)
                 }
             }
+            CornucopiaType::IntEnum { enum_name, .. } => int_enum_path(enum_name, ctx),
+            // Extracted as its inner type, the conversion happens when taking ownership
+            CornucopiaType::Mapped { inner, .. } => {
+                inner.brw_ty(is_inner_nullable, has_lifetime, ctx)
+            }
         }
     }
 }
@@ -313,13 +615,55 @@ pub fn custom_ty_path(schema: &str, struct_name: &str, ctx: &GenCtx) -> String {
     }
 }
 
+/// `hstore` is an extension type without a fixed oid
+fn is_hstore(ty: &Type) -> bool {
+    ty.name() == "hstore"
+}
+
+/// Int-coded enums are declared in their query module
+pub fn int_enum_path(enum_name: &str, ctx: &GenCtx) -> String {
+    ctx.path(ctx.depth.saturating_sub(2), enum_name)
+}
+
 /// Data structure holding all types known to this particular run of Cornucopia.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct TypeRegistrar {
     pub types: IndexMap<(String, String), Rc<CornucopiaType>>,
+    /// Map `numeric` to `f64` instead of `rust_decimal::Decimal`
+    pub numeric_as_f64: bool,
+    /// Map `real` and `double precision` to totally ordered wrappers instead of `f32` and `f64`
+    pub ordered_floats: bool,
+    /// Map `text` and `varchar` to `Vec<u8>` instead of `String`
+    pub text_as_bytes: bool,
+    /// Map `timestamp` and `timestamptz` to `Timestamp`, accepting infinite values
+    pub infinite_timestamps: bool,
+    /// Crate providing the types of temporal columns
+    pub time_backend: TimeBackend,
 }
 
 impl TypeRegistrar {
+    /// Registers the type of a query param. Params of a scalar type without a Rust equivalent,
+    /// like `interval`, are bound from their text representation.
+    pub(crate) fn register_param(
+        &mut self,
+        name: &str,
+        ty: &Type,
+        query_name: &Span<String>,
+        module_info: &ModuleInfo,
+    ) -> Result<Rc<CornucopiaType>, Error> {
+        match self.register(name, ty, query_name, module_info) {
+            Err(Error::UnsupportedPostgresType { .. }) if *ty.kind() == Kind::Simple => {
+                Ok(Rc::new(CornucopiaType::Simple {
+                    pg_ty: ty.clone(),
+                    rust_name: "TextParam",
+                    is_copy: false,
+                    repr: Repr::Text,
+                }))
+            }
+            result => result.map(Rc::clone),
+        }
+    }
+
     pub(crate) fn register(
         &mut self,
         name: &str,
@@ -327,13 +671,14 @@ impl TypeRegistrar {
         query_name: &Span<String>,
         module_info: &ModuleInfo,
     ) -> Result<&Rc<CornucopiaType>, Error> {
-        fn custom(ty: &Type, is_copy: bool, is_params: bool) -> CornucopiaType {
+        fn custom(ty: &Type, is_copy: bool, is_params: bool, is_brw_ser: bool) -> CornucopiaType {
             let rust_ty_name = ty.name().to_upper_camel_case();
             CornucopiaType::Custom {
                 pg_ty: ty.clone(),
                 struct_name: rust_ty_name,
                 is_copy,
                 is_params,
+                is_brw_ser,
             }
         }
 
@@ -349,7 +694,7 @@ impl TypeRegistrar {
         }
 
         Ok(match ty.kind() {
-            Kind::Enum(_) => self.insert(ty, || custom(ty, true, true)),
+            Kind::Enum(_) => self.insert(ty, || custom(ty, true, true, true)),
             Kind::Array(inner_ty) => {
                 let inner = self
                     .register(name, inner_ty, query_name, module_info)?
@@ -364,36 +709,78 @@ impl TypeRegistrar {
                     .clone();
                 self.insert(ty, || domain(ty, inner.clone()))
             }
+            Kind::Range(inner_ty) => {
+                let inner = self
+                    .register(name, inner_ty, query_name, module_info)?
+                    .clone();
+                self.insert(ty, || CornucopiaType::Range {
+                    pg_ty: ty.clone(),
+                    inner: inner.clone(),
+                })
+            }
+            Kind::Multirange(inner_ty) => {
+                let inner = self
+                    .register(name, inner_ty, query_name, module_info)?
+                    .clone();
+                self.insert(ty, || CornucopiaType::Multirange {
+                    pg_ty: ty.clone(),
+                    inner: inner.clone(),
+                })
+            }
             Kind::Composite(composite_fields) => {
                 let mut is_copy = true;
                 let mut is_params = true;
+                let mut is_brw_ser = true;
                 for field in composite_fields {
                     let field_ty = self.register(name, field.type_(), query_name, module_info)?;
                     is_copy &= field_ty.is_copy();
                     is_params &= field_ty.is_params();
+                    is_brw_ser &= field_ty.is_brw_ser();
                 }
-                self.insert(ty, || custom(ty, is_copy, is_params))
+                self.insert(ty, || custom(ty, is_copy, is_params, is_brw_ser))
             }
             Kind::Simple => {
+                let repr = self.repr(ty);
                 let (rust_name, is_copy) = match *ty {
                     Type::BOOL => ("bool", true),
                     Type::CHAR => ("i8", true),
                     Type::INT2 => ("i16", true),
                     Type::INT4 => ("i32", true),
                     Type::INT8 => ("i64", true),
+                    Type::FLOAT4 if repr == Repr::OrderedFloat => ("OrderedF32", true),
+                    Type::FLOAT8 if repr == Repr::OrderedFloat => ("OrderedF64", true),
                     Type::FLOAT4 => ("f32", true),
                     Type::FLOAT8 => ("f64", true),
+                    Type::TEXT | Type::VARCHAR if self.text_as_bytes => ("Vec<u8>", false),
                     Type::TEXT | Type::VARCHAR => ("String", false),
                     Type::BYTEA => ("Vec<u8>", false),
-                    Type::TIMESTAMP => ("time::PrimitiveDateTime", true),
-                    Type::TIMESTAMPTZ => ("time::OffsetDateTime", true),
-                    Type::DATE => ("time::Date", true),
-                    Type::TIME => ("time::Time", true),
+                    Type::TIMESTAMP | Type::TIMESTAMPTZ | Type::DATE | Type::TIME
+                        if self.time_backend != TimeBackend::None =>
+                    {
+                        (self.temporal_rust_name(ty, repr), true)
+                    }
                     Type::JSON | Type::JSONB => ("serde_json::Value", false),
+                    Type::JSONPATH => ("JsonPath", false),
                     Type::UUID => ("uuid::Uuid", true),
                     Type::INET => ("std::net::IpAddr", true),
                     Type::MACADDR => ("eui48::MacAddress", true),
+                    Type::NUMERIC if repr == Repr::NumericF64 => ("f64", false),
                     Type::NUMERIC => ("rust_decimal::Decimal", true),
+                    // OID alias types are sent as OIDs, not by their textual name
+                    Type::REGCLASS
+                    | Type::REGCOLLATION
+                    | Type::REGCONFIG
+                    | Type::REGDICTIONARY
+                    | Type::REGNAMESPACE
+                    | Type::REGOPER
+                    | Type::REGOPERATOR
+                    | Type::REGPROC
+                    | Type::REGPROCEDURE
+                    | Type::REGROLE
+                    | Type::REGTYPE => ("RegOid", true),
+                    _ if is_hstore(ty) => {
+                        ("std::collections::HashMap<String, Option<String>>", false)
+                    }
                     _ => {
                         return Err(Error::UnsupportedPostgresType {
                             src: module_info.clone().into(),
@@ -407,6 +794,7 @@ impl TypeRegistrar {
                     pg_ty: ty.clone(),
                     rust_name,
                     is_copy,
+                    repr,
                 })
             }
             _ => {
@@ -420,6 +808,44 @@ impl TypeRegistrar {
         })
     }
 
+    /// Rust type of a temporal column under the selected time backend
+    /// Representation of a simple type
+    fn repr(&self, ty: &Type) -> Repr {
+        match *ty {
+            Type::NUMERIC if self.numeric_as_f64 => Repr::NumericF64,
+            Type::FLOAT4 | Type::FLOAT8 if self.ordered_floats => Repr::OrderedFloat,
+            Type::TIMESTAMP | Type::TIMESTAMPTZ
+                if self.infinite_timestamps && self.time_backend != TimeBackend::None =>
+            {
+                Repr::InfiniteTimestamp
+            }
+            _ => Repr::Native,
+        }
+    }
+
+    fn temporal_rust_name(&self, ty: &Type, repr: Repr) -> &'static str {
+        match (
+            self.time_backend,
+            ty.clone(),
+            repr == Repr::InfiniteTimestamp,
+        ) {
+            (TimeBackend::Chrono, Type::TIMESTAMP, true) => "Timestamp<chrono::NaiveDateTime>",
+            (TimeBackend::Chrono, Type::TIMESTAMPTZ, true) => {
+                "Timestamp<chrono::DateTime<chrono::Utc>>"
+            }
+            (TimeBackend::Chrono, Type::TIMESTAMP, false) => "chrono::NaiveDateTime",
+            (TimeBackend::Chrono, Type::TIMESTAMPTZ, false) => "chrono::DateTime<chrono::Utc>",
+            (TimeBackend::Chrono, Type::DATE, _) => "chrono::NaiveDate",
+            (TimeBackend::Chrono, _, _) => "chrono::NaiveTime",
+            (_, Type::TIMESTAMP, true) => "Timestamp<time::PrimitiveDateTime>",
+            (_, Type::TIMESTAMPTZ, true) => "Timestamp<time::OffsetDateTime>",
+            (_, Type::TIMESTAMP, false) => "time::PrimitiveDateTime",
+            (_, Type::TIMESTAMPTZ, false) => "time::OffsetDateTime",
+            (_, Type::DATE, _) => "time::Date",
+            _ => "time::Time",
+        }
+    }
+
     pub(crate) fn ref_of(&self, ty: &Type) -> Rc<CornucopiaType> {
         self.types
             .get(&SchemaKey::from(ty))
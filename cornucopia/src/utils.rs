@@ -33,6 +33,69 @@ pub fn find_duplicate<T>(slice: &[T], eq: fn(&T, &T) -> bool) -> Option<(&T, &T)
     None
 }
 
+/// Matches `text` against a glob `pattern`, where `*` matches any sequence of characters
+/// and `?` a single one.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and of the text it was tried at, to backtrack on mismatch
+    let mut star = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Finds the value of a literal `LIMIT` applied to the whole query,
+/// ignoring quoted text and the clauses of nested queries.
+pub(crate) fn literal_limit(sql: &str) -> Option<usize> {
+    let mut top_level = String::with_capacity(sql.len());
+    let mut depth = 0usize;
+    let mut quote = None;
+    for c in sql.chars() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, c) if depth == 0 => {
+                top_level.push(c);
+                continue;
+            }
+            _ => {}
+        }
+        top_level.push(' ');
+    }
+    let words: Vec<&str> = top_level
+        .split(|c: char| c.is_whitespace() || c == ';')
+        .filter(|w| !w.is_empty())
+        .collect();
+    words
+        .windows(2)
+        .rev()
+        .find(|w| w[0].eq_ignore_ascii_case("limit"))
+        .and_then(|w| w[1].parse().ok())
+}
+
 /// Extracts useful info from a `postgres`-generated error.
 pub(crate) fn db_err(err: &postgres::Error) -> Option<(u32, String, Option<String>)> {
     if let Some(db_err) = err.as_db_error() {
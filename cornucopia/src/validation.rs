@@ -1,15 +1,18 @@
 use std::collections::BTreeMap;
 
 use crate::{
-    parser::{Module, NullableIdent, Query, QueryDataStruct, Span, TypeAnnotation},
-    prepare_queries::{PreparedField, PreparedModule},
+    catalog::Column,
+    parser::{
+        AttributeTarget, ColumnMapping, EnumAnnotation, Module, NullableIdent, Query,
+        QueryAttribute, QueryDataStruct, Span, TypeAnnotation, ViewAnnotation,
+    },
+    prepare_queries::{PreparedField, PreparedIntEnum, PreparedModule},
     read_queries::ModuleInfo,
     utils::{find_duplicate, STRICT_KEYWORD},
 };
 
-use error::Error;
+use error::{Error, Warning};
 use miette::SourceSpan;
-use postgres::Column;
 use postgres_types::Type;
 
 pub(crate) fn duplicate_nullable_ident(
@@ -26,6 +29,20 @@ pub(crate) fn duplicate_nullable_ident(
     })
 }
 
+/// Only the params of a query can be given a Postgres type, which is cast in its SQL
+pub(crate) fn misplaced_param_type(
+    info: &ModuleInfo,
+    idents: &[NullableIdent],
+) -> Result<(), Box<Error>> {
+    match idents.iter().find_map(|it| it.pg_ty.as_ref()) {
+        Some(ty) => Err(Box::new(Error::MisplacedParamType {
+            src: info.into(),
+            pos: ty.span,
+        })),
+        None => Ok(()),
+    }
+}
+
 pub(crate) fn duplicate_sql_col_name(
     info: &ModuleInfo,
     query_name: &Span<String>,
@@ -55,6 +72,130 @@ pub(crate) fn query_name_already_used(
     })
 }
 
+pub(crate) fn view_name_already_used(
+    info: &ModuleInfo,
+    views: &[ViewAnnotation],
+    queries: &[Query],
+) -> Result<(), Box<Error>> {
+    let mut names: Vec<_> = views
+        .iter()
+        .map(|it| &it.name)
+        .chain(queries.iter().map(|it| &it.name))
+        .collect();
+    names.sort_by_key(|it| it.span.offset());
+    find_duplicate(&names, |a, b| a == b).map_or(Ok(()), |(first, second)| {
+        Err(Box::new(Error::DuplicateType {
+            src: info.into(),
+            ty: "query",
+            name: first.value.clone(),
+            first: first.span,
+            second: second.span,
+        }))
+    })
+}
+
+pub(crate) fn unknown_view(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    columns: &[String],
+) -> Result<(), Box<Error>> {
+    if columns.is_empty() {
+        return Err(Box::new(Error::UnknownView {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: name.span,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn attribute_without_struct(
+    info: &ModuleInfo,
+    query_name: &Span<String>,
+    attribute: &QueryAttribute,
+    has_struct: bool,
+) -> Result<(), Box<Error>> {
+    if !has_struct {
+        return Err(Box::new(Error::AttributeWithoutStruct {
+            src: info.into(),
+            name: query_name.value.clone(),
+            target: match attribute.target.value {
+                AttributeTarget::Row => "row",
+                AttributeTarget::Params => "params",
+            },
+            pos: attribute.target.span,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn group_without_struct(
+    info: &ModuleInfo,
+    query_name: &Span<String>,
+    group: &Span<String>,
+    has_struct: bool,
+) -> Result<(), Box<Error>> {
+    if !has_struct {
+        return Err(Box::new(Error::GroupWithoutStruct {
+            src: info.into(),
+            name: query_name.value.clone(),
+            pos: group.span,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn group_columns(
+    info: &ModuleInfo,
+    group: &Span<String>,
+    fields: &[PreparedField],
+    columns: &[usize],
+) -> Result<(), Box<Error>> {
+    if columns.is_empty() {
+        return Err(Box::new(Error::EmptyGroup {
+            src: info.into(),
+            prefix: format!("{}_", group.value),
+            pos: group.span,
+            known: fields
+                .iter()
+                .map(|it| it.ident.db.clone())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }));
+    }
+    if fields.iter().any(|it| it.ident.db == group.value) {
+        return Err(Box::new(Error::GroupFieldConflict {
+            src: info.into(),
+            name: group.value.clone(),
+            pos: group.span,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn conflicting_attributes(
+    info: &ModuleInfo,
+    name: &str,
+    (first, first_attributes): (SourceSpan, &[String]),
+    (second, second_attributes): (SourceSpan, &[String]),
+) -> Result<(), Box<Error>> {
+    let mut first_sorted = first_attributes.to_vec();
+    let mut second_sorted = second_attributes.to_vec();
+    first_sorted.sort();
+    second_sorted.sort();
+    if first_sorted != second_sorted {
+        return Err(Box::new(Error::ConflictingAttributes {
+            src: info.into(),
+            name: name.to_string(),
+            first_label: format!("declares `{}`", first_attributes.join(", ")),
+            first,
+            second_label: format!("but this declares `{}`", second_attributes.join(", ")),
+            second,
+        }));
+    }
+    Ok(())
+}
+
 pub(crate) fn named_type_already_used(
     info: &ModuleInfo,
     types: &[TypeAnnotation],
@@ -105,19 +246,98 @@ pub(crate) fn reference_unknown_type(
     Ok(())
 }
 
+pub(crate) fn reference_unknown_enum(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    enums: &[EnumAnnotation],
+) -> Result<(), Box<Error>> {
+    if enums.iter().all(|it| it.name != *name) {
+        return Err(Box::new(Error::UnknownEnum {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: name.span,
+        }));
+    }
+    Ok(())
+}
+
+/// An int-coded enum is mapped from `smallint` or `integer` columns, all of the same type
+pub(crate) fn int_enum_column_type(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    int_enum: &PreparedIntEnum,
+    ty: &Type,
+) -> Result<(), Box<Error>> {
+    let fits = match *ty {
+        Type::INT2 => int_enum
+            .variants
+            .iter()
+            .all(|(_, value)| i16::try_from(*value).is_ok()),
+        Type::INT4 => true,
+        _ => false,
+    };
+    if !fits || matches!(&int_enum.pg_ty, Some(mapped) if mapped != ty) {
+        return Err(Box::new(Error::IntEnumColumnType {
+            src: info.into(),
+            name: name.value.clone(),
+            ty: ty.to_string(),
+            pos: name.span,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn duplicate_enum_variant(
+    info: &ModuleInfo,
+    annotation: &EnumAnnotation,
+) -> Result<(), Box<Error>> {
+    if let Some((first, second)) = find_duplicate(&annotation.variants, |a, b| a.0 == b.0) {
+        return Err(Box::new(Error::DuplicateType {
+            src: info.into(),
+            ty: "variant",
+            name: first.0.value.clone(),
+            first: first.0.span,
+            second: second.0.span,
+        }));
+    }
+    if let Some((first, second)) = find_duplicate(&annotation.variants, |a, b| a.1 == b.1) {
+        return Err(Box::new(Error::DuplicateType {
+            src: info.into(),
+            ty: "discriminant",
+            name: first.1.to_string(),
+            first: first.0.span,
+            second: second.0.span,
+        }));
+    }
+    Ok(())
+}
+
 pub(crate) fn nullable_column_name(
     info: &ModuleInfo,
     nullable_col: &NullableIdent,
     stmt_cols: &[Column],
 ) -> Result<(), Box<Error>> {
-    // If none of the row's columns match the nullable column
-    if stmt_cols
-        .iter()
-        .all(|row_col| row_col.name() != nullable_col.name.value)
-    {
+    column_name(info, &nullable_col.name, stmt_cols)
+}
+
+pub(crate) fn mapped_column_name(
+    info: &ModuleInfo,
+    mapping: &ColumnMapping,
+    stmt_cols: &[Column],
+) -> Result<(), Box<Error>> {
+    column_name(info, &mapping.column, stmt_cols)
+}
+
+fn column_name(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    stmt_cols: &[Column],
+) -> Result<(), Box<Error>> {
+    // If none of the row's columns match the annotated column
+    if stmt_cols.iter().all(|row_col| row_col.name() != name.value) {
         return Err(Box::new(Error::UnknownFieldName {
             src: info.into(),
-            pos: nullable_col.name.span,
+            pos: name.span,
             known: stmt_cols
                 .iter()
                 .map(|it| it.name().to_string())
@@ -169,6 +389,26 @@ pub(crate) fn row_on_execute(
     Ok(())
 }
 
+pub(crate) fn row_on_forced_execute(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    execute: &SourceSpan,
+    row: &QueryDataStruct,
+    cache: &Option<Span<String>>,
+) -> Result<(), Box<Error>> {
+    let row = match cache {
+        _ if !row.is_empty() => row.span,
+        Some(key) => key.span,
+        None => return Ok(()),
+    };
+    Err(Box::new(Error::RowOnForcedExecute {
+        src: info.into(),
+        name: name.value.clone(),
+        row,
+        execute: *execute,
+    }))
+}
+
 pub(crate) fn param_on_simple_query(
     info: &ModuleInfo,
     name: &Span<String>,
@@ -266,6 +506,177 @@ pub(crate) fn named_struct_field(
     Ok(())
 }
 
+/// Splits an SQL query into identifiers, bind params, literals and equal signs
+fn sql_tokens(sql: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in sql.char_indices() {
+        let is_token = c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '"');
+        match (start, is_token) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                tokens.push(&sql[s..i]);
+                start = None;
+            }
+            _ => {}
+        }
+        // Skip comparisons like `<=`, `>=` and `!=`
+        if c == '=' && !sql[..i].ends_with(['<', '>', '!']) {
+            tokens.push("=");
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(&sql[s..]);
+    }
+    tokens
+}
+
+/// Best-effort list of the tables a query reads from
+pub(crate) fn referenced_tables(sql: &str) -> Vec<String> {
+    sql_tokens(sql)
+        .windows(2)
+        .filter(|it| it[0].eq_ignore_ascii_case("from") || it[0].eq_ignore_ascii_case("join"))
+        .map(|it| it[1].to_string())
+        .collect()
+}
+
+/// Checks that a cached query loads a whole table, indexed by one of its unique columns
+pub(crate) fn cache_key(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    key: &Span<String>,
+    sql: &str,
+    has_params: bool,
+    stmt_cols: &[Column],
+    unique_columns: &[String],
+) -> Result<(), Box<Error>> {
+    column_name(info, key, stmt_cols)?;
+    if has_params {
+        return Err(Box::new(Error::CacheWithParams {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: key.span,
+        }));
+    }
+    if referenced_tables(sql).len() != 1 {
+        return Err(Box::new(Error::CacheNotSingleTable {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: key.span,
+        }));
+    }
+    if !unique_columns.contains(&key.value) {
+        return Err(Box::new(Error::CacheKeyNotUnique {
+            src: info.into(),
+            key: key.value.clone(),
+            pos: key.span,
+        }));
+    }
+    Ok(())
+}
+
+/// Checks that the Rust type of a cache key column can key a `HashMap`
+pub(crate) fn cache_key_hash(
+    info: &ModuleInfo,
+    key: &Span<String>,
+    row_fields: &[PreparedField],
+) -> Result<(), Box<Error>> {
+    if let Some(field) = row_fields
+        .iter()
+        .find(|it| it.ident.db == key.value && !it.ty.is_hash())
+    {
+        return Err(Box::new(Error::CacheKeyNotHash {
+            src: info.into(),
+            key: key.value.clone(),
+            ty: field.ty.pg_name(),
+            pos: key.span,
+        }));
+    }
+    Ok(())
+}
+
+/// Lints a query marked with `:one`, which should not be able to return several rows. A query
+/// only limited to one row can still return none, `opt()` is then hinted
+pub(crate) fn single_row_query(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    sql: &str,
+    unique_columns: &[String],
+) -> Option<Warning> {
+    let tokens = sql_tokens(sql);
+    let is_one = |token: &str| token.parse::<i64>() == Ok(1);
+    // `LIMIT 1` or its standard spelling `FETCH FIRST 1 ROW ONLY`
+    let has_limit_one = tokens
+        .windows(2)
+        .any(|it| it[0].eq_ignore_ascii_case("limit") && is_one(it[1]))
+        || tokens.windows(3).any(|it| {
+            it[0].eq_ignore_ascii_case("fetch")
+                && (it[1].eq_ignore_ascii_case("first") || it[1].eq_ignore_ascii_case("next"))
+                && is_one(it[2])
+        });
+    let is_constant = |token: &str| token.starts_with('$') || token.parse::<i64>().is_ok();
+    let is_unique = |token: &str| {
+        let column = token.rsplit('.').next().unwrap_or(token);
+        let column = match column.strip_prefix('"').and_then(|it| it.strip_suffix('"')) {
+            Some(quoted) => quoted.to_string(),
+            None => column.to_lowercase(),
+        };
+        unique_columns.contains(&column)
+    };
+    let has_unique_filter = tokens
+        .iter()
+        .skip_while(|it| !it.eq_ignore_ascii_case("where"))
+        .copied()
+        .collect::<Vec<_>>()
+        .windows(3)
+        .any(|it| {
+            it[1] == "="
+                && ((is_unique(it[0]) && is_constant(it[2]))
+                    || (is_constant(it[0]) && is_unique(it[2])))
+        });
+
+    if has_unique_filter {
+        None
+    } else if has_limit_one {
+        Some(Warning::LimitOne {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: name.span,
+        })
+    } else {
+        Some(Warning::MultipleRows {
+            src: info.into(),
+            name: name.value.clone(),
+            pos: name.span,
+        })
+    }
+}
+
+/// Postgres reports the columns of a set operation like those of its first branch, a column
+/// can still be null if any other branch returns null
+pub(crate) fn set_operation_nullity(
+    info: &ModuleInfo,
+    name: &Span<String>,
+    sql: &str,
+    unannotated_columns: &[String],
+) -> Option<Warning> {
+    let is_set_operation = sql_tokens(sql).iter().any(|it| {
+        it.eq_ignore_ascii_case("union")
+            || it.eq_ignore_ascii_case("intersect")
+            || it.eq_ignore_ascii_case("except")
+    });
+    (is_set_operation && !unannotated_columns.is_empty()).then(|| Warning::SetOperationNullity {
+        src: info.into(),
+        name: name.value.clone(),
+        columns: unannotated_columns
+            .iter()
+            .map(|it| format!("`{it}`"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        pos: name.span,
+    })
+}
+
 pub(crate) fn validate_preparation(module: &PreparedModule) -> Result<(), Box<Error>> {
     // Check generated name clash
     let mut name_registrar = BTreeMap::new();
@@ -291,6 +702,10 @@ pub(crate) fn validate_preparation(module: &PreparedModule) -> Result<(), Box<Er
         }
     };
 
+    for int_enum in &module.enums {
+        reserved_type_keyword(&module.info, &int_enum.name)?;
+        check_name(int_enum.name.value.clone(), int_enum.name.span, "enum")?;
+    }
     for (origin, query) in &module.queries {
         reserved_type_keyword(&module.info, origin)?;
         check_name(
@@ -329,15 +744,38 @@ pub(crate) fn validate_module(
     Module {
         info,
         types,
+        enums,
+        views,
+        nullable_defaults,
         queries,
+        ..
     }: &Module,
 ) -> Result<(), Box<Error>> {
+    if let [first, second, ..] = nullable_defaults.as_slice() {
+        return Err(Box::new(Error::DuplicateNullableDefault {
+            src: info.into(),
+            first: first.span,
+            second: second.span,
+        }));
+    }
     query_name_already_used(info, queries)?;
+    view_name_already_used(info, views, queries)?;
     named_type_already_used(info, types)?;
     for ty in types {
         duplicate_nullable_ident(info, &ty.fields)?;
+        misplaced_param_type(info, &ty.fields)?;
+    }
+    for annotation in enums {
+        duplicate_enum_variant(info, annotation)?;
+    }
+    for view in views {
+        duplicate_nullable_ident(info, &view.fields)?;
+        misplaced_param_type(info, &view.fields)?;
     }
     for query in queries {
+        if let Some(idents) = &query.row.idents {
+            misplaced_param_type(info, idents)?;
+        }
         for (it, ty) in [(&query.param, "param"), (&query.row, "row")] {
             if let Some(idents) = &it.idents {
                 duplicate_nullable_ident(info, idents)?;
@@ -357,7 +795,7 @@ pub(crate) fn validate_module(
 pub mod error {
     use std::fmt::Debug;
 
-    use miette::{Diagnostic, NamedSource, SourceSpan};
+    use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, NamedSource, SourceSpan};
     use thiserror::Error as ThisError;
 
     #[derive(Debug, ThisError, Diagnostic)]
@@ -382,6 +820,26 @@ pub mod error {
             #[label("redeclared here")]
             second: SourceSpan,
         },
+        #[error("only query params can be given a type")]
+        #[diagnostic(help(
+            "annotate the param in the query annotation, e.g. `--! query (param::int4)`"
+        ))]
+        MisplacedParamType {
+            #[source_code]
+            src: NamedSource,
+            #[label("this field is not a query param")]
+            pos: SourceSpan,
+        },
+        #[error("the default nullability of the module is declared multiple time")]
+        #[diagnostic(help("remove one of the two declaration"))]
+        DuplicateNullableDefault {
+            #[source_code]
+            src: NamedSource,
+            #[label("previous declaration")]
+            first: SourceSpan,
+            #[label("redeclared here")]
+            second: SourceSpan,
+        },
         #[error("the {ty} `{name}` is defined multiple time")]
         #[diagnostic(help("use a different name for one of those"))]
         DuplicateType {
@@ -404,6 +862,38 @@ pub mod error {
             #[label("unknown named {ty}")]
             pos: SourceSpan,
         },
+        #[error("reference to an unknown enum `{name}`")]
+        #[diagnostic(help("declare it in this module using `--: enum {name}(Variant = 1)`"))]
+        UnknownEnum {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("unknown enum")]
+            pos: SourceSpan,
+        },
+        #[error("`{name}` is not a known view or materialized view")]
+        #[diagnostic(help(
+            "check that the view exists in the schema and has at least one column"
+        ))]
+        UnknownView {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("unknown view")]
+            pos: SourceSpan,
+        },
+        #[error("enum `{name}` cannot be mapped from a column of type `{ty}`")]
+        #[diagnostic(help(
+            "int-coded enums are mapped from `smallint` or `integer` columns of a single type that fits their discriminants"
+        ))]
+        IntEnumColumnType {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            ty: String,
+            #[label("mapped here")]
+            pos: SourceSpan,
+        },
         #[error("unknown field")]
         #[diagnostic(help("use one of those names: {known}"))]
         UnknownFieldName {
@@ -437,6 +927,68 @@ pub mod error {
             #[label("but query return nothing")]
             query: SourceSpan,
         },
+        #[error("the query `{name}` uses its rows but is annotated to be executed")]
+        #[diagnostic(help("remove the `--# execute` annotation to read its rows"))]
+        RowOnForcedExecute {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("rows used here")]
+            row: SourceSpan,
+            #[label("but they are discarded here")]
+            execute: SourceSpan,
+        },
+        #[error("the query `{name}` has no {target} struct to attach this attribute to")]
+        #[diagnostic(help("name the {target} to generate a struct for it"))]
+        AttributeWithoutStruct {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            target: &'static str,
+            #[label("attribute declared here")]
+            pos: SourceSpan,
+        },
+        #[error("the query `{name}` has no row struct to nest this group in")]
+        #[diagnostic(help("name the row to generate a struct for it"))]
+        GroupWithoutStruct {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("group declared here")]
+            pos: SourceSpan,
+        },
+        #[error("no column of the row starts with `{prefix}`")]
+        #[diagnostic(help("the row's columns are: {known}"))]
+        EmptyGroup {
+            #[source_code]
+            src: NamedSource,
+            prefix: String,
+            #[label("no column to group")]
+            pos: SourceSpan,
+            known: String,
+        },
+        #[error("the group `{name}` has the same name as a column of the row")]
+        #[diagnostic(help("rename the column or use another prefix"))]
+        GroupFieldConflict {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("group declared here")]
+            pos: SourceSpan,
+        },
+        #[error("the struct `{name}` is declared with conflicting attributes")]
+        #[diagnostic(help("declare the same attributes on every query using this struct"))]
+        ConflictingAttributes {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            first_label: String,
+            #[label("{first_label}")]
+            first: SourceSpan,
+            second_label: String,
+            #[label("{second_label}")]
+            second: SourceSpan,
+        },
         #[error("the query `{name}` declares a parameter but has no binding")]
         #[diagnostic(help("remove parameter declaration"))]
         ParamsOnSimpleQuery {
@@ -448,6 +1000,45 @@ pub mod error {
             #[label("but query has no binding")]
             query: SourceSpan,
         },
+        #[error("the cached query `{name}` has parameters")]
+        #[diagnostic(help("caches load every row at once, remove the query parameters"))]
+        CacheWithParams {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("cache declared here")]
+            pos: SourceSpan,
+        },
+        #[error("the cached query `{name}` does not read a single table")]
+        #[diagnostic(help("only cache queries selecting from one table"))]
+        CacheNotSingleTable {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("cache declared here")]
+            pos: SourceSpan,
+        },
+        #[error("the cache key `{key}` is not unique")]
+        #[diagnostic(help("index the cache by the primary key or a unique column of the table"))]
+        CacheKeyNotUnique {
+            #[source_code]
+            src: NamedSource,
+            key: String,
+            #[label("not a primary key or unique column")]
+            pos: SourceSpan,
+        },
+        #[error("the cache key `{key}` of type `{ty}` cannot be hashed")]
+        #[diagnostic(help(
+            "index the cache by a column whose Rust type implements `Hash` and `Eq`"
+        ))]
+        CacheKeyNotHash {
+            #[source_code]
+            src: NamedSource,
+            key: String,
+            ty: String,
+            #[label("not hashable")]
+            pos: SourceSpan,
+        },
         #[error("`{name}` is used multiple time")]
         #[diagnostic(help("use a different name for one of those"))]
         DuplicateName {
@@ -481,4 +1072,57 @@ pub mod error {
             pos: SourceSpan,
         },
     }
+
+    #[derive(Debug, ThisError, Diagnostic)]
+    pub enum Warning {
+        #[error("query `{name}` is expected to return a single row but may return several")]
+        #[diagnostic(
+            severity(Warning),
+            help("add a `LIMIT 1`, filter on a primary key or unique column, or use `opt()`")
+        )]
+        MultipleRows {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("query marked with `:one`")]
+            pos: SourceSpan,
+        },
+        #[error("query `{name}` is limited to one row but can still return none")]
+        #[diagnostic(
+            severity(Warning),
+            help("`opt()` is safer than `one()` unless the filter always matches a row")
+        )]
+        LimitOne {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            #[label("query marked with `:one`")]
+            pos: SourceSpan,
+        },
+        #[error("query `{name}` combines rows with a set operation, its columns {columns} are assumed not null")]
+        #[diagnostic(
+            severity(Warning),
+            help("a column is nullable if it is in any branch, mark it with `?` or with `!` if it is not")
+        )]
+        SetOperationNullity {
+            #[source_code]
+            src: NamedSource,
+            name: String,
+            columns: String,
+            #[label("query with a set operation")]
+            pos: SourceSpan,
+        },
+    }
+
+    impl Warning {
+        #[must_use]
+        pub fn report(&self) -> String {
+            let mut buff = String::new();
+            GraphicalReportHandler::new()
+                .with_theme(GraphicalTheme::unicode_nocolor())
+                .render_report(&mut buff, self)
+                .unwrap();
+            buff
+        }
+    }
 }
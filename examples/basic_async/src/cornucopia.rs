@@ -18,8 +18,10 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 buf: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 let s = match *self {
                     SpongeBobCharacter::Bob => "Bob",
                     SpongeBobCharacter::Patrick => "Patrick",
@@ -51,8 +53,10 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -60,12 +64,18 @@ pub mod types {
             fn from_sql(
                 ty: &postgres_types::Type,
                 buf: &'a [u8],
-            ) -> Result<SpongeBobCharacter, Box<dyn std::error::Error + Sync + Send>> {
+            ) -> std::result::Result<
+                SpongeBobCharacter,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 match std::str::from_utf8(buf)? {
-                    "Bob" => Ok(SpongeBobCharacter::Bob),
-                    "Patrick" => Ok(SpongeBobCharacter::Patrick),
-                    "Squidward" => Ok(SpongeBobCharacter::Squidward),
-                    s => Result::Err(Into::into(format!("invalid variant `{}`", s))),
+                    "Bob" => std::result::Result::Ok(SpongeBobCharacter::Bob),
+                    "Patrick" => std::result::Result::Ok(SpongeBobCharacter::Patrick),
+                    "Squidward" => std::result::Result::Ok(SpongeBobCharacter::Squidward),
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
+                        "invalid variant `{}`",
+                        s
+                    ))),
                 }
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
@@ -92,7 +102,7 @@ pub mod types {
         #[postgres(name = "voiceactor")]
         pub struct Voiceactor {
             #[postgres(name = "name")]
-            pub name: String,
+            pub name: std::string::String,
             #[postgres(name = "age")]
             pub age: i32,
         }
@@ -101,7 +111,8 @@ pub mod types {
             pub name: &'a str,
             pub age: i32,
         }
-        impl<'a> From<VoiceactorBorrowed<'a>> for Voiceactor {
+        impl<'a> std::convert::From<VoiceactorBorrowed<'a>> for Voiceactor {
+            #[inline]
             fn from(VoiceactorBorrowed { name, age }: VoiceactorBorrowed<'a>) -> Self {
                 Self {
                     name: name.into(),
@@ -113,26 +124,33 @@ pub mod types {
             fn from_sql(
                 ty: &postgres_types::Type,
                 out: &'a [u8],
-            ) -> Result<VoiceactorBorrowed<'a>, Box<dyn std::error::Error + Sync + Send>>
-            {
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                let mut out = out;
-                let num_fields = postgres_types::private::read_be_i32(&mut out)?;
-                if num_fields as usize != fields.len() {
-                    return std::result::Result::Err(std::convert::Into::into(format!(
-                        "invalid field count: {} vs {}",
-                        num_fields,
-                        fields.len()
-                    )));
-                }
-                let _oid = postgres_types::private::read_be_i32(&mut out)?;
-                let name = postgres_types::private::read_value(fields[0].type_(), &mut out)?;
-                let _oid = postgres_types::private::read_be_i32(&mut out)?;
-                let age = postgres_types::private::read_value(fields[1].type_(), &mut out)?;
-                Ok(VoiceactorBorrowed { name, age })
+            ) -> std::result::Result<
+                VoiceactorBorrowed<'a>,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let mut name = None;
+                let mut age = None;
+                cornucopia_async::private::read_composite(ty, out, |field, out| {
+                    match field.name() {
+                        "name" => {
+                            name = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        "age" => {
+                            age = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        _ => {
+                            return std::result::Result::Err(std::convert::Into::into(format!(
+                                "unexpected field: {}",
+                                field.name()
+                            )))
+                        }
+                    }
+                    std::result::Result::Ok(())
+                })?;
+                std::result::Result::Ok(VoiceactorBorrowed {
+                    name: name.ok_or("missing field: name")?,
+                    age: age.ok_or("missing field: age")?,
+                })
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
                 ty.name() == "voiceactor" && ty.schema() == "public"
@@ -143,61 +161,36 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 let VoiceactorBorrowed { name, age } = self;
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                out.extend_from_slice(&(fields.len() as i32).to_be_bytes());
-                for field in fields {
-                    out.extend_from_slice(&field.type_().oid().to_be_bytes());
-                    let base = out.len();
-                    out.extend_from_slice(&[0; 4]);
-                    let r = match field.name() {
+                cornucopia_async::private::write_composite(ty, out, |field, out| {
+                    match field.name() {
                         "name" => postgres_types::ToSql::to_sql(name, field.type_(), out),
                         "age" => postgres_types::ToSql::to_sql(age, field.type_(), out),
                         _ => unreachable!(),
-                    };
-                    let count = match r? {
-                        postgres_types::IsNull::Yes => -1,
-                        postgres_types::IsNull::No => {
-                            let len = out.len() - base - 4;
-                            if len > i32::max_value() as usize {
-                                return Err(Into::into("value too large to transmit"));
-                            }
-                            len as i32
-                        }
-                    };
-                    out[base..base + 4].copy_from_slice(&count.to_be_bytes());
-                }
-                Ok(postgres_types::IsNull::No)
+                    }
+                })
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
-                if ty.name() != "voiceactor" {
-                    return false;
-                }
-                match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => {
-                        if fields.len() != 2 {
-                            return false;
-                        }
-                        fields.iter().all(|f| match f.name() {
-                            "name" => <&'a str as postgres_types::ToSql>::accepts(f.type_()),
-                            "age" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
-                            _ => false,
-                        })
+                cornucopia_async::private::composite_accepts(ty, "voiceactor", 2, |f| {
+                    match f.name() {
+                        "name" => <&'a str as postgres_types::ToSql>::accepts(f.type_()),
+                        "age" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
+                        _ => false,
                     }
-                    _ => false,
-                }
+                })
             }
             fn to_sql_checked(
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -210,8 +203,6 @@ pub mod types {
 pub mod queries {
     pub mod module_1 {
         use cornucopia_async::GenericClient;
-        use futures;
-        use futures::{StreamExt, TryStreamExt};
         pub fn insert_book() -> InsertBookStmt {
             InsertBookStmt(cornucopia_async::private::Stmt::new(
                 "INSERT INTO Book (title)
@@ -220,6 +211,9 @@ pub mod queries {
         }
         pub struct InsertBookStmt(cornucopia_async::private::Stmt);
         impl InsertBookStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub async fn bind<'a, C: GenericClient, T1: cornucopia_async::StringSql>(
                 &'a mut self,
                 client: &'a C,
@@ -233,20 +227,28 @@ pub mod queries {
     pub mod module_2 {
         #[derive(Debug)]
         pub struct AuthorNameStartingWithParams<T1: cornucopia_async::StringSql> {
+            /// `text`
             pub start_str: T1,
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct Authors {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: String,
+            /// `varchar`
             pub country: String,
         }
         pub struct AuthorsBorrowed<'a> {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `varchar`
             pub country: &'a str,
         }
         impl<'a> From<AuthorsBorrowed<'a>> for Authors {
+            #[inline]
             fn from(AuthorsBorrowed { id, name, country }: AuthorsBorrowed<'a>) -> Self {
                 Self {
                     id,
@@ -257,18 +259,27 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct AuthorNameStartingWith {
+            /// `int4`
             pub authorid: i32,
+            /// `varchar`
             pub name: String,
+            /// `int4`
             pub bookid: i32,
+            /// `varchar`
             pub title: String,
         }
         pub struct AuthorNameStartingWithBorrowed<'a> {
+            /// `int4`
             pub authorid: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `int4`
             pub bookid: i32,
+            /// `varchar`
             pub title: &'a str,
         }
         impl<'a> From<AuthorNameStartingWithBorrowed<'a>> for AuthorNameStartingWith {
+            #[inline]
             fn from(
                 AuthorNameStartingWithBorrowed {
                     authorid,
@@ -287,14 +298,19 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct SelectTranslations {
+            /// `varchar`
             pub title: String,
+            /// `text[]`
             pub translations: Vec<String>,
         }
         pub struct SelectTranslationsBorrowed<'a> {
+            /// `varchar`
             pub title: &'a str,
+            /// `text[]`
             pub translations: cornucopia_async::ArrayIterator<'a, &'a str>,
         }
         impl<'a> From<SelectTranslationsBorrowed<'a>> for SelectTranslations {
+            #[inline]
             fn from(
                 SelectTranslationsBorrowed {
                     title,
@@ -308,7 +324,6 @@ pub mod queries {
             }
         }
         use cornucopia_async::GenericClient;
-        use futures;
         use futures::{StreamExt, TryStreamExt};
         pub struct AuthorsQuery<'a, C: GenericClient, T, const N: usize> {
             client: &'a C,
@@ -316,6 +331,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_async::private::Stmt,
             extractor: fn(&tokio_postgres::Row) -> AuthorsBorrowed,
             mapper: fn(AuthorsBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> AuthorsQuery<'a, C, T, N>
         where
@@ -328,6 +344,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -336,7 +353,20 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                self.iter().await?.try_collect().await
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
             }
             pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -346,10 +376,14 @@ pub mod queries {
                     .await?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
             pub async fn iter(
                 self,
             ) -> Result<
-                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                 tokio_postgres::Error,
             > {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -368,6 +402,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_async::private::Stmt,
             extractor: fn(&tokio_postgres::Row) -> &str,
             mapper: fn(&str) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> StringQuery<'a, C, T, N>
         where
@@ -380,6 +415,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -388,7 +424,20 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                self.iter().await?.try_collect().await
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
             }
             pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -398,10 +447,14 @@ pub mod queries {
                     .await?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
             pub async fn iter(
                 self,
             ) -> Result<
-                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                 tokio_postgres::Error,
             > {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -420,6 +473,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_async::private::Stmt,
             extractor: fn(&tokio_postgres::Row) -> AuthorNameStartingWithBorrowed,
             mapper: fn(AuthorNameStartingWithBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> AuthorNameStartingWithQuery<'a, C, T, N>
         where
@@ -435,6 +489,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -443,7 +498,20 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                self.iter().await?.try_collect().await
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
             }
             pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -453,10 +521,14 @@ pub mod queries {
                     .await?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
             pub async fn iter(
                 self,
             ) -> Result<
-                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                 tokio_postgres::Error,
             > {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -475,6 +547,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_async::private::Stmt,
             extractor: fn(&tokio_postgres::Row) -> super::super::types::public::VoiceactorBorrowed,
             mapper: fn(super::super::types::public::VoiceactorBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> PublicVoiceactorQuery<'a, C, T, N>
         where
@@ -490,6 +563,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -498,7 +572,20 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                self.iter().await?.try_collect().await
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
             }
             pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -508,10 +595,14 @@ pub mod queries {
                     .await?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
             pub async fn iter(
                 self,
             ) -> Result<
-                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                 tokio_postgres::Error,
             > {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -530,6 +621,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_async::private::Stmt,
             extractor: fn(&tokio_postgres::Row) -> SelectTranslationsBorrowed,
             mapper: fn(SelectTranslationsBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> SelectTranslationsQuery<'a, C, T, N>
         where
@@ -545,6 +637,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub async fn one(self) -> Result<T, tokio_postgres::Error> {
@@ -553,7 +646,20 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-                self.iter().await?.try_collect().await
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity).await
+            }
+            pub async fn vec_with_capacity(
+                self,
+                capacity: usize,
+            ) -> Result<Vec<T>, tokio_postgres::Error> {
+                self.iter()
+                    .await?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it);
+                        futures::future::ready(Ok(vec))
+                    })
+                    .await
             }
             pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -563,10 +669,14 @@ pub mod queries {
                     .await?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub async fn execute(self) -> Result<u64, tokio_postgres::Error> {
+                let stmt = self.stmt.prepare(self.client).await?;
+                self.client.execute(stmt, &self.params).await
+            }
             pub async fn iter(
                 self,
             ) -> Result<
-                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'a,
+                impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + Send + 'a,
                 tokio_postgres::Error,
             > {
                 let stmt = self.stmt.prepare(self.client).await?;
@@ -589,6 +699,9 @@ FROM
         }
         pub struct AuthorsStmt(cornucopia_async::private::Stmt);
         impl AuthorsStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a C,
@@ -603,6 +716,7 @@ FROM
                         country: row.get(2),
                     },
                     mapper: |it| <Authors>::from(it),
+                    capacity: 0,
                 }
             }
         }
@@ -616,6 +730,9 @@ FROM
         }
         pub struct BooksStmt(cornucopia_async::private::Stmt);
         impl BooksStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a C,
@@ -626,6 +743,7 @@ FROM
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -641,6 +759,9 @@ WHERE
         }
         pub struct AuthorNameByIdStmt(cornucopia_async::private::Stmt);
         impl AuthorNameByIdStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a C,
@@ -652,6 +773,7 @@ WHERE
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -672,6 +794,9 @@ WHERE
         }
         pub struct AuthorNameStartingWithStmt(cornucopia_async::private::Stmt);
         impl AuthorNameStartingWithStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient, T1: cornucopia_async::StringSql>(
                 &'a mut self,
                 client: &'a C,
@@ -688,6 +813,7 @@ WHERE
                         title: row.get(3),
                     },
                     mapper: |it| <AuthorNameStartingWith>::from(it),
+                    capacity: 0,
                 }
             }
         }
@@ -719,6 +845,9 @@ WHERE
         }
         pub struct SelectVoiceActorWithCharacterStmt(cornucopia_async::private::Stmt);
         impl SelectVoiceActorWithCharacterStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a C,
@@ -731,6 +860,7 @@ WHERE
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -745,6 +875,9 @@ FROM
         }
         pub struct SelectTranslationsStmt(cornucopia_async::private::Stmt);
         impl SelectTranslationsStmt {
+            pub fn statement(&self) -> Option<&tokio_postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a C,
@@ -758,6 +891,7 @@ FROM
                         translations: row.get(1),
                     },
                     mapper: |it| <SelectTranslations>::from(it),
+                    capacity: 0,
                 }
             }
         }
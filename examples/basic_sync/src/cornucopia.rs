@@ -18,8 +18,10 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 buf: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 let s = match *self {
                     SpongeBobCharacter::Bob => "Bob",
                     SpongeBobCharacter::Patrick => "Patrick",
@@ -51,8 +53,10 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -60,12 +64,18 @@ pub mod types {
             fn from_sql(
                 ty: &postgres_types::Type,
                 buf: &'a [u8],
-            ) -> Result<SpongeBobCharacter, Box<dyn std::error::Error + Sync + Send>> {
+            ) -> std::result::Result<
+                SpongeBobCharacter,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 match std::str::from_utf8(buf)? {
-                    "Bob" => Ok(SpongeBobCharacter::Bob),
-                    "Patrick" => Ok(SpongeBobCharacter::Patrick),
-                    "Squidward" => Ok(SpongeBobCharacter::Squidward),
-                    s => Result::Err(Into::into(format!("invalid variant `{}`", s))),
+                    "Bob" => std::result::Result::Ok(SpongeBobCharacter::Bob),
+                    "Patrick" => std::result::Result::Ok(SpongeBobCharacter::Patrick),
+                    "Squidward" => std::result::Result::Ok(SpongeBobCharacter::Squidward),
+                    s => std::result::Result::Err(std::convert::Into::into(format!(
+                        "invalid variant `{}`",
+                        s
+                    ))),
                 }
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
@@ -92,7 +102,7 @@ pub mod types {
         #[postgres(name = "voiceactor")]
         pub struct Voiceactor {
             #[postgres(name = "name")]
-            pub name: String,
+            pub name: std::string::String,
             #[postgres(name = "age")]
             pub age: i32,
         }
@@ -101,7 +111,8 @@ pub mod types {
             pub name: &'a str,
             pub age: i32,
         }
-        impl<'a> From<VoiceactorBorrowed<'a>> for Voiceactor {
+        impl<'a> std::convert::From<VoiceactorBorrowed<'a>> for Voiceactor {
+            #[inline]
             fn from(VoiceactorBorrowed { name, age }: VoiceactorBorrowed<'a>) -> Self {
                 Self {
                     name: name.into(),
@@ -113,26 +124,33 @@ pub mod types {
             fn from_sql(
                 ty: &postgres_types::Type,
                 out: &'a [u8],
-            ) -> Result<VoiceactorBorrowed<'a>, Box<dyn std::error::Error + Sync + Send>>
-            {
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                let mut out = out;
-                let num_fields = postgres_types::private::read_be_i32(&mut out)?;
-                if num_fields as usize != fields.len() {
-                    return std::result::Result::Err(std::convert::Into::into(format!(
-                        "invalid field count: {} vs {}",
-                        num_fields,
-                        fields.len()
-                    )));
-                }
-                let _oid = postgres_types::private::read_be_i32(&mut out)?;
-                let name = postgres_types::private::read_value(fields[0].type_(), &mut out)?;
-                let _oid = postgres_types::private::read_be_i32(&mut out)?;
-                let age = postgres_types::private::read_value(fields[1].type_(), &mut out)?;
-                Ok(VoiceactorBorrowed { name, age })
+            ) -> std::result::Result<
+                VoiceactorBorrowed<'a>,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
+                let mut name = None;
+                let mut age = None;
+                cornucopia_sync::private::read_composite(ty, out, |field, out| {
+                    match field.name() {
+                        "name" => {
+                            name = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        "age" => {
+                            age = Some(postgres_types::private::read_value(field.type_(), out)?)
+                        }
+                        _ => {
+                            return std::result::Result::Err(std::convert::Into::into(format!(
+                                "unexpected field: {}",
+                                field.name()
+                            )))
+                        }
+                    }
+                    std::result::Result::Ok(())
+                })?;
+                std::result::Result::Ok(VoiceactorBorrowed {
+                    name: name.ok_or("missing field: name")?,
+                    age: age.ok_or("missing field: age")?,
+                })
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
                 ty.name() == "voiceactor" && ty.schema() == "public"
@@ -143,61 +161,36 @@ pub mod types {
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 let VoiceactorBorrowed { name, age } = self;
-                let fields = match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => fields,
-                    _ => unreachable!(),
-                };
-                out.extend_from_slice(&(fields.len() as i32).to_be_bytes());
-                for field in fields {
-                    out.extend_from_slice(&field.type_().oid().to_be_bytes());
-                    let base = out.len();
-                    out.extend_from_slice(&[0; 4]);
-                    let r = match field.name() {
+                cornucopia_sync::private::write_composite(ty, out, |field, out| {
+                    match field.name() {
                         "name" => postgres_types::ToSql::to_sql(name, field.type_(), out),
                         "age" => postgres_types::ToSql::to_sql(age, field.type_(), out),
                         _ => unreachable!(),
-                    };
-                    let count = match r? {
-                        postgres_types::IsNull::Yes => -1,
-                        postgres_types::IsNull::No => {
-                            let len = out.len() - base - 4;
-                            if len > i32::max_value() as usize {
-                                return Err(Into::into("value too large to transmit"));
-                            }
-                            len as i32
-                        }
-                    };
-                    out[base..base + 4].copy_from_slice(&count.to_be_bytes());
-                }
-                Ok(postgres_types::IsNull::No)
+                    }
+                })
             }
             fn accepts(ty: &postgres_types::Type) -> bool {
-                if ty.name() != "voiceactor" {
-                    return false;
-                }
-                match *ty.kind() {
-                    postgres_types::Kind::Composite(ref fields) => {
-                        if fields.len() != 2 {
-                            return false;
-                        }
-                        fields.iter().all(|f| match f.name() {
-                            "name" => <&'a str as postgres_types::ToSql>::accepts(f.type_()),
-                            "age" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
-                            _ => false,
-                        })
+                cornucopia_sync::private::composite_accepts(ty, "voiceactor", 2, |f| {
+                    match f.name() {
+                        "name" => <&'a str as postgres_types::ToSql>::accepts(f.type_()),
+                        "age" => <i32 as postgres_types::ToSql>::accepts(f.type_()),
+                        _ => false,
                     }
-                    _ => false,
-                }
+                })
             }
             fn to_sql_checked(
                 &self,
                 ty: &postgres_types::Type,
                 out: &mut postgres_types::private::BytesMut,
-            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>>
-            {
+            ) -> std::result::Result<
+                postgres_types::IsNull,
+                std::boxed::Box<dyn std::error::Error + std::marker::Sync + std::marker::Send>,
+            > {
                 postgres_types::__to_sql_checked(self, ty, out)
             }
         }
@@ -209,7 +202,7 @@ pub mod types {
 #[allow(dead_code)]
 pub mod queries {
     pub mod module_1 {
-        use postgres::{fallible_iterator::FallibleIterator, GenericClient};
+        use postgres::GenericClient;
         pub fn insert_book() -> InsertBookStmt {
             InsertBookStmt(cornucopia_sync::private::Stmt::new(
                 "INSERT INTO Book (title)
@@ -218,6 +211,9 @@ pub mod queries {
         }
         pub struct InsertBookStmt(cornucopia_sync::private::Stmt);
         impl InsertBookStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
                 &'a mut self,
                 client: &'a mut C,
@@ -231,20 +227,28 @@ pub mod queries {
     pub mod module_2 {
         #[derive(Debug)]
         pub struct AuthorNameStartingWithParams<T1: cornucopia_sync::StringSql> {
+            /// `text`
             pub start_str: T1,
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct Authors {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: String,
+            /// `varchar`
             pub country: String,
         }
         pub struct AuthorsBorrowed<'a> {
+            /// `int4`
             pub id: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `varchar`
             pub country: &'a str,
         }
         impl<'a> From<AuthorsBorrowed<'a>> for Authors {
+            #[inline]
             fn from(AuthorsBorrowed { id, name, country }: AuthorsBorrowed<'a>) -> Self {
                 Self {
                     id,
@@ -255,18 +259,27 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct AuthorNameStartingWith {
+            /// `int4`
             pub authorid: i32,
+            /// `varchar`
             pub name: String,
+            /// `int4`
             pub bookid: i32,
+            /// `varchar`
             pub title: String,
         }
         pub struct AuthorNameStartingWithBorrowed<'a> {
+            /// `int4`
             pub authorid: i32,
+            /// `varchar`
             pub name: &'a str,
+            /// `int4`
             pub bookid: i32,
+            /// `varchar`
             pub title: &'a str,
         }
         impl<'a> From<AuthorNameStartingWithBorrowed<'a>> for AuthorNameStartingWith {
+            #[inline]
             fn from(
                 AuthorNameStartingWithBorrowed {
                     authorid,
@@ -285,14 +298,19 @@ pub mod queries {
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct SelectTranslations {
+            /// `varchar`
             pub title: String,
+            /// `text[]`
             pub translations: Vec<String>,
         }
         pub struct SelectTranslationsBorrowed<'a> {
+            /// `varchar`
             pub title: &'a str,
+            /// `text[]`
             pub translations: cornucopia_sync::ArrayIterator<'a, &'a str>,
         }
         impl<'a> From<SelectTranslationsBorrowed<'a>> for SelectTranslations {
+            #[inline]
             fn from(
                 SelectTranslationsBorrowed {
                     title,
@@ -312,6 +330,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_sync::private::Stmt,
             extractor: fn(&postgres::Row) -> AuthorsBorrowed,
             mapper: fn(AuthorsBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> AuthorsQuery<'a, C, T, N>
         where
@@ -324,6 +343,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub fn one(self) -> Result<T, postgres::Error> {
@@ -332,7 +352,15 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                self.iter()?.collect()
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
             }
             pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                 let stmt = self.stmt.prepare(self.client)?;
@@ -341,6 +369,10 @@ pub mod queries {
                     .query_opt(stmt, &self.params)?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
             pub fn iter(
                 self,
             ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -360,6 +392,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_sync::private::Stmt,
             extractor: fn(&postgres::Row) -> &str,
             mapper: fn(&str) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> StringQuery<'a, C, T, N>
         where
@@ -372,6 +405,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub fn one(self) -> Result<T, postgres::Error> {
@@ -380,7 +414,15 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                self.iter()?.collect()
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
             }
             pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                 let stmt = self.stmt.prepare(self.client)?;
@@ -389,6 +431,10 @@ pub mod queries {
                     .query_opt(stmt, &self.params)?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
             pub fn iter(
                 self,
             ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -408,6 +454,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_sync::private::Stmt,
             extractor: fn(&postgres::Row) -> AuthorNameStartingWithBorrowed,
             mapper: fn(AuthorNameStartingWithBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> AuthorNameStartingWithQuery<'a, C, T, N>
         where
@@ -423,6 +470,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub fn one(self) -> Result<T, postgres::Error> {
@@ -431,7 +479,15 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                self.iter()?.collect()
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
             }
             pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                 let stmt = self.stmt.prepare(self.client)?;
@@ -440,6 +496,10 @@ pub mod queries {
                     .query_opt(stmt, &self.params)?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
             pub fn iter(
                 self,
             ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -459,6 +519,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_sync::private::Stmt,
             extractor: fn(&postgres::Row) -> super::super::types::public::VoiceactorBorrowed,
             mapper: fn(super::super::types::public::VoiceactorBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> PublicVoiceactorQuery<'a, C, T, N>
         where
@@ -474,6 +535,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub fn one(self) -> Result<T, postgres::Error> {
@@ -482,7 +544,15 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                self.iter()?.collect()
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
             }
             pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                 let stmt = self.stmt.prepare(self.client)?;
@@ -491,6 +561,10 @@ pub mod queries {
                     .query_opt(stmt, &self.params)?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
             pub fn iter(
                 self,
             ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -510,6 +584,7 @@ pub mod queries {
             stmt: &'a mut cornucopia_sync::private::Stmt,
             extractor: fn(&postgres::Row) -> SelectTranslationsBorrowed,
             mapper: fn(SelectTranslationsBorrowed) -> T,
+            capacity: usize,
         }
         impl<'a, C, T: 'a, const N: usize> SelectTranslationsQuery<'a, C, T, N>
         where
@@ -525,6 +600,7 @@ pub mod queries {
                     stmt: self.stmt,
                     extractor: self.extractor,
                     mapper,
+                    capacity: self.capacity,
                 }
             }
             pub fn one(self) -> Result<T, postgres::Error> {
@@ -533,7 +609,15 @@ pub mod queries {
                 Ok((self.mapper)((self.extractor)(&row)))
             }
             pub fn all(self) -> Result<Vec<T>, postgres::Error> {
-                self.iter()?.collect()
+                let capacity = self.capacity;
+                self.vec_with_capacity(capacity)
+            }
+            pub fn vec_with_capacity(self, capacity: usize) -> Result<Vec<T>, postgres::Error> {
+                self.iter()?
+                    .try_fold(Vec::with_capacity(capacity), |mut vec, it| {
+                        vec.push(it?);
+                        Ok(vec)
+                    })
             }
             pub fn opt(self) -> Result<Option<T>, postgres::Error> {
                 let stmt = self.stmt.prepare(self.client)?;
@@ -542,6 +626,10 @@ pub mod queries {
                     .query_opt(stmt, &self.params)?
                     .map(|row| (self.mapper)((self.extractor)(&row))))
             }
+            pub fn execute(self) -> Result<u64, postgres::Error> {
+                let stmt = self.stmt.prepare(self.client)?;
+                self.client.execute(stmt, &self.params)
+            }
             pub fn iter(
                 self,
             ) -> Result<impl Iterator<Item = Result<T, postgres::Error>> + 'a, postgres::Error>
@@ -565,6 +653,9 @@ FROM
         }
         pub struct AuthorsStmt(cornucopia_sync::private::Stmt);
         impl AuthorsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a mut C,
@@ -579,6 +670,7 @@ FROM
                         country: row.get(2),
                     },
                     mapper: |it| <Authors>::from(it),
+                    capacity: 0,
                 }
             }
         }
@@ -592,6 +684,9 @@ FROM
         }
         pub struct BooksStmt(cornucopia_sync::private::Stmt);
         impl BooksStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a mut C,
@@ -602,6 +697,7 @@ FROM
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -617,6 +713,9 @@ WHERE
         }
         pub struct AuthorNameByIdStmt(cornucopia_sync::private::Stmt);
         impl AuthorNameByIdStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a mut C,
@@ -628,6 +727,7 @@ WHERE
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -648,6 +748,9 @@ WHERE
         }
         pub struct AuthorNameStartingWithStmt(cornucopia_sync::private::Stmt);
         impl AuthorNameStartingWithStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient, T1: cornucopia_sync::StringSql>(
                 &'a mut self,
                 client: &'a mut C,
@@ -664,6 +767,7 @@ WHERE
                         title: row.get(3),
                     },
                     mapper: |it| <AuthorNameStartingWith>::from(it),
+                    capacity: 0,
                 }
             }
         }
@@ -695,6 +799,9 @@ WHERE
         }
         pub struct SelectVoiceActorWithCharacterStmt(cornucopia_sync::private::Stmt);
         impl SelectVoiceActorWithCharacterStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a mut C,
@@ -707,6 +814,7 @@ WHERE
                     stmt: &mut self.0,
                     extractor: |row| row.get(0),
                     mapper: |it| it.into(),
+                    capacity: 0,
                 }
             }
         }
@@ -721,6 +829,9 @@ FROM
         }
         pub struct SelectTranslationsStmt(cornucopia_sync::private::Stmt);
         impl SelectTranslationsStmt {
+            pub fn statement(&self) -> Option<&postgres::Statement> {
+                self.0.statement()
+            }
             pub fn bind<'a, C: GenericClient>(
                 &'a mut self,
                 client: &'a mut C,
@@ -734,6 +845,7 @@ FROM
                         translations: row.get(1),
                     },
                     mapper: |it| <SelectTranslations>::from(it),
+                    capacity: 0,
                 }
             }
         }
@@ -17,10 +17,13 @@ use cornucopia_sync::Params;
 pub fn main() {
     // You can learn which database connection types are compatible with Cornucopia in the book
     // https://cornucopia-rs.netlify.app/book/using_queries/db_connections.html
-    let mut client = get_client().unwrap();
+    let pool = create_pool().unwrap();
+    // Pooled connections deref to a `postgres::Client`, which the generated queries accept
+    let mut conn = pool.get().unwrap();
+    let client: &mut postgres::Client = &mut conn;
 
     // The `all` method returns queried rows collected into a `Vec`
-    let authors = authors().bind(&mut client).all().unwrap();
+    let authors = authors().bind(client).all().unwrap();
     dbg!(authors);
 
     // Queries also accept transactions. Let's see how that works.
@@ -58,7 +61,7 @@ pub fn main() {
 
     // Using `opt` returns an optional row (zero or one).
     // Any other number of rows will return an error.
-    let author_name = author_name_by_id().bind(&mut client, &0).opt().unwrap();
+    let author_name = author_name_by_id().bind(client, &0).opt().unwrap();
     dbg!(author_name);
 
     // Using named structs as parameters and rows can be more convenient
@@ -71,10 +74,7 @@ pub fn main() {
     // !    parameter type was created).
     // ! 2. Import the `Params` trait.
     let name_starting_with_jo = author_name_starting_with()
-        .params(
-            &mut client,
-            &AuthorNameStartingWithParams { start_str: "Jo" },
-        )
+        .params(client, &AuthorNameStartingWithParams { start_str: "Jo" })
         .all()
         .unwrap();
     dbg!(name_starting_with_jo);
@@ -85,7 +85,7 @@ pub fn main() {
     // You can use them as bind parameters (as shown here)
     // or receive them in returned rows.
     let patrick_voice_actor = select_voice_actor_with_character()
-        .bind(&mut client, &SpongeBobCharacter::Patrick)
+        .bind(client, &SpongeBobCharacter::Patrick)
         .one()
         .unwrap();
     dbg!(patrick_voice_actor);
@@ -93,24 +93,25 @@ pub fn main() {
     // Cornucopia also supports PostgreSQL arrays, which you
     // can use as bind parameters or in returned rows.
     let translations = select_translations()
-        .bind(&mut client)
+        .bind(client)
         .map(|row| format!("{}: {:?}", row.title, row.translations))
         .all()
         .unwrap();
     dbg!(translations);
 }
 
-/// Connection client configuration.
+/// Connection pool configuration.
 ///
 /// This is just a simple example config, please look at
-/// `postgres` for details.
+/// `postgres` and `r2d2_postgres` for details.
 use postgres::{Config, NoTls};
-fn get_client() -> Result<postgres::Client, postgres::Error> {
-    Config::new()
-        .user("postgres")
+use r2d2_postgres::{r2d2, PostgresConnectionManager};
+fn create_pool() -> Result<r2d2::Pool<PostgresConnectionManager<NoTls>>, r2d2::Error> {
+    let mut cfg = Config::new();
+    cfg.user("postgres")
         .password("postgres")
         .host("127.0.0.1")
         .port(5435)
-        .dbname("postgres")
-        .connect(NoTls)
+        .dbname("postgres");
+    r2d2::Pool::new(PostgresConnectionManager::new(cfg, NoTls))
 }
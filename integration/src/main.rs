@@ -1,12 +1,12 @@
 use std::{
     borrow::Cow,
     fmt::Display,
-    io::Write,
-    process::{Command, ExitCode, Stdio},
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
 };
 
 use clap::Parser;
-use cornucopia::{container, CodegenSettings, Error};
+use cornucopia::{container, Backend, CodegenSettings, Error, TimeBackend};
 use owo_colors::OwoColorize;
 
 /// Start cornucopia test runner
@@ -33,6 +33,9 @@ struct ErrorTest<'a> {
     query: Option<&'a str>,
     schema: Option<&'a str>,
     query_name: Option<&'a str>,
+    extra_query: Option<&'a str>,
+    /// Report every error using `check_live` instead of the first one
+    check: Option<bool>,
     error: Cow<'a, str>,
 }
 
@@ -47,10 +50,45 @@ struct CodegenTest<'a> {
     name: &'a str,
     base_path: &'a str,
     queries: Option<&'a str>,
+    /// Defaults to `schema.sql`
+    schema: Option<&'a str>,
     destination: Option<&'a str>,
     sync: Option<bool>,
     r#async: Option<bool>,
     derive_ser: Option<bool>,
+    derive_sqlx: Option<bool>,
+    derive_hash: Option<bool>,
+    cow: Option<bool>,
+    foreign_keys: Option<bool>,
+    prelude: Option<bool>,
+    query_errors: Option<bool>,
+    numeric_as_f64: Option<bool>,
+    ordered_floats: Option<bool>,
+    enum_ord: Option<bool>,
+    column_enums: Option<bool>,
+    yesql: Option<bool>,
+    dyn_params: Option<bool>,
+    qualified_paths: Option<bool>,
+    statements_struct: Option<bool>,
+    named_streams: Option<bool>,
+    columns_by_name: Option<bool>,
+    text_as_bytes: Option<bool>,
+    infinite_timestamps: Option<bool>,
+    bigint_as_string: Option<bool>,
+    /// One of `chrono`, `time` or `none`, defaults to `time`
+    time_backend: Option<&'a str>,
+    serde_feature: Option<&'a str>,
+    search_path: Option<&'a str>,
+    filter: Option<&'a str>,
+    /// Generate one file per module in the `destination` directory
+    split: Option<bool>,
+    /// Pairs of a backend name and a destination, generated from a single preparation
+    #[serde(borrow)]
+    backends: Option<Vec<(&'a str, &'a str)>>,
+    /// Also generate the code from an offline cache, which must give the same output
+    offline: Option<bool>,
+    /// Also generate the code from the schema files alone, which must give the same output
+    schema_only: Option<bool>,
     run: Option<Run>,
 }
 
@@ -97,11 +135,6 @@ fn test(
     successful.unwrap()
 }
 
-/// Reset the current database
-fn reset_db(client: &mut postgres::Client) -> Result<(), postgres::Error> {
-    client.batch_execute("DROP SCHEMA public CASCADE;CREATE SCHEMA public;")
-}
-
 // Common schema to all error tests
 const SCHEMA_BASE: &str = "CREATE TABLE author (id SERIAL, name TEXT);\n";
 
@@ -136,7 +169,7 @@ fn run_errors_test(
             let temp_dir = tempfile::tempdir()?;
 
             // Reset db
-            reset_db(client)?;
+            cornucopia::reset_database(client, "postgres")?;
 
             // We need to change current dir for error path to always be the same
             std::env::set_current_dir(&temp_dir)?;
@@ -151,24 +184,58 @@ fn run_errors_test(
             std::fs::create_dir("queries")?;
             let name = test.query_name.unwrap_or("test.sql");
             std::fs::write(format!("queries/{name}"), test.query.unwrap_or_default())?;
+            let mut queries_paths = vec!["queries"];
+            if let Some(extra_query) = test.extra_query {
+                // Same file name in a second directory merged into the same output
+                std::fs::create_dir("extra_queries")?;
+                std::fs::write(format!("extra_queries/{name}"), extra_query)?;
+                queries_paths.push("extra_queries");
+            }
 
             // Run codegen
-            let result: Result<(), cornucopia::Error> = (|| {
-                cornucopia::load_schema(client, &["schema.sql"])?;
-                cornucopia::generate_live(
-                    client,
-                    "queries",
-                    None,
-                    CodegenSettings {
-                        gen_sync: true,
-                        gen_async: false,
-                        derive_ser: false,
-                    },
-                )?;
-                Ok(())
-            })();
-
-            let err = result.err().map(Error::report).unwrap_or_default();
+            let settings = CodegenSettings {
+                gen_sync: true,
+                gen_async: false,
+                derive_ser: false,
+                derive_sqlx: false,
+                derive_hash: false,
+                gen_cow: false,
+                gen_foreign_keys: false,
+                gen_prelude: false,
+                gen_query_errors: false,
+                numeric_as_f64: false,
+                ordered_floats: false,
+                enum_ord: false,
+                column_enums: false,
+                yesql: false,
+                dyn_params: false,
+                qualified_paths: false,
+                statements_struct: false,
+                named_streams: false,
+                columns_by_name: false,
+                text_as_bytes: false,
+                infinite_timestamps: false,
+                bigint_as_string: false,
+                time_backend: TimeBackend::Time,
+                serde_feature: None,
+                statement_timeout: None,
+            };
+            let err = match cornucopia::load_schema(client, &["schema.sql"]) {
+                Err(e) => Error::from(e).report(),
+                Ok(()) if test.check.unwrap_or(false) => {
+                    cornucopia::check_live(client, &queries_paths, settings)
+                        .err()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(Error::report)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                }
+                Ok(()) => cornucopia::generate_live_multi(client, &queries_paths, None, settings)
+                    .err()
+                    .map(Error::report)
+                    .unwrap_or_default(),
+            };
             if err.trim() == test.error.trim() {
                 println!("{} {}", test.name, "OK".green());
             } else {
@@ -217,60 +284,179 @@ fn run_codegen_test(
         for codegen_test in suite.codegen {
             std::env::set_current_dir(format!("../{}", codegen_test.base_path))?;
             let queries_path = codegen_test.queries.unwrap_or("queries");
-            let schema_path = "schema.sql";
+            let schema_path = codegen_test.schema.unwrap_or("schema.sql");
             let destination = codegen_test.destination.unwrap_or("src/cornucopia.rs");
             let gen_sync = codegen_test.sync.unwrap_or(false);
             let gen_async = codegen_test.r#async.unwrap_or(false);
             let derive_ser = codegen_test.derive_ser.unwrap_or(false);
+            let derive_sqlx = codegen_test.derive_sqlx.unwrap_or(false);
+            let derive_hash = codegen_test.derive_hash.unwrap_or(false);
+            let gen_cow = codegen_test.cow.unwrap_or(false);
+            let gen_foreign_keys = codegen_test.foreign_keys.unwrap_or(false);
+            let gen_prelude = codegen_test.prelude.unwrap_or(false);
+            let gen_query_errors = codegen_test.query_errors.unwrap_or(false);
+            let numeric_as_f64 = codegen_test.numeric_as_f64.unwrap_or(false);
+            let ordered_floats = codegen_test.ordered_floats.unwrap_or(false);
+            let enum_ord = codegen_test.enum_ord.unwrap_or(false);
+            let column_enums = codegen_test.column_enums.unwrap_or(false);
+            let yesql = codegen_test.yesql.unwrap_or(false);
+            let dyn_params = codegen_test.dyn_params.unwrap_or(false);
+            let qualified_paths = codegen_test.qualified_paths.unwrap_or(false);
+            let statements_struct = codegen_test.statements_struct.unwrap_or(false);
+            let named_streams = codegen_test.named_streams.unwrap_or(false);
+            let columns_by_name = codegen_test.columns_by_name.unwrap_or(false);
+            let text_as_bytes = codegen_test.text_as_bytes.unwrap_or(false);
+            let infinite_timestamps = codegen_test.infinite_timestamps.unwrap_or(false);
+            let bigint_as_string = codegen_test.bigint_as_string.unwrap_or(false);
+            let time_backend = codegen_test.time_backend.unwrap_or("time").parse()?;
+            let serde_feature = codegen_test
+                .serde_feature
+                .map(|it| &*Box::leak(it.to_owned().into_boxed_str()));
             let settings = CodegenSettings {
                 gen_async,
                 gen_sync,
                 derive_ser,
+                derive_sqlx,
+                derive_hash,
+                gen_cow,
+                gen_foreign_keys,
+                gen_prelude,
+                gen_query_errors,
+                numeric_as_f64,
+                ordered_floats,
+                enum_ord,
+                column_enums,
+                yesql,
+                dyn_params,
+                qualified_paths,
+                statements_struct,
+                named_streams,
+                columns_by_name,
+                text_as_bytes,
+                infinite_timestamps,
+                bigint_as_string,
+                time_backend,
+                serde_feature,
+                statement_timeout: None,
             };
 
             // Load schema
-            reset_db(client)?;
+            cornucopia::reset_database(client, "postgres")?;
             cornucopia::load_schema(client, &[schema_path])?;
+            if let Some(search_path) = codegen_test.search_path {
+                cornucopia::conn::set_search_path(client, search_path)?;
+            }
 
-            // If `--apply`, then the code will be regenerated.
-            // Otherwise, it is only checked.
-            if apply {
-                // Generate
-                cornucopia::generate_live(client, queries_path, Some(destination), settings)
-                    .map_err(Error::report)?;
-                // Format the generated file
-                Command::new("rustfmt")
-                    .args(["--edition", "2021"])
-                    .arg(destination)
-                    .output()?;
-            } else {
-                // Get currently checked-in generate file
-                let old_codegen = std::fs::read_to_string(destination).unwrap_or_default();
-                // Generate new file
-                let new_codegen = cornucopia::generate_live(client, queries_path, None, settings)
-                    .map_err(Error::report)?;
-                // Format the generated code string by piping to rustfmt
-                let mut rustfmt = Command::new("rustfmt")
-                    .args(["--edition", "2021"])
-                    .stdin(Stdio::piped())
-                    .stdout(Stdio::piped())
-                    .spawn()?;
-                rustfmt
-                    .stdin
-                    .as_mut()
-                    .unwrap()
-                    .write_all(new_codegen.as_bytes())?;
-                let formated_new_codegen =
-                    String::from_utf8(rustfmt.wait_with_output()?.stdout).unwrap();
-
-                // If the newly generated file differs from
-                // the currently checked in one, return an error.
-                if old_codegen != formated_new_codegen {
-                    Err("\"{destination}\" is outdated")?;
+            // Generate and format the code
+            let generated = match &codegen_test.backends {
+                Some(backends) => {
+                    let mut outputs = Vec::new();
+                    for (backend, _) in backends {
+                        let backend = match *backend {
+                            "async" => Backend::Async,
+                            "sync" => Backend::Sync,
+                            "sqlx" => Backend::Sqlx,
+                            _ => Err(format!("unknown backend \"{backend}\""))?,
+                        };
+                        outputs.push((backend, None));
+                    }
+                    cornucopia::generate_live_backends(client, &[queries_path], &outputs, settings)
+                        .map(|codes| {
+                            backends
+                                .iter()
+                                .map(|(_, it)| PathBuf::from(it))
+                                .zip(codes)
+                                .collect()
+                        })
+                }
+                None if codegen_test.split.unwrap_or(false) => {
+                    cornucopia::generate_live_split(client, &[queries_path], None, settings).map(
+                        |files| {
+                            files
+                                .into_iter()
+                                .map(|(path, code)| (Path::new(destination).join(path), code))
+                                .collect()
+                        },
+                    )
+                }
+                None => match codegen_test.filter {
+                    Some(filter) => cornucopia::generate_live_filtered(
+                        client,
+                        &[queries_path],
+                        filter,
+                        None,
+                        settings,
+                    ),
+                    None => cornucopia::generate_live(client, queries_path, None, settings),
+                }
+                .map(|code| vec![(PathBuf::from(destination), code)]),
+            }
+            .map_err(Error::report)?;
+            for (destination, code) in generated {
+                let new_codegen =
+                    cornucopia::format_generated_code(&code, "rustfmt --edition 2021")
+                        .map_err(Error::report)?;
+                // If `--apply`, then the code will be regenerated.
+                // Otherwise, it is only checked.
+                if apply {
+                    if let Some(parent) = destination.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(destination, new_codegen)?;
+                } else {
+                    // If the newly generated file differs from
+                    // the currently checked in one, return an error.
+                    cornucopia::verify_generated_code(&destination, &new_codegen)
+                        .map_err(Error::report)?;
                 }
             }
             println!("(generate) {} {}", codegen_test.name, "OK".green());
 
+            // Generate the same code without a database
+            if codegen_test.offline.unwrap_or(false) {
+                let cache_dir = tempfile::tempdir()?;
+                let cache = cache_dir.path().join("cornucopia-offline.json");
+                let (queries_path, schema_path) = (Path::new(queries_path), Path::new(schema_path));
+                cornucopia::save_offline_cache(
+                    client,
+                    &[queries_path],
+                    &[schema_path],
+                    &cache,
+                    settings,
+                )
+                .map_err(Error::report)?;
+                let code = cornucopia::generate_offline(
+                    &[queries_path],
+                    &[schema_path],
+                    Some(cache.as_path()),
+                    None,
+                    settings,
+                )
+                .and_then(|code| cornucopia::format_generated_code(&code, "rustfmt --edition 2021"))
+                .map_err(Error::report)?;
+                cornucopia::verify_generated_code(Path::new(destination), &code)
+                    .map_err(Error::report)?;
+                println!("(offline) {} {}", codegen_test.name, "OK".green());
+            }
+            if codegen_test.schema_only.unwrap_or(false) {
+                let (queries_path, schema_path) = (Path::new(queries_path), Path::new(schema_path));
+                let code = cornucopia::generate_offline(
+                    &[queries_path],
+                    &[schema_path],
+                    None,
+                    None,
+                    settings,
+                )
+                .and_then(|code| cornucopia::format_generated_code(&code, "rustfmt --edition 2021"))
+                .map_err(Error::report)?;
+                cornucopia::verify_generated_code(Path::new(destination), &code)
+                    .map_err(Error::report)?;
+                println!("(schema only) {} {}", codegen_test.name, "OK".green());
+            }
+            if codegen_test.search_path.is_some() {
+                client.batch_execute("RESET search_path")?;
+            }
+
             // Run code
             let run = match codegen_test.run.unwrap_or(Run::Bool(false)) {
                 Run::Bool(bool) => bool,